@@ -1,6 +1,5 @@
-use futures::future::Future;
-
-fn main() {
+#[tokio::main]
+async fn main() {
     // Create a new client
     let secret_key = std::env::var("STRIPE_SECRET_KEY").expect("Missing STRIPE_SECRET_KEY in env");
     let client = stripe::r#async::Client::new(secret_key);
@@ -12,15 +11,12 @@ fn main() {
     card.exp_year = "20";
 
     // Define the charge
-    let mut params = stripe::CreateCharge::new();
+    let mut params = stripe::ChargeParams::default();
     params.amount = Some(1000);
+    params.currency = Some(stripe::Currency::USD);
     params.source = Some(stripe::PaymentSourceParams::Card(card));
 
     // Create the charge
-    stripe::Charge::create(&client, params)
-        .map(|charge| {
-            println!("{:?}", charge);
-        })
-        .wait()
-        .unwrap();
+    let charge = stripe::Charge::create(&client, params).await.unwrap();
+    println!("{:?}", charge);
 }
@@ -10,8 +10,9 @@ fn main() {
     card.exp_year = "20";
 
     // Define the charge
-    let mut params = stripe::CreateCharge::new();
+    let mut params = stripe::ChargeParams::default();
     params.amount = Some(1000);
+    params.currency = Some(stripe::Currency::USD);
     params.source = Some(stripe::PaymentSourceParams::Card(card));
 
     // Create the charge
@@ -14,7 +14,6 @@ fn main() {
     id_renames.insert("fee_refund", "application_fee_refund");
     id_renames.insert("invoiceitem", "invoice_item");
     id_renames.insert("line_item", "invoice_line_item");
-    id_renames.insert("source_transaction", "charge");
 
     // Compute additional metadata from spec.
     let mut ids = BTreeMap::new();
@@ -412,6 +411,10 @@ fn main() {
         )
         .unwrap();
     }
+
+    // Generate the event types and the objects which events are about
+    let out = gen_event_types(&meta);
+    fs::write("openapi/out/event_types.rs", out.as_bytes()).unwrap();
 }
 
 struct Metadata<'a> {
@@ -549,6 +552,86 @@ struct InferredParams {
     parameters: Json,
 }
 
+/// Generates `EventType` and `EventObject` (with the macros in `resources/event.rs`) from
+/// the event types which a webhook endpoint can be enabled for.
+fn gen_event_types(meta: &Metadata) -> String {
+    let create_webhook_endpoint = &meta.spec["paths"]["/v1/webhook_endpoints"]["post"]
+        ["requestBody"]["content"]["application/x-www-form-urlencoded"]["schema"];
+    let mut event_types: BTreeSet<&str> = create_webhook_endpoint["properties"]["enabled_events"]
+        ["items"]["enum"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|name| name.as_str())
+        .filter(|name| *name != "*")
+        .collect();
+
+    // N.B. These are no longer in the spec, but are kept for backwards compatibility.
+    event_types.insert("order_return.updated");
+    event_types.insert("payment_intent.requires_capture");
+
+    // The objects of the events which aren't named by the event type,
+    // i.e. which aren't `{object}.{action}` or `{parent}.{object}.{action}`.
+    let mut object_overrides: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    object_overrides.insert("account.application", vec!["application"]);
+    object_overrides.insert("account.external_account", vec!["bank_account", "card"]);
+    object_overrides.insert("application_fee.refund", vec!["fee_refund"]);
+    object_overrides.insert("charge.refund", vec!["refund"]);
+    object_overrides.insert("customer.source", vec!["bank_account", "card", "source"]);
+    object_overrides.insert("source.mandate_notification", vec!["source_mandate_notification"]);
+    object_overrides.insert("source.transaction", vec!["source_transaction"]);
+
+    let mut out = String::new();
+    out.push_str("// ======================================\n");
+    out.push_str("// This file was automatically generated.\n");
+    out.push_str("// ======================================\n\n");
+    out.push_str("use crate::resources::*;\n\n");
+
+    let mut objects = BTreeSet::new();
+    out.push_str("def_event_types! {\n");
+    for event_type in &event_types {
+        let (prefix, _action) = event_type.split_at(event_type.rfind('.').unwrap());
+        let names = if let Some(names) = object_overrides.get(event_type) {
+            names.clone()
+        } else if let Some(names) = object_overrides.get(prefix) {
+            names.clone()
+        } else {
+            let parent = prefix.rsplit('.').next().unwrap();
+            let candidates = [prefix.to_string(), prefix.replacen('_', ".", 1), parent.to_string()];
+            match candidates.iter().find(|name| meta.objects.contains(name.as_str())) {
+                Some(name) => vec![meta.objects.get(name.as_str()).cloned().unwrap()],
+                None => {
+                    eprintln!("warning: could not determine the object of event `{}`", event_type);
+                    vec![]
+                }
+            }
+        };
+        objects.extend(names);
+
+        let variant = event_type.replace("invoiceitem", "invoice_item").replace('.', "_");
+        out.push_str("    ");
+        out.push_str(&variant.to_camel_case());
+        out.push_str(" = \"");
+        out.push_str(event_type);
+        out.push_str("\",\n");
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("def_event_objects! {\n");
+    for object in &objects {
+        let rust_type = meta.schema_to_rust_type(object);
+        out.push_str("    ");
+        out.push_str(&rust_type);
+        out.push('(');
+        out.push_str(&rust_type);
+        out.push_str(") = \"");
+        out.push_str(object);
+        out.push_str("\",\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
 fn gen_impl_object(meta: &Metadata, object: &str) -> String {
     let mut out = String::new();
     let mut state = Generated::default();
@@ -1162,11 +1245,11 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             "/// An enum representing the possible values of an `{}`'s `{}` field.\n",
             enum_.parent, enum_.field
         ));
-        out.push_str("#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]\n");
-        out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+        out.push_str("#[derive(Clone, Debug, Eq, PartialEq)]\n");
         out.push_str("pub enum ");
         out.push_str(&enum_name);
         out.push_str(" {\n");
+        let mut variants = Vec::new();
         for wire_name in &enum_.options {
             if wire_name.trim().is_empty() {
                 continue;
@@ -1184,44 +1267,63 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             if variant_name.trim().is_empty() {
                 panic!("unhandled enum variant: {:?}", wire_name)
             }
-            if &variant_name.to_snake_case() != wire_name {
-                out.push_str("    #[serde(rename = \"");
-                out.push_str(wire_name);
-                out.push_str("\")]\n");
-            }
             out.push_str("    ");
             out.push_str(&variant_name);
             out.push_str(",\n");
+            variants.push((variant_name, wire_name));
         }
+        // Values which the library doesn't know about (e.g. added by a newer api version)
+        // are kept in a catch-all variant, instead of failing to deserialize.
+        let unknown_variant =
+            if variants.iter().any(|(name, _)| name == "Unknown") { "Other" } else { "Unknown" };
+        out.push_str(
+            "    /// A value not yet supported by the library, e.g. one added by a newer API version.\n",
+        );
+        out.push_str("    ");
+        out.push_str(unknown_variant);
+        out.push_str("(String),\n");
         out.push_str("}\n");
         out.push('\n');
         out.push_str("impl ");
         out.push_str(&enum_name);
         out.push_str(" {\n");
-        out.push_str("    pub fn as_str(&self) -> &'static str {\n");
+        out.push_str("    pub fn as_str(&self) -> &str {\n");
         out.push_str("        match self {\n");
-        for wire_name in &enum_.options {
-            if wire_name.trim().is_empty() {
-                continue;
-            }
-            let variant_name = match wire_name.as_str() {
-                "*" => "All".to_string(),
-                n => {
-                    if n.chars().next().unwrap().is_digit(10) {
-                        format!("V{}", n.to_string().replace('-', "_"))
-                    } else {
-                        meta.schema_to_rust_type(wire_name)
-                    }
-                }
-            };
+        for (variant_name, wire_name) in &variants {
             out.push_str("            ");
             out.push_str(&enum_name);
             out.push_str("::");
-            out.push_str(&variant_name);
+            out.push_str(variant_name);
             out.push_str(" => ");
             out.push_str(&format!("{:?}", wire_name));
             out.push_str(",\n");
         }
+        out.push_str(&format!(
+            "            {}::{}(value) => value,\n",
+            enum_name, unknown_variant
+        ));
+        out.push_str("        }\n");
+        out.push_str("    }\n");
+        out.push_str("}\n");
+        out.push('\n');
+        out.push_str("impl From<&str> for ");
+        out.push_str(&enum_name);
+        out.push_str(" {\n");
+        out.push_str("    fn from(value: &str) -> Self {\n");
+        out.push_str("        match value {\n");
+        for (variant_name, wire_name) in &variants {
+            out.push_str("            ");
+            out.push_str(&format!("{:?}", wire_name));
+            out.push_str(" => ");
+            out.push_str(&enum_name);
+            out.push_str("::");
+            out.push_str(variant_name);
+            out.push_str(",\n");
+        }
+        out.push_str(&format!(
+            "            _ => {}::{}(value.to_string()),\n",
+            enum_name, unknown_variant
+        ));
         out.push_str("        }\n");
         out.push_str("    }\n");
         out.push_str("}\n");
@@ -1241,6 +1343,10 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         out.push_str("        self.as_str().fmt(f)\n");
         out.push_str("    }\n");
         out.push_str("}\n");
+        out.push('\n');
+        out.push_str("serde_string_enum!(");
+        out.push_str(&enum_name);
+        out.push_str(");\n");
     }
 
     let mut prelude = String::new();
@@ -1599,12 +1705,12 @@ fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn list(client: &Client, params: ");
+                    out.push_str("    pub fn list<C: StripeClient>(client: &C, params: ");
                     out.push_str(&params_name);
-                    out.push_str("<'_>) -> Response<List<");
+                    out.push_str("<'_>) -> C::Response<List<");
                     out.push_str(&rust_struct);
                     out.push_str(">> {\n");
-                    out.push_str("        client.get_query(\"/");
+                    out.push_str("        List::get_query(client, \"/");
                     out.push_str(&segments.join("/"));
                     out.push_str("\", &params)\n");
                     out.push_str("    }\n");
@@ -1628,19 +1734,19 @@ fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn retrieve(client: &Client, id: &");
+                    out.push_str("    pub fn retrieve<C: StripeClient>(client: &C, id: &");
                     out.push_str(&id_type);
                     if let Some(param) = expand_param {
                         state.use_params.insert("Expand");
                         assert_eq!(param["in"].as_str(), Some("query"));
-                        out.push_str(", expand: &[&str]) -> Response<");
+                        out.push_str(", expand: &[&str]) -> C::Response<");
                         out.push_str(&rust_struct);
                         out.push_str("> {\n");
                         out.push_str("        client.get_query(");
                         out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                         out.push_str(", &Expand { expand })\n");
                     } else {
-                        out.push_str(") -> Response<");
+                        out.push_str(") -> C::Response<");
                         out.push_str(&rust_struct);
                         out.push_str("> {\n");
                         out.push_str("        client.get(/");
@@ -1709,9 +1815,9 @@ fn gen_impl_requests(
                 let mut out = String::new();
                 out.push('\n');
                 print_doc_comment(&mut out, doc_comment, 1);
-                out.push_str("    pub fn create(client: &Client, params: ");
+                out.push_str("    pub fn create<C: StripeClient>(client: &C, params: ");
                 out.push_str(&params_name);
-                out.push_str("<'_>) -> Response<");
+                out.push_str("<'_>) -> C::Response<");
                 out.push_str(&return_type);
                 out.push_str("> {\n");
                 out.push_str("        client.post_form(\"/");
@@ -1769,11 +1875,11 @@ fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn update(client: &Client, id: &");
+                    out.push_str("    pub fn update<C: StripeClient>(client: &C, id: &");
                     out.push_str(&id_type);
                     out.push_str(", params: ");
                     out.push_str(&params_name);
-                    out.push_str("<'_>) -> Response<");
+                    out.push_str("<'_>) -> C::Response<");
                     out.push_str(&return_type);
                     out.push_str("> {\n");
                     out.push_str("        client.post_form(");
@@ -1813,9 +1919,9 @@ fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn delete(client: &Client, id: &");
+                    out.push_str("    pub fn delete<C: StripeClient>(client: &C, id: &");
                     out.push_str(&id_type);
-                    out.push_str(") -> Response<Deleted<");
+                    out.push_str(") -> C::Response<Deleted<");
                     out.push_str(&id_type);
                     out.push_str(">> {\n");
                     out.push_str("        client.delete(");
@@ -1832,8 +1938,7 @@ fn gen_impl_requests(
     }
 
     // Add imports
-    state.use_config.insert("Client");
-    state.use_config.insert("Response");
+    state.use_config.insert("StripeClient");
 
     // Output the impl block
     let mut out = String::new();
@@ -1,23 +1,65 @@
-use crate::config::Response;
-use crate::error::{Error, ErrorResponse, RequestError};
-use crate::params::Headers;
-use futures::future::{self, Future};
-use futures::stream::Stream;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::r#async::RequestBuilder;
+use crate::config::StripeClient;
+use crate::error::Error;
+use crate::observer::RequestObserver;
+use crate::params::{Headers, Paginate, Paginator, ResponseMeta, ResponseMetaSlot};
+use crate::resources::ApiVersion;
+use crate::retry::RetryPolicy;
+use crate::transport::{with_form_urlencoded, Attempt, ClientConfig, HttpRequest, HttpResponse};
+use reqwest::Method;
 use serde::de::DeserializeOwned;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A future of a `Result`.
+///
+/// This is the type returned by requests made with the async `Client`.
+pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
+
+/// Sends the requests of the async `Client`.
+///
+/// This is implemented for `reqwest::Client` (which the client uses by default),
+/// so a `reqwest` client configured with a custom proxy or TLS settings can be provided
+/// with `Client::with_transport`.  An error returned by a transport is treated as a
+/// connection failure, and so the request may be retried.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Response<HttpResponse>;
+}
+
+impl Transport for reqwest::Client {
+    fn send(&self, request: HttpRequest) -> Response<HttpResponse> {
+        let mut builder = self.request(request.method, request.url).headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        Box::pin(async move {
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok(HttpResponse { status, headers, body })
+        })
+    }
+}
 
 #[derive(Clone)]
 pub struct Client {
-    client: reqwest::r#async::Client,
-    secret_key: String,
-    headers: Headers,
+    transport: Arc<dyn Transport>,
+    config: ClientConfig,
 }
 
 impl Client {
-    pub fn new<S: Into<String>>(secret_key: S) -> Client {
-        let client = reqwest::r#async::Client::new();
-        Client { client, secret_key: secret_key.into(), headers: Headers::default() }
+    /// Creates a new client pointed to `https://api.stripe.com/`
+    pub fn new(secret_key: impl Into<String>) -> Client {
+        Client::from_url("https://api.stripe.com/", secret_key)
+    }
+
+    /// Creates a new client posted to a custom `scheme://host/`
+    pub fn from_url(scheme_host: impl Into<String>, secret_key: impl Into<String>) -> Client {
+        Client {
+            transport: Arc::new(reqwest::Client::new()),
+            config: ClientConfig::new(scheme_host.into(), secret_key.into()),
+        }
     }
 
     /// Clones a new client with different headers.
@@ -26,23 +68,98 @@ impl Client {
     /// or with different Meta, Extra, and Expand headers while using the same secret key.
     pub fn with_headers(&self, headers: Headers) -> Client {
         let mut client = self.clone();
-        client.headers = headers;
+        client.config.headers = headers;
+        client
+    }
+
+    /// Clones a new client which sends `version` as the `Stripe-Version` of its requests.
+    ///
+    /// By default a client sends `stripe::API_VERSION`, which is the version this crate's
+    /// types were generated for.  Another version may return objects which can't be parsed.
+    pub fn with_api_version(&self, version: ApiVersion) -> Client {
+        let mut client = self.clone();
+        client.config.api_version = version;
         client
     }
 
+    /// Clones a new client which sends `user_agent` as the `User-Agent` of its requests.
+    pub(crate) fn with_user_agent(&self, user_agent: String) -> Client {
+        let mut client = self.clone();
+        client.config.user_agent = user_agent;
+        client
+    }
+
+    /// Clones a new client which retries failed requests according to `policy`.
+    ///
+    /// By default a client makes a single attempt for each request.
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Client {
+        let mut client = self.clone();
+        client.config.retry_policy = policy;
+        client
+    }
+
+    /// Clones a new client which sends `key` as the Idempotency-Key of its `POST` requests.
+    ///
+    /// Stripe returns the result of the first request made with a given key for any later
+    /// request with the same key, so a request which is sent again (e.g. after a network
+    /// failure) can't create a second charge.
+    ///
+    /// Without an explicit key, a random key is generated for each `POST` request
+    /// when the client's retry policy allows more than one attempt.
+    pub fn with_idempotency_key(&self, key: impl Into<String>) -> Client {
+        let mut client = self.clone();
+        client.config.idempotency_key = Some(key.into());
+        client
+    }
+
+    /// Clones a new client which sends its requests with `transport`
+    /// instead of the default `reqwest::Client`.
+    pub fn with_transport(&self, transport: impl Transport + 'static) -> Client {
+        let mut client = self.clone();
+        client.transport = Arc::new(transport);
+        client
+    }
+
+    /// Clones a new client which reports every request it sends to `observer`,
+    /// e.g. to log requests or to collect metrics.
+    pub fn with_observer(&self, observer: impl RequestObserver + 'static) -> Client {
+        let mut client = self.clone();
+        client.config.observer = Some(Arc::new(observer));
+        client
+    }
+
+    /// Makes the requests in `request` and resolves to the result alongside the metadata
+    /// (e.g. the `Request-Id`) of the last response received.
+    ///
+    /// If the request fails, the `request_id` of a `RequestError` can be used instead.
+    pub fn with_response_meta<T, F>(&self, request: F) -> Response<(T, ResponseMeta)>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Client) -> Response<T>,
+    {
+        let slot = ResponseMetaSlot::default();
+        let mut client = self.clone();
+        client.config.response_meta = Some(slot.clone());
+        let request = request(&client);
+        Box::pin(async move {
+            let value = request.await?;
+            let meta = slot.lock().ok().and_then(|mut meta| meta.take());
+            Ok((value, meta.ok_or(Error::Unexpected("no response was received from stripe"))?))
+        })
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
     /// Otherwise, prefer `client.with(Headers{stripe_account: "acct_ABC", ..})`.
     pub fn set_stripe_account<S: Into<String>>(&mut self, account_id: S) {
-        self.headers.stripe_account = Some(account_id.into());
+        self.config.headers.stripe_account = Some(account_id.into());
     }
 
     /// Make a `GET` http request with just a path
     pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = Client::url(path);
-        let request = self.client.get(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::GET, &url))
     }
 
     /// Make a `GET` http request with url query parameters
@@ -51,19 +168,17 @@ impl Client {
         path: &str,
         params: P,
     ) -> Response<T> {
-        let url = match Client::url_with_params(path, params) {
-            Err(err) => return Box::new(future::err(err)),
+        let url = match self.config.url_with_params(path, params) {
+            Err(e) => return Client::err(e),
             Ok(ok) => ok,
         };
-        let request = self.client.get(&url).headers(self.headers());
-        send(request)
+        self.send(self.config.request(Method::GET, &url))
     }
 
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = Client::url(path);
-        let request = self.client.delete(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::DELETE, &url))
     }
 
     /// Make a `DELETE` http request with url query parameters
@@ -72,19 +187,17 @@ impl Client {
         path: &str,
         params: P,
     ) -> Response<T> {
-        let url = match Client::url_with_params(path, params) {
-            Err(err) => return Box::new(future::err(err)),
+        let url = match self.config.url_with_params(path, params) {
+            Err(e) => return Client::err(e),
             Ok(ok) => ok,
         };
-        let request = self.client.delete(&url).headers(self.headers());
-        send(request)
+        self.send(self.config.request(Method::DELETE, &url))
     }
 
     /// Make a `POST` http request with just a path
     pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = Client::url(path);
-        let request = self.client.post(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::POST, &url))
     }
 
     /// Make a `POST` http request with urlencoded body
@@ -93,78 +206,103 @@ impl Client {
         path: &str,
         form: F,
     ) -> Response<T> {
-        let url = Client::url(path);
-        let request = self.client.post(&url).headers(self.headers());
-        let request = match with_form_urlencoded(request, &form) {
-            Err(err) => return Box::new(future::err(err)),
-            Ok(ok) => ok,
-        };
-        send(request)
+        let url = self.config.url(path);
+        let request = self.config.request(Method::POST, &url);
+        self.send(request.and_then(|request| with_form_urlencoded(request, &form)))
     }
 
-    fn url(path: &str) -> String {
-        format!("https://api.stripe.com/v1/{}", &path[1..])
-    }
-
-    fn url_with_params<P: serde::Serialize>(path: &str, params: P) -> Result<String, Error> {
-        let params = serde_qs::to_string(&params).map_err(Error::serialize)?;
-        Ok(format!("https://api.stripe.com/v1/{}?{}", &path[1..], params))
+    fn send<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: Result<HttpRequest, Error>,
+    ) -> Response<T> {
+        match request {
+            Ok(request) => Box::pin(self.clone().execute(request)),
+            Err(err) => Client::err(err),
+        }
     }
 
-    fn headers(&self) -> HeaderMap {
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", self.secret_key)).unwrap(),
-        );
-        if let Some(account) = &self.headers.stripe_account {
-            headers.insert(
-                HeaderName::from_static("stripe-account"),
-                HeaderValue::from_str(account).unwrap(),
-            );
-        }
-        if let Some(client_id) = &self.headers.client_id {
-            headers.insert(
-                HeaderName::from_static("client-id"),
-                HeaderValue::from_str(client_id).unwrap(),
-            );
+    async fn execute<T: DeserializeOwned>(self, request: HttpRequest) -> Result<T, Error> {
+        let mut exchange = self.config.exchange(&request);
+        loop {
+            exchange.start();
+            match exchange.finish(self.transport.send(request.clone()).await) {
+                Attempt::Done(result) => return result,
+                Attempt::Retry(delay) => tokio::time::sleep(delay).await,
+            }
         }
-        headers
     }
 }
 
-/// Serialize the form content using `serde_qs` instead of `serde_urlencoded`
-///
-/// See https://github.com/seanmonstar/reqwest/issues/274
-fn with_form_urlencoded<T: serde::Serialize>(
-    request: RequestBuilder,
-    form: &T,
-) -> Result<RequestBuilder, Error> {
-    let body = serde_qs::to_string(form).map_err(Error::serialize)?;
-    Ok(request
-        .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(body))
-}
+impl StripeClient for Client {
+    type Response<T: Send + 'static> = Response<T>;
 
-fn send<T: DeserializeOwned + Send + 'static>(request: RequestBuilder) -> Response<T> {
-    Box::new(request.send().map_err(Error::Http).and_then(|response| {
-        let status = response.status();
-        response.into_body().concat2().map_err(Error::Http).and_then(move |body| {
-            let mut body = std::io::Cursor::new(body);
-            let mut bytes = Vec::with_capacity(4096);
-            std::io::copy(&mut body, &mut bytes)?;
-
-            if !status.is_success() {
-                let mut err = serde_json::from_slice(&bytes).unwrap_or_else(|err| {
-                    let mut req = ErrorResponse { error: RequestError::default() };
-                    req.error.message = Some(format!("failed to deserialize error: {}", err));
-                    req
-                });
-                err.error.http_status = status.as_u16();
-                return Err(Error::from(err.error));
-            }
+    fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::get(self, path)
+    }
 
-            serde_json::from_slice(&bytes).map_err(Error::deserialize)
+    fn get_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        Client::get_query(self, path, params)
+    }
+
+    fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::delete(self, path)
+    }
+
+    fn delete_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        Client::delete_query(self, path, params)
+    }
+
+    fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::post(self, path)
+    }
+
+    fn post_form<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        Client::post_form(self, path, form)
+    }
+
+    fn ok<T: Send + 'static>(value: T) -> Response<T> {
+        Box::pin(future::ready(Ok(value)))
+    }
+
+    fn err<T: Send + 'static>(err: Error) -> Response<T> {
+        Box::pin(future::ready(Err(err)))
+    }
+
+    fn and_then<T, U, F>(response: Response<T>, f: F) -> Response<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> Response<U> + Send + 'static,
+    {
+        Box::pin(async move { f(response.await?).await })
+    }
+
+    fn collect_all<T>(mut paginator: Paginator<T, Self>) -> Response<Vec<T>>
+    where
+        T: Paginate + DeserializeOwned + Send + 'static,
+    {
+        use futures_core::Stream;
+
+        Box::pin(async move {
+            let mut data = Vec::new();
+            while let Some(item) =
+                future::poll_fn(|cx| Pin::new(&mut paginator).poll_next(cx)).await
+            {
+                data.push(item?);
+            }
+            Ok(data)
         })
-    }))
+    }
 }
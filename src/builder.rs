@@ -0,0 +1,156 @@
+use crate::error::Error;
+use crate::transport::USER_AGENT;
+use std::time::Duration;
+
+/// Applies the settings of a `ClientBuilder` to a blocking or async `reqwest::ClientBuilder`.
+///
+/// N.B. The two builders have the same methods but no common trait.
+macro_rules! configure {
+    ($settings:expr, $builder:expr) => {{
+        let settings: &ClientBuilder = $settings;
+        let mut builder = $builder;
+        if let Some(timeout) = settings.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = settings.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &settings.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+        if let Some(timeout) = settings.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = settings.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        for pem in &settings.root_certificates {
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
+        }
+        builder
+    }};
+}
+
+/// Configures the http connection used by a `Client`.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// let client = stripe::ClientBuilder::new("sk_test_YOUR_STRIPE_SECRET")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .user_agent_suffix("my-app/1.2.3")
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Settings which aren't set use the defaults of `reqwest`.
+#[derive(Clone, Debug)]
+pub struct ClientBuilder {
+    secret_key: String,
+    url: String,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    user_agent_suffix: Option<String>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    root_certificates: Vec<Vec<u8>>,
+}
+
+impl ClientBuilder {
+    /// Creates a builder for a client pointed to `https://api.stripe.com/`
+    pub fn new(secret_key: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            secret_key: secret_key.into(),
+            url: "https://api.stripe.com/".into(),
+            connect_timeout: None,
+            timeout: None,
+            proxy: None,
+            user_agent_suffix: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            root_certificates: Vec::new(),
+        }
+    }
+
+    /// Points the client to a custom `scheme://host/`
+    pub fn url(mut self, scheme_host: impl Into<String>) -> ClientBuilder {
+        self.url = scheme_host.into();
+        self
+    }
+
+    /// Sets the time to wait for a connection to Stripe to be established.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time to wait for each attempt of a request to complete, from connecting
+    /// until the response body has been read.
+    ///
+    /// N.B. This and `connect_timeout` are the only timeouts; there is no separate timeout
+    ///      for reading the response.  The blocking client uses a timeout of 30 seconds
+    ///      if none is set.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends all requests through the proxy at `url` (e.g. `http://proxy.example:3128`).
+    pub fn proxy(mut self, url: impl Into<String>) -> ClientBuilder {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Appends `suffix` to the `User-Agent` sent with each request, e.g. to identify
+    /// the application (or version of the application) making the requests.
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> ClientBuilder {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Sets how long an idle connection is kept open to be reused by a later request.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections which are kept open to each host.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> ClientBuilder {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Trusts the PEM encoded root certificate `pem` (in addition to the system's roots),
+    /// e.g. for a proxy which intercepts TLS connections.
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> ClientBuilder {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Builds a blocking client.
+    #[cfg(feature = "blocking")]
+    pub fn build(&self) -> Result<crate::Client, Error> {
+        let http = configure!(self, reqwest::blocking::Client::builder()).build()?;
+        Ok(crate::Client::from_url(self.url.as_str(), self.secret_key.as_str())
+            .with_transport(http)
+            .with_user_agent(self.user_agent()))
+    }
+
+    /// Builds an async client.
+    #[cfg(feature = "async")]
+    pub fn build_async(&self) -> Result<crate::r#async::Client, Error> {
+        let http = configure!(self, reqwest::Client::builder()).build()?;
+        Ok(crate::r#async::Client::from_url(self.url.as_str(), self.secret_key.as_str())
+            .with_transport(http)
+            .with_user_agent(self.user_agent()))
+    }
+
+    fn user_agent(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {}", USER_AGENT, suffix),
+            None => USER_AGENT.into(),
+        }
+    }
+}
@@ -1,17 +1,23 @@
-use crate::config::Response;
-use crate::error::{Error, ErrorResponse, RequestError};
-use crate::params::Headers;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::RequestBuilder;
+use crate::config::StripeClient;
+use crate::error::Error;
+use crate::observer::RequestObserver;
+use crate::params::{Headers, Paginate, Paginator, ResponseMeta, ResponseMetaSlot};
+use crate::resources::ApiVersion;
+use crate::retry::RetryPolicy;
+use crate::transport::{with_form_urlencoded, Attempt, ClientConfig, HttpRequest, Transport};
+use reqwest::Method;
 use serde::de::DeserializeOwned;
-use std::io::Read;
+use std::sync::Arc;
+
+/// An alias for `Result`.
+///
+/// This is the type returned by requests made with the blocking `Client`.
+pub type Response<T> = Result<T, Error>;
 
 #[derive(Clone)]
 pub struct Client {
-    client: reqwest::Client,
-    secret_key: String,
-    headers: Headers,
-    host: String,
+    transport: Arc<dyn Transport>,
+    config: ClientConfig,
 }
 
 impl Client {
@@ -22,13 +28,9 @@ impl Client {
 
     /// Creates a new client posted to a custom `scheme://host/`
     pub fn from_url(scheme_host: impl Into<String>, secret_key: impl Into<String>) -> Client {
-        let url = scheme_host.into();
-        let host = if url.ends_with("/") { format!("{}v1", url) } else { format!("{}/v1", url) };
         Client {
-            client: reqwest::Client::new(),
-            secret_key: secret_key.into(),
-            headers: Headers::default(),
-            host,
+            transport: Arc::new(reqwest::blocking::Client::new()),
+            config: ClientConfig::new(scheme_host.into(), secret_key.into()),
         }
     }
 
@@ -38,23 +40,109 @@ impl Client {
     /// or with different Meta, Extra, and Expand headers while using the same secret key.
     pub fn with_headers(&self, headers: Headers) -> Client {
         let mut client = self.clone();
-        client.headers = headers;
+        client.config.headers = headers;
         client
     }
 
+    /// Clones a new client which sends `version` as the `Stripe-Version` of its requests.
+    ///
+    /// By default a client sends `stripe::API_VERSION`, which is the version this crate's
+    /// types were generated for.  Another version may return objects which can't be parsed.
+    pub fn with_api_version(&self, version: ApiVersion) -> Client {
+        let mut client = self.clone();
+        client.config.api_version = version;
+        client
+    }
+
+    /// Clones a new client which sends `user_agent` as the `User-Agent` of its requests.
+    pub(crate) fn with_user_agent(&self, user_agent: String) -> Client {
+        let mut client = self.clone();
+        client.config.user_agent = user_agent;
+        client
+    }
+
+    /// Clones a new client which retries failed requests according to `policy`.
+    ///
+    /// By default a client makes a single attempt for each request.
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Client {
+        let mut client = self.clone();
+        client.config.retry_policy = policy;
+        client
+    }
+
+    /// Clones a new client which sends `key` as the Idempotency-Key of its `POST` requests.
+    ///
+    /// Stripe returns the result of the first request made with a given key for any later
+    /// request with the same key, so a request which is sent again (e.g. after a network
+    /// failure) can't create a second charge.  The key should identify one logical request:
+    ///
+    /// ```rust,no_run
+    /// # let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET");
+    /// # let params = stripe::ChargeParams::default();
+    /// let charge = stripe::Charge::create(&client.with_idempotency_key("order_1234"), params);
+    /// ```
+    ///
+    /// Without an explicit key, a random key is generated for each `POST` request
+    /// when the client's retry policy allows more than one attempt.
+    pub fn with_idempotency_key(&self, key: impl Into<String>) -> Client {
+        let mut client = self.clone();
+        client.config.idempotency_key = Some(key.into());
+        client
+    }
+
+    /// Clones a new client which sends its requests with `transport`
+    /// instead of the default `reqwest::blocking::Client`.
+    pub fn with_transport(&self, transport: impl Transport + 'static) -> Client {
+        let mut client = self.clone();
+        client.transport = Arc::new(transport);
+        client
+    }
+
+    /// Clones a new client which reports every request it sends to `observer`,
+    /// e.g. to log requests or to collect metrics.
+    pub fn with_observer(&self, observer: impl RequestObserver + 'static) -> Client {
+        let mut client = self.clone();
+        client.config.observer = Some(Arc::new(observer));
+        client
+    }
+
+    /// Makes the requests in `request` and returns the result alongside the metadata
+    /// (e.g. the `Request-Id`) of the last response received.
+    ///
+    /// ```rust,no_run
+    /// # let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET");
+    /// # let id = "ch_123".parse().unwrap();
+    /// let (charge, meta) = client.with_response_meta(|client| {
+    ///     stripe::Charge::retrieve(client, &id, &[])
+    /// }).unwrap();
+    /// println!("{:?}", meta.request_id); // => Some("req_12345")
+    /// ```
+    ///
+    /// If the request fails, the `request_id` of a `RequestError` can be used instead.
+    pub fn with_response_meta<T, F>(&self, request: F) -> Response<(T, ResponseMeta)>
+    where
+        F: FnOnce(&Client) -> Response<T>,
+    {
+        let slot = ResponseMetaSlot::default();
+        let mut client = self.clone();
+        client.config.response_meta = Some(slot.clone());
+        let value = request(&client)?;
+        let meta = slot.lock().ok().and_then(|mut meta| meta.take());
+        Ok((value, meta.ok_or(Error::Unexpected("no response was received from stripe"))?))
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
     /// Otherwise, prefer `client.with(Headers{stripe_account: "acct_ABC", ..})`.
     pub fn set_stripe_account<S: Into<String>>(&mut self, account_id: S) {
-        self.headers.stripe_account = Some(account_id.into());
+        self.config.headers.stripe_account = Some(account_id.into());
     }
 
     /// Make a `GET` http request with just a path
     pub fn get<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        let request = self.client.get(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::GET, &url)?)
     }
 
     /// Make a `GET` http request with url query parameters
@@ -63,16 +151,14 @@ impl Client {
         path: &str,
         params: P,
     ) -> Response<T> {
-        let url = self.url_with_params(path, params)?;
-        let request = self.client.get(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url_with_params(path, params)?;
+        self.send(self.config.request(Method::GET, &url)?)
     }
 
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        let request = self.client.delete(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::DELETE, &url)?)
     }
 
     /// Make a `DELETE` http request with url query parameters
@@ -81,16 +167,14 @@ impl Client {
         path: &str,
         params: P,
     ) -> Response<T> {
-        let url = self.url_with_params(path, params)?;
-        let request = self.client.delete(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url_with_params(path, params)?;
+        self.send(self.config.request(Method::DELETE, &url)?)
     }
 
     /// Make a `POST` http request with just a path
     pub fn post<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        let request = self.client.post(&url).headers(self.headers());
-        send(request)
+        let url = self.config.url(path);
+        self.send(self.config.request(Method::POST, &url)?)
     }
 
     /// Make a `POST` http request with urlencoded body
@@ -99,101 +183,83 @@ impl Client {
         path: &str,
         form: F,
     ) -> Response<T> {
-        let url = self.url(path);
-        let request = self.client.post(&url).headers(self.headers());
-        let request = with_form_urlencoded(request, &form)?;
-        send(request)
+        let url = self.config.url(path);
+        let request = with_form_urlencoded(self.config.request(Method::POST, &url)?, &form)?;
+        self.send(request)
     }
 
-    fn url(&self, path: &str) -> String {
-        format!("{}/{}", self.host, &path[1..])
+    fn send<T: DeserializeOwned>(&self, request: HttpRequest) -> Response<T> {
+        let mut exchange = self.config.exchange(&request);
+        loop {
+            exchange.start();
+            match exchange.finish(self.transport.send(request.clone())) {
+                Attempt::Done(result) => return result,
+                Attempt::Retry(delay) => std::thread::sleep(delay),
+            }
+        }
     }
+}
 
-    fn url_with_params<P: serde::Serialize>(&self, path: &str, params: P) -> Result<String, Error> {
-        let params = serde_qs::to_string(&params).map_err(Error::serialize)?;
-        Ok(format!("{}/{}?{}", self.host, &path[1..], params))
+impl StripeClient for Client {
+    type Response<T: Send + 'static> = Response<T>;
+
+    fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::get(self, path)
     }
 
-    fn headers(&self) -> HeaderMap {
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", self.secret_key)).unwrap(),
-        );
-        if let Some(account) = &self.headers.stripe_account {
-            headers.insert(
-                HeaderName::from_static("stripe-account"),
-                HeaderValue::from_str(account).unwrap(),
-            );
-        }
-        if let Some(client_id) = &self.headers.client_id {
-            headers.insert(
-                HeaderName::from_static("client-id"),
-                HeaderValue::from_str(client_id).unwrap(),
-            );
-        }
-        headers
+    fn get_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        Client::get_query(self, path, params)
     }
-}
 
-/// Serialize the form content using `serde_qs` instead of `serde_urlencoded`
-///
-/// See https://github.com/seanmonstar/reqwest/issues/274
-fn with_form_urlencoded<T: serde::Serialize>(
-    request: RequestBuilder,
-    form: &T,
-) -> Result<RequestBuilder, Error> {
-    let body = serde_qs::to_string(form).map_err(Error::serialize)?;
-    Ok(request
-        .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .body(body))
-}
+    fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::delete(self, path)
+    }
 
-fn send<T: DeserializeOwned>(request: RequestBuilder) -> Response<T> {
-    let mut response = request.send()?;
-    let mut body = String::with_capacity(4096);
-    response.read_to_string(&mut body)?;
+    fn delete_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Response<T> {
+        Client::delete_query(self, path, params)
+    }
+
+    fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+        Client::post(self, path)
+    }
+
+    fn post_form<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        Client::post_form(self, path, form)
+    }
 
-    // N.B. For debugging
-    println!("request was: {:?}", body);
+    fn ok<T: Send + 'static>(value: T) -> Response<T> {
+        Ok(value)
+    }
 
-    let status = response.status();
-    if !status.is_success() {
-        let mut err = serde_json::from_str(&body).unwrap_or_else(|err| {
-            let mut req = ErrorResponse { error: RequestError::default() };
-            req.error.message = Some(format!("failed to deserialize error: {}", err));
-            req
-        });
-        err.error.http_status = status.as_u16();
-        return Err(Error::from(err.error));
+    fn err<T: Send + 'static>(err: Error) -> Response<T> {
+        Err(err)
     }
 
-    serde_json::from_str(&body).map_err(Error::deserialize)
-}
+    fn and_then<T, U, F>(response: Response<T>, f: F) -> Response<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> Response<U> + Send + 'static,
+    {
+        response.and_then(f)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::with_form_urlencoded;
-    use crate::CreateCustomer;
-    use std::collections::HashMap;
-
-    #[test]
-    fn serialize_metadata() {
-        let mut metadata = HashMap::new();
-        metadata.insert("any".to_string(), "thing".to_string());
-        let mut form = CreateCustomer::new();
-        form.email = Some("jdoe@example.org");
-        form.metadata = Some(metadata);
-        let http = reqwest::Client::new();
-        let result = with_form_urlencoded(http.post("https://example.example/v1/"), &form)
-            .and_then(|x| Ok(x.build()?));
-        assert!(result.is_ok(), "Failed to build request: {:?}", result);
-        if let Ok(request) = result {
-            let body = format!("{:?}", request.body());
-            assert_eq!(
-                body,
-                "Some(Body { kind: b\"email=jdoe%40example.org&metadata[any]=thing\" })"
-            );
-        }
+    fn collect_all<T>(paginator: Paginator<T, Self>) -> Response<Vec<T>>
+    where
+        T: Paginate + DeserializeOwned + Send + 'static,
+    {
+        paginator.collect()
     }
 }
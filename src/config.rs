@@ -0,0 +1,69 @@
+use crate::error::Error;
+use crate::params::{Paginate, Paginator};
+use serde::de::DeserializeOwned;
+
+/// A client which can send requests to Stripe.
+///
+/// The methods of each resource (e.g. `Customer::create`) are generic over this trait,
+/// so they can be called with either the blocking `stripe::Client` or the async
+/// `stripe::r#async::Client`.  Both clients can be enabled in the same build.
+///
+/// ```rust,ignore
+/// // With the blocking client, `Response<T>` is `Result<T, Error>`
+/// let customer = stripe::Customer::retrieve(&client, &id, &[])?;
+///
+/// // With the async client, `Response<T>` is a future of a `Result<T, Error>`
+/// let customer = stripe::Customer::retrieve(&async_client, &id, &[]).await?;
+/// ```
+pub trait StripeClient: Clone + Send + Sync + 'static {
+    /// The type returned by a request sent with this client.
+    type Response<T: Send + 'static>;
+
+    /// Make a `GET` http request with just a path
+    fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `GET` http request with url query parameters
+    fn get_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T>;
+
+    /// Make a `DELETE` http request with just a path
+    fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `DELETE` http request with url query parameters
+    fn delete_query<T: DeserializeOwned + Send + 'static, P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T>;
+
+    /// Make a `POST` http request with just a path
+    fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `POST` http request with urlencoded body
+    fn post_form<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Self::Response<T>;
+
+    /// Returns a response which succeeds with `value` without making a request.
+    fn ok<T: Send + 'static>(value: T) -> Self::Response<T>;
+
+    /// Returns a response which fails with `err` without making a request.
+    fn err<T: Send + 'static>(err: Error) -> Self::Response<T>;
+
+    /// Chains a request to be made with the result of a successful `response`.
+    fn and_then<T, U, F>(response: Self::Response<T>, f: F) -> Self::Response<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> Self::Response<U> + Send + 'static;
+
+    /// Collects the remaining elements of `paginator`, fetching one page at a time.
+    fn collect_all<T>(paginator: Paginator<T, Self>) -> Self::Response<Vec<T>>
+    where
+        T: Paginate + DeserializeOwned + Send + 'static;
+}
@@ -1,5 +1,6 @@
 use crate::params::to_snakecase;
-use serde_derive::{Deserialize, Serialize};
+use crate::resources::{PaymentIntent, PaymentMethod, PaymentSource, SetupIntent};
+use serde_derive::Deserialize;
 use std::num::ParseIntError;
 
 /// An error encountered when communicating with the Stripe API.
@@ -78,6 +79,45 @@ impl std::error::Error for Error {
     }
 }
 
+impl Error {
+    /// Returns `true` if the request may succeed when it is sent again, i.e. if the connection
+    /// to Stripe failed or if `RequestError::is_retryable`.
+    ///
+    /// N.B. A `POST` request should be sent again with the same idempotency key
+    ///      (see `Client::with_idempotency_key`), so that it isn't applied twice.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Stripe(err) => err.is_retryable(),
+            Error::Http(_) | Error::Io(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for a `RequestError` which `is_card_error`.
+    pub fn is_card_error(&self) -> bool {
+        match self {
+            Error::Stripe(err) => err.is_card_error(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for a `RequestError` which `is_idempotency_conflict`.
+    pub fn is_idempotency_conflict(&self) -> bool {
+        match self {
+            Error::Stripe(err) => err.is_idempotency_conflict(),
+            _ => false,
+        }
+    }
+
+    /// Returns the `RequestError::user_facing_message` of a card error.
+    pub fn user_facing_message(&self) -> Option<&str> {
+        match self {
+            Error::Stripe(err) => err.user_facing_message(),
+            _ => None,
+        }
+    }
+}
+
 impl From<RequestError> for Error {
     fn from(err: RequestError) -> Error {
         Error::Stripe(err)
@@ -99,8 +139,6 @@ impl From<std::io::Error> for Error {
 /// The list of possible values for a RequestError's type.
 #[derive(Debug, PartialEq, Deserialize)]
 pub enum ErrorType {
-    #[serde(skip_deserializing)]
-    Unknown,
     #[serde(rename = "api_error")]
     Api,
     #[serde(rename = "api_connection_error")]
@@ -109,12 +147,16 @@ pub enum ErrorType {
     Authentication,
     #[serde(rename = "card_error")]
     Card,
+    #[serde(rename = "idempotency_error")]
+    Idempotency,
     #[serde(rename = "invalid_request_error")]
     InvalidRequest,
     #[serde(rename = "rate_limit_error")]
     RateLimit,
     #[serde(rename = "validation_error")]
     Validation,
+    #[serde(other)]
+    Unknown,
 }
 
 impl Default for ErrorType {
@@ -130,8 +172,7 @@ impl std::fmt::Display for ErrorType {
 }
 
 /// The list of possible values for a RequestError's code.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum ErrorCode {
     AccountAlreadyExists,
     AccountCountryInvalidAddress,
@@ -156,6 +197,7 @@ pub enum ErrorCode {
     CustomerMaxSubscriptions,
     EmailInvalid,
     ExpiredCard,
+    IdempotencyKeyInUse,
     IncorrectAddress,
     IncorrectCvc,
     IncorrectNumber,
@@ -209,16 +251,388 @@ pub enum ErrorCode {
     TransfersNotAllowed,
     UpstreamOrderCreationFailed,
     UrlInvalid,
-    #[doc(hidden)]
-    __NonExhaustive,
+    /// A code not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::AccountAlreadyExists => "account_already_exists",
+            ErrorCode::AccountCountryInvalidAddress => "account_country_invalid_address",
+            ErrorCode::AccountInvalid => "account_invalid",
+            ErrorCode::AccountNumberInvalid => "account_number_invalid",
+            ErrorCode::AlipayUpgradeRequired => "alipay_upgrade_required",
+            ErrorCode::AmountTooLarge => "amount_too_large",
+            ErrorCode::AmountTooSmall => "amount_too_small",
+            ErrorCode::ApiKeyExpired => "api_key_expired",
+            ErrorCode::BalanceInsufficient => "balance_insufficient",
+            ErrorCode::BankAccountExists => "bank_account_exists",
+            ErrorCode::BankAccountUnusable => "bank_account_unusable",
+            ErrorCode::BankAccountUnverified => "bank_account_unverified",
+            ErrorCode::BitcoinUpgradeRequired => "bitcoin_upgrade_required",
+            ErrorCode::CardDeclined => "card_declined",
+            ErrorCode::ChargeAlreadyCaptured => "charge_already_captured",
+            ErrorCode::ChargeAlreadyRefunded => "charge_already_refunded",
+            ErrorCode::ChargeDisputed => "charge_disputed",
+            ErrorCode::ChargeExpiredForCapture => "charge_expired_for_capture",
+            ErrorCode::CountryUnsupported => "country_unsupported",
+            ErrorCode::CouponExpired => "coupon_expired",
+            ErrorCode::CustomerMaxSubscriptions => "customer_max_subscriptions",
+            ErrorCode::EmailInvalid => "email_invalid",
+            ErrorCode::ExpiredCard => "expired_card",
+            ErrorCode::IdempotencyKeyInUse => "idempotency_key_in_use",
+            ErrorCode::IncorrectAddress => "incorrect_address",
+            ErrorCode::IncorrectCvc => "incorrect_cvc",
+            ErrorCode::IncorrectNumber => "incorrect_number",
+            ErrorCode::IncorrectZip => "incorrect_zip",
+            ErrorCode::InstantPayoutsUnsupported => "instant_payouts_unsupported",
+            ErrorCode::InvalidCardType => "invalid_card_type",
+            ErrorCode::InvalidChargeAmount => "invalid_charge_amount",
+            ErrorCode::InvalidCvc => "invalid_cvc",
+            ErrorCode::InvalidExpiryMonth => "invalid_expiry_month",
+            ErrorCode::InvalidExpiryYear => "invalid_expiry_year",
+            ErrorCode::InvalidNumber => "invalid_number",
+            ErrorCode::InvalidSourceUsage => "invalid_source_usage",
+            ErrorCode::InvoiceNoCustomerLineItems => "invoice_no_customer_line_items",
+            ErrorCode::InvoiceNoSubscriptionLineItems => "invoice_no_subscription_line_items",
+            ErrorCode::InvoiceNotEditable => "invoice_not_editable",
+            ErrorCode::InvoiceUpcomingNone => "invoice_upcoming_none",
+            ErrorCode::LivemodeMismatch => "livemode_mismatch",
+            ErrorCode::Missing => "missing",
+            ErrorCode::OrderCreationFailed => "order_creation_failed",
+            ErrorCode::OrderRequiredSettings => "order_required_settings",
+            ErrorCode::OrderStatusInvalid => "order_status_invalid",
+            ErrorCode::OrderUpstreamTimeout => "order_upstream_timeout",
+            ErrorCode::OutOfInventory => "out_of_inventory",
+            ErrorCode::ParameterInvalidEmpty => "parameter_invalid_empty",
+            ErrorCode::ParameterInvalidInteger => "parameter_invalid_integer",
+            ErrorCode::ParameterInvalidStringBlank => "parameter_invalid_string_blank",
+            ErrorCode::ParameterInvalidStringEmpty => "parameter_invalid_string_empty",
+            ErrorCode::ParameterMissing => "parameter_missing",
+            ErrorCode::ParameterUnknown => "parameter_unknown",
+            ErrorCode::PaymentMethodUnactivated => "payment_method_unactivated",
+            ErrorCode::PayoutsNotAllowed => "payouts_not_allowed",
+            ErrorCode::PlatformApiKeyExpired => "platform_api_key_expired",
+            ErrorCode::PostalCodeInvalid => "postal_code_invalid",
+            ErrorCode::ProcessingError => "processing_error",
+            ErrorCode::ProductInactive => "product_inactive",
+            ErrorCode::RateLimit => "rate_limit",
+            ErrorCode::ResourceAlreadyExists => "resource_already_exists",
+            ErrorCode::ResourceMissing => "resource_missing",
+            ErrorCode::RoutingNumberInvalid => "routing_number_invalid",
+            ErrorCode::SecretKeyRequired => "secret_key_required",
+            ErrorCode::SepaUnsupportedAccount => "sepa_unsupported_account",
+            ErrorCode::ShippingCalculationFailed => "shipping_calculation_failed",
+            ErrorCode::SkuInactive => "sku_inactive",
+            ErrorCode::StateUnsupported => "state_unsupported",
+            ErrorCode::TaxIdInvalid => "tax_id_invalid",
+            ErrorCode::TaxesCalculationFailed => "taxes_calculation_failed",
+            ErrorCode::TestmodeChargesOnly => "testmode_charges_only",
+            ErrorCode::TlsVersionUnsupported => "tls_version_unsupported",
+            ErrorCode::TokenAlreadyUsed => "token_already_used",
+            ErrorCode::TokenInUse => "token_in_use",
+            ErrorCode::TransfersNotAllowed => "transfers_not_allowed",
+            ErrorCode::UpstreamOrderCreationFailed => "upstream_order_creation_failed",
+            ErrorCode::UrlInvalid => "url_invalid",
+            ErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "account_already_exists" => ErrorCode::AccountAlreadyExists,
+            "account_country_invalid_address" => ErrorCode::AccountCountryInvalidAddress,
+            "account_invalid" => ErrorCode::AccountInvalid,
+            "account_number_invalid" => ErrorCode::AccountNumberInvalid,
+            "alipay_upgrade_required" => ErrorCode::AlipayUpgradeRequired,
+            "amount_too_large" => ErrorCode::AmountTooLarge,
+            "amount_too_small" => ErrorCode::AmountTooSmall,
+            "api_key_expired" => ErrorCode::ApiKeyExpired,
+            "balance_insufficient" => ErrorCode::BalanceInsufficient,
+            "bank_account_exists" => ErrorCode::BankAccountExists,
+            "bank_account_unusable" => ErrorCode::BankAccountUnusable,
+            "bank_account_unverified" => ErrorCode::BankAccountUnverified,
+            "bitcoin_upgrade_required" => ErrorCode::BitcoinUpgradeRequired,
+            "card_declined" => ErrorCode::CardDeclined,
+            "charge_already_captured" => ErrorCode::ChargeAlreadyCaptured,
+            "charge_already_refunded" => ErrorCode::ChargeAlreadyRefunded,
+            "charge_disputed" => ErrorCode::ChargeDisputed,
+            "charge_expired_for_capture" => ErrorCode::ChargeExpiredForCapture,
+            "country_unsupported" => ErrorCode::CountryUnsupported,
+            "coupon_expired" => ErrorCode::CouponExpired,
+            "customer_max_subscriptions" => ErrorCode::CustomerMaxSubscriptions,
+            "email_invalid" => ErrorCode::EmailInvalid,
+            "expired_card" => ErrorCode::ExpiredCard,
+            "idempotency_key_in_use" => ErrorCode::IdempotencyKeyInUse,
+            "incorrect_address" => ErrorCode::IncorrectAddress,
+            "incorrect_cvc" => ErrorCode::IncorrectCvc,
+            "incorrect_number" => ErrorCode::IncorrectNumber,
+            "incorrect_zip" => ErrorCode::IncorrectZip,
+            "instant_payouts_unsupported" => ErrorCode::InstantPayoutsUnsupported,
+            "invalid_card_type" => ErrorCode::InvalidCardType,
+            "invalid_charge_amount" => ErrorCode::InvalidChargeAmount,
+            "invalid_cvc" => ErrorCode::InvalidCvc,
+            "invalid_expiry_month" => ErrorCode::InvalidExpiryMonth,
+            "invalid_expiry_year" => ErrorCode::InvalidExpiryYear,
+            "invalid_number" => ErrorCode::InvalidNumber,
+            "invalid_source_usage" => ErrorCode::InvalidSourceUsage,
+            "invoice_no_customer_line_items" => ErrorCode::InvoiceNoCustomerLineItems,
+            "invoice_no_subscription_line_items" => ErrorCode::InvoiceNoSubscriptionLineItems,
+            "invoice_not_editable" => ErrorCode::InvoiceNotEditable,
+            "invoice_upcoming_none" => ErrorCode::InvoiceUpcomingNone,
+            "livemode_mismatch" => ErrorCode::LivemodeMismatch,
+            "missing" => ErrorCode::Missing,
+            "order_creation_failed" => ErrorCode::OrderCreationFailed,
+            "order_required_settings" => ErrorCode::OrderRequiredSettings,
+            "order_status_invalid" => ErrorCode::OrderStatusInvalid,
+            "order_upstream_timeout" => ErrorCode::OrderUpstreamTimeout,
+            "out_of_inventory" => ErrorCode::OutOfInventory,
+            "parameter_invalid_empty" => ErrorCode::ParameterInvalidEmpty,
+            "parameter_invalid_integer" => ErrorCode::ParameterInvalidInteger,
+            "parameter_invalid_string_blank" => ErrorCode::ParameterInvalidStringBlank,
+            "parameter_invalid_string_empty" => ErrorCode::ParameterInvalidStringEmpty,
+            "parameter_missing" => ErrorCode::ParameterMissing,
+            "parameter_unknown" => ErrorCode::ParameterUnknown,
+            "payment_method_unactivated" => ErrorCode::PaymentMethodUnactivated,
+            "payouts_not_allowed" => ErrorCode::PayoutsNotAllowed,
+            "platform_api_key_expired" => ErrorCode::PlatformApiKeyExpired,
+            "postal_code_invalid" => ErrorCode::PostalCodeInvalid,
+            "processing_error" => ErrorCode::ProcessingError,
+            "product_inactive" => ErrorCode::ProductInactive,
+            "rate_limit" => ErrorCode::RateLimit,
+            "resource_already_exists" => ErrorCode::ResourceAlreadyExists,
+            "resource_missing" => ErrorCode::ResourceMissing,
+            "routing_number_invalid" => ErrorCode::RoutingNumberInvalid,
+            "secret_key_required" => ErrorCode::SecretKeyRequired,
+            "sepa_unsupported_account" => ErrorCode::SepaUnsupportedAccount,
+            "shipping_calculation_failed" => ErrorCode::ShippingCalculationFailed,
+            "sku_inactive" => ErrorCode::SkuInactive,
+            "state_unsupported" => ErrorCode::StateUnsupported,
+            "tax_id_invalid" => ErrorCode::TaxIdInvalid,
+            "taxes_calculation_failed" => ErrorCode::TaxesCalculationFailed,
+            "testmode_charges_only" => ErrorCode::TestmodeChargesOnly,
+            "tls_version_unsupported" => ErrorCode::TlsVersionUnsupported,
+            "token_already_used" => ErrorCode::TokenAlreadyUsed,
+            "token_in_use" => ErrorCode::TokenInUse,
+            "transfers_not_allowed" => ErrorCode::TransfersNotAllowed,
+            "upstream_order_creation_failed" => ErrorCode::UpstreamOrderCreationFailed,
+            "url_invalid" => ErrorCode::UrlInvalid,
+            _ => ErrorCode::Unknown(code.to_string()),
+        }
+    }
 }
 
 impl std::fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(ErrorCode);
+
+/// The list of possible values for a RequestError's decline code, the reason given by the
+/// card issuer for declining a payment.
+///
+/// For more details see https://stripe.com/docs/declines/codes.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DeclineCode {
+    ApproveWithId,
+    AuthenticationRequired,
+    CallIssuer,
+    CardNotSupported,
+    CardVelocityExceeded,
+    CurrencyNotSupported,
+    DoNotHonor,
+    DoNotTryAgain,
+    DuplicateTransaction,
+    ExpiredCard,
+    Fraudulent,
+    GenericDecline,
+    IncorrectCvc,
+    IncorrectNumber,
+    IncorrectPin,
+    IncorrectZip,
+    InsufficientFunds,
+    InvalidAccount,
+    InvalidAmount,
+    InvalidCvc,
+    InvalidExpiryMonth,
+    InvalidExpiryYear,
+    InvalidNumber,
+    InvalidPin,
+    IssuerNotAvailable,
+    LostCard,
+    MerchantBlacklist,
+    NewAccountInformationAvailable,
+    NoActionTaken,
+    NotPermitted,
+    OfflinePinRequired,
+    OnlineOrOfflinePinRequired,
+    PickupCard,
+    PinTryExceeded,
+    ProcessingError,
+    ReenterTransaction,
+    RestrictedCard,
+    RevocationOfAllAuthorizations,
+    RevocationOfAuthorization,
+    SecurityViolation,
+    ServiceNotAllowed,
+    StolenCard,
+    StopPaymentOrder,
+    TestmodeDecline,
+    TransactionNotAllowed,
+    TryAgainLater,
+    WithdrawalCountLimitExceeded,
+    /// A code not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl DeclineCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DeclineCode::ApproveWithId => "approve_with_id",
+            DeclineCode::AuthenticationRequired => "authentication_required",
+            DeclineCode::CallIssuer => "call_issuer",
+            DeclineCode::CardNotSupported => "card_not_supported",
+            DeclineCode::CardVelocityExceeded => "card_velocity_exceeded",
+            DeclineCode::CurrencyNotSupported => "currency_not_supported",
+            DeclineCode::DoNotHonor => "do_not_honor",
+            DeclineCode::DoNotTryAgain => "do_not_try_again",
+            DeclineCode::DuplicateTransaction => "duplicate_transaction",
+            DeclineCode::ExpiredCard => "expired_card",
+            DeclineCode::Fraudulent => "fraudulent",
+            DeclineCode::GenericDecline => "generic_decline",
+            DeclineCode::IncorrectCvc => "incorrect_cvc",
+            DeclineCode::IncorrectNumber => "incorrect_number",
+            DeclineCode::IncorrectPin => "incorrect_pin",
+            DeclineCode::IncorrectZip => "incorrect_zip",
+            DeclineCode::InsufficientFunds => "insufficient_funds",
+            DeclineCode::InvalidAccount => "invalid_account",
+            DeclineCode::InvalidAmount => "invalid_amount",
+            DeclineCode::InvalidCvc => "invalid_cvc",
+            DeclineCode::InvalidExpiryMonth => "invalid_expiry_month",
+            DeclineCode::InvalidExpiryYear => "invalid_expiry_year",
+            DeclineCode::InvalidNumber => "invalid_number",
+            DeclineCode::InvalidPin => "invalid_pin",
+            DeclineCode::IssuerNotAvailable => "issuer_not_available",
+            DeclineCode::LostCard => "lost_card",
+            DeclineCode::MerchantBlacklist => "merchant_blacklist",
+            DeclineCode::NewAccountInformationAvailable => "new_account_information_available",
+            DeclineCode::NoActionTaken => "no_action_taken",
+            DeclineCode::NotPermitted => "not_permitted",
+            DeclineCode::OfflinePinRequired => "offline_pin_required",
+            DeclineCode::OnlineOrOfflinePinRequired => "online_or_offline_pin_required",
+            DeclineCode::PickupCard => "pickup_card",
+            DeclineCode::PinTryExceeded => "pin_try_exceeded",
+            DeclineCode::ProcessingError => "processing_error",
+            DeclineCode::ReenterTransaction => "reenter_transaction",
+            DeclineCode::RestrictedCard => "restricted_card",
+            DeclineCode::RevocationOfAllAuthorizations => "revocation_of_all_authorizations",
+            DeclineCode::RevocationOfAuthorization => "revocation_of_authorization",
+            DeclineCode::SecurityViolation => "security_violation",
+            DeclineCode::ServiceNotAllowed => "service_not_allowed",
+            DeclineCode::StolenCard => "stolen_card",
+            DeclineCode::StopPaymentOrder => "stop_payment_order",
+            DeclineCode::TestmodeDecline => "testmode_decline",
+            DeclineCode::TransactionNotAllowed => "transaction_not_allowed",
+            DeclineCode::TryAgainLater => "try_again_later",
+            DeclineCode::WithdrawalCountLimitExceeded => "withdrawal_count_limit_exceeded",
+            DeclineCode::Unknown(code) => code,
+        }
+    }
+
+    /// Returns `true` if the decline suggests fraud, in which case Stripe recommends
+    /// telling the customer that the card was declined without giving the reason.
+    pub fn is_fraud_related(&self) -> bool {
+        matches!(
+            self,
+            DeclineCode::Fraudulent
+                | DeclineCode::LostCard
+                | DeclineCode::MerchantBlacklist
+                | DeclineCode::PickupCard
+                | DeclineCode::StolenCard
+        )
+    }
+}
+
+impl AsRef<str> for DeclineCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for DeclineCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "approve_with_id" => DeclineCode::ApproveWithId,
+            "authentication_required" => DeclineCode::AuthenticationRequired,
+            "call_issuer" => DeclineCode::CallIssuer,
+            "card_not_supported" => DeclineCode::CardNotSupported,
+            "card_velocity_exceeded" => DeclineCode::CardVelocityExceeded,
+            "currency_not_supported" => DeclineCode::CurrencyNotSupported,
+            "do_not_honor" => DeclineCode::DoNotHonor,
+            "do_not_try_again" => DeclineCode::DoNotTryAgain,
+            "duplicate_transaction" => DeclineCode::DuplicateTransaction,
+            "expired_card" => DeclineCode::ExpiredCard,
+            "fraudulent" => DeclineCode::Fraudulent,
+            "generic_decline" => DeclineCode::GenericDecline,
+            "incorrect_cvc" => DeclineCode::IncorrectCvc,
+            "incorrect_number" => DeclineCode::IncorrectNumber,
+            "incorrect_pin" => DeclineCode::IncorrectPin,
+            "incorrect_zip" => DeclineCode::IncorrectZip,
+            "insufficient_funds" => DeclineCode::InsufficientFunds,
+            "invalid_account" => DeclineCode::InvalidAccount,
+            "invalid_amount" => DeclineCode::InvalidAmount,
+            "invalid_cvc" => DeclineCode::InvalidCvc,
+            "invalid_expiry_month" => DeclineCode::InvalidExpiryMonth,
+            "invalid_expiry_year" => DeclineCode::InvalidExpiryYear,
+            "invalid_number" => DeclineCode::InvalidNumber,
+            "invalid_pin" => DeclineCode::InvalidPin,
+            "issuer_not_available" => DeclineCode::IssuerNotAvailable,
+            "lost_card" => DeclineCode::LostCard,
+            "merchant_blacklist" => DeclineCode::MerchantBlacklist,
+            "new_account_information_available" => DeclineCode::NewAccountInformationAvailable,
+            "no_action_taken" => DeclineCode::NoActionTaken,
+            "not_permitted" => DeclineCode::NotPermitted,
+            "offline_pin_required" => DeclineCode::OfflinePinRequired,
+            "online_or_offline_pin_required" => DeclineCode::OnlineOrOfflinePinRequired,
+            "pickup_card" => DeclineCode::PickupCard,
+            "pin_try_exceeded" => DeclineCode::PinTryExceeded,
+            "processing_error" => DeclineCode::ProcessingError,
+            "reenter_transaction" => DeclineCode::ReenterTransaction,
+            "restricted_card" => DeclineCode::RestrictedCard,
+            "revocation_of_all_authorizations" => DeclineCode::RevocationOfAllAuthorizations,
+            "revocation_of_authorization" => DeclineCode::RevocationOfAuthorization,
+            "security_violation" => DeclineCode::SecurityViolation,
+            "service_not_allowed" => DeclineCode::ServiceNotAllowed,
+            "stolen_card" => DeclineCode::StolenCard,
+            "stop_payment_order" => DeclineCode::StopPaymentOrder,
+            "testmode_decline" => DeclineCode::TestmodeDecline,
+            "transaction_not_allowed" => DeclineCode::TransactionNotAllowed,
+            "try_again_later" => DeclineCode::TryAgainLater,
+            "withdrawal_count_limit_exceeded" => DeclineCode::WithdrawalCountLimitExceeded,
+            _ => DeclineCode::Unknown(code.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for DeclineCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
     }
 }
 
+serde_string_enum!(DeclineCode);
+
 /// An error reported by stripe in a request's response.
 ///
 /// For more details see https://stripe.com/docs/api#errors.
@@ -228,6 +642,16 @@ pub struct RequestError {
     #[serde(skip_deserializing)]
     pub http_status: u16,
 
+    /// The value of the `Request-Id` header in the response, if present.
+    ///
+    /// Stripe support can use it to look up the failed request.
+    #[serde(skip_deserializing)]
+    pub request_id: Option<String>,
+
+    /// The value of the `Stripe-Should-Retry` header in the response, if present.
+    #[serde(skip_deserializing)]
+    pub should_retry: Option<bool>,
+
     /// The type of error returned.
     #[serde(rename = "type")]
     pub error_type: ErrorType,
@@ -240,14 +664,98 @@ pub struct RequestError {
     /// For card errors, a value describing the kind of card error that occured.
     pub code: Option<ErrorCode>,
 
-    /// For card errors resulting from a bank decline, a string indicating the
-    /// bank's reason for the decline if they provide one.
-    pub decline_code: Option<String>,
+    /// For card errors resulting from a bank decline, the bank's reason for
+    /// the decline if they provide one.
+    pub decline_code: Option<DeclineCode>,
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// If the error is parameter-specific, the parameter related to the error.
+    ///
+    /// For example, this can be used to show a message near the correct form field.
+    pub param: Option<String>,
+
+    /// A URL to more information about the error code reported.
+    pub doc_url: Option<String>,
+
+    /// The PaymentIntent object for errors returned on a request involving a PaymentIntent.
+    ///
+    /// For example, a card declined while confirming a PaymentIntent which requires
+    /// authentication, whose `next_action` can be used to authenticate the payment.
+    #[serde(default, deserialize_with = "deserialize_embedded")]
+    pub payment_intent: Option<Box<PaymentIntent>>,
+
+    /// The PaymentMethod object for errors returned on a request involving a PaymentMethod.
+    #[serde(default, deserialize_with = "deserialize_embedded")]
+    pub payment_method: Option<Box<PaymentMethod>>,
+
+    /// The SetupIntent object for errors returned on a request involving a SetupIntent.
+    #[serde(default, deserialize_with = "deserialize_embedded")]
+    pub setup_intent: Option<Box<SetupIntent>>,
+
+    /// The source object for errors returned on a request involving a source.
+    #[serde(default, deserialize_with = "deserialize_embedded")]
+    pub source: Option<Box<PaymentSource>>,
+}
+
+/// Deserializes an object embedded in an error, or `None` if it doesn't parse as the resource
+/// (e.g. because it is partial), so that the rest of the error isn't lost.
+fn deserialize_embedded<'de, D, T>(deserializer: D) -> Result<Option<Box<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value: Option<serde_json::Value> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(value.and_then(|value| serde_json::from_value(value).ok()))
+}
+
+impl RequestError {
+    /// Returns `true` if the request may succeed when it is sent again: if Stripe says so
+    /// in the `Stripe-Should-Retry` header, or else for a `409 Conflict` (e.g. a lock timeout),
+    /// a rate limit error or a `5xx` server error.
+    pub fn is_retryable(&self) -> bool {
+        self.should_retry.unwrap_or_else(|| {
+            self.http_status == 409
+                || self.http_status == 429
+                || self.http_status >= 500
+                || self.error_type == ErrorType::RateLimit
+        })
+    }
+
+    /// Returns `true` if the card was declined or its details are invalid, in which case
+    /// the customer can be asked for another card.
+    pub fn is_card_error(&self) -> bool {
+        self.error_type == ErrorType::Card
+    }
+
+    /// Returns `true` if the request's idempotency key was already used, either by a request
+    /// with other parameters or by a request which is still in progress.
+    pub fn is_idempotency_conflict(&self) -> bool {
+        self.error_type == ErrorType::Idempotency
+            || self.code == Some(ErrorCode::IdempotencyKeyInUse)
+    }
+
+    /// Returns a message describing a card error which can be shown to the customer.
+    ///
+    /// A decline which suggests fraud (e.g. a `stolen_card`) is described as a generic decline,
+    /// as Stripe recommends.  Other errors return `None`, since their message is meant for
+    /// the developer.
+    pub fn user_facing_message(&self) -> Option<&str> {
+        if !self.is_card_error() {
+            return None;
+        }
+        match (&self.decline_code, &self.message) {
+            (Some(code), _) if code.is_fraud_related() => Some(GENERIC_DECLINE_MESSAGE),
+            (_, Some(message)) => Some(message),
+            (_, None) => Some(GENERIC_DECLINE_MESSAGE),
+        }
+    }
 }
 
+/// The message Stripe gives for a `generic_decline`.
+const GENERIC_DECLINE_MESSAGE: &str = "Your card was declined.";
+
 impl std::fmt::Display for RequestError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}({})", self.error_type, self.http_status)?;
@@ -285,6 +793,24 @@ pub enum WebhookError {
     BadTimestamp(i64),
     /// An error deserializing an event received from stripe.
     BadParse(serde_json::Error),
+    /// The body of a request received by a webhook endpoint could not be read.
+    BadBody(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl WebhookError {
+    /// Returns the http status which a webhook endpoint should respond with when an event
+    /// it received fails with this error.
+    ///
+    /// N.B. Stripe retries sending an event until the endpoint responds with a `2xx` status.
+    /// So an event which was signed by Stripe but can't be parsed (`BadParse`) is acknowledged
+    /// with `200`, since sending it again wouldn't help.
+    pub fn status_code(&self) -> u16 {
+        match *self {
+            WebhookError::BadKey => 500,
+            WebhookError::BadParse(_) => 200,
+            _ => 400,
+        }
+    }
 }
 
 impl std::fmt::Display for WebhookError {
@@ -296,6 +822,7 @@ impl std::fmt::Display for WebhookError {
             WebhookError::BadSignature => Ok(()),
             WebhookError::BadTimestamp(ref err) => write!(f, ": {}", err),
             WebhookError::BadParse(ref err) => write!(f, ": {}", err),
+            WebhookError::BadBody(ref err) => write!(f, ": {}", err),
         }
     }
 }
@@ -308,6 +835,7 @@ impl std::error::Error for WebhookError {
             WebhookError::BadSignature => "error comparing signatures",
             WebhookError::BadTimestamp(_) => "error comparing timestamps - over tolerance",
             WebhookError::BadParse(_) => "error parsing event object",
+            WebhookError::BadBody(_) => "error reading request body",
         }
     }
 
@@ -318,6 +846,7 @@ impl std::error::Error for WebhookError {
             WebhookError::BadSignature => None,
             WebhookError::BadTimestamp(_) => None,
             WebhookError::BadParse(ref err) => Some(err),
+            WebhookError::BadBody(ref err) => Some(&**err),
         }
     }
 }
@@ -461,8 +461,10 @@ def_id!(CardTokenId, "tok_");
 def_id!(ChargeId, "ch_");
 def_id!(CheckoutSessionId, "cs_");
 def_id!(CouponId: String); // N.B. A coupon id can be user-provided so can be any arbitrary string
+def_id!(CreditNoteId, "cn_");
 def_id!(CustomerId, "cus_");
 def_id!(DisputeId, "dp_");
+def_id!(EventId, "evt_");
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
 def_id!(InvoiceId, "in_");
@@ -472,6 +474,7 @@ def_id!(IssuingAuthorizationId, "iauth_");
 def_id!(IssuingCardholderId, "ich_");
 def_id!(IssuingCardId, "ic_");
 def_id!(IssuingDisputeId, "idp_");
+def_id!(IssuingSettlementId: String); // N.B. The prefix of a settlement id isn't documented yet
 def_id!(OrderId, "or_");
 def_id!(IssuingTransactionId, "ipi_");
 def_id!(OrderReturnId, "orret_");
@@ -498,10 +501,14 @@ def_id!(PlanId: String); // N.B. A plan id can be user-provided so can be any ar
 def_id!(ProductId, "prod_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_");
+def_id!(ReportingReportRunId, "frr_");
+def_id!(ReportingReportTypeId: String); // N.B. A report type id is a name, e.g. `balance.summary.1`
 def_id!(ReviewId, "prv_");
 def_id!(ScheduledQueryRunId, "sqr_");
 def_id!(SkuId, "sku_");
 def_id!(SourceId, "src_");
+def_id!(SourceMandateNotificationId, "srcmn_");
+def_id!(SourceTransactionId, "srctxn_");
 def_id!(SubscriptionId, "sub_");
 def_id!(SubscriptionItemId, "si_");
 def_id!(TaxIdId, "txi_");
@@ -51,94 +51,75 @@
 //! let charges = stripe::Charge::list(&client, params).unwrap();
 //! println!("{:?}", charges); // =>  List { data: [Charge { id: "ch_12345", .. }] }
 //! ```
+//!
+//! With the `async` feature enabled, the same requests can be made with a
+//! `stripe::r#async::Client`, in which case they return a future to `.await`.
+//! The blocking and async clients can be used together in the same build.
+//!
+//! With the `hyper` feature enabled, a webhook endpoint built on `hyper` (or on the `http`
+//! crate's `Request`, e.g. with `axum` or `warp`) can verify and parse the events it receives
+//! with `WebhookVerifier::receive` or `WebhookVerifier::verify_request`.  With the `axum`
+//! feature, an `axum` handler can take a verified `StripeEvent` as an argument.  There is no
+//! integration for `actix-web`, whose requests aren't built on `http`; verify the body it read
+//! with `WebhookVerifier::construct_event` instead.
 
 #![allow(clippy::needless_pass_by_value)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 
-#[cfg(not(feature = "async"))]
+// N.B. Declared first, so that its macros can be used by the other modules.
+#[macro_use]
+mod params;
+mod builder;
+#[cfg(feature = "blocking")]
 mod client;
+mod config;
 mod error;
 mod ids;
-mod params;
+mod observer;
 mod resources;
+mod retry;
+mod transport;
+#[cfg(feature = "webhooks")]
+mod webhooks;
 
 extern crate strum;
 #[macro_use]
 extern crate strum_macros;
 
-// N.B. Ideally we would support both a blocking client and
-//      an async client without a feature flag, but the originally
-//      discussed solution requires Generic Associated Types--
-//      instead we provide an async client only a feature flag.
+// N.B. The blocking client and the async client can be enabled together;
+//      the resource methods are generic over the `StripeClient` trait
+//      (which relies on Generic Associated Types) to support both.
 //
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
-// See https://github.com/rust-lang/rust/issues/44265
 #[cfg(feature = "async")]
 pub mod r#async;
-#[cfg(not(feature = "async"))]
-pub use crate::client::Client;
+#[cfg(feature = "blocking")]
+pub use crate::client::{Client, Response};
 
-pub use crate::error::{Error, ErrorCode, ErrorType, RequestError, WebhookError};
+#[cfg(not(any(feature = "blocking", feature = "async")))]
+compile_error!("either the `blocking` or the `async` feature must be enabled");
+
+pub use crate::builder::ClientBuilder;
+pub use crate::config::StripeClient;
+pub use crate::error::{DeclineCode, Error, ErrorCode, ErrorType, RequestError, WebhookError};
 pub use crate::ids::*;
+pub use crate::observer::{RequestLog, RequestObserver};
 pub use crate::params::{
     Deleted,
     Expandable, Expand, Object, Paginate,
     RangeBounds, RangeQuery, Timestamp,
-    Headers, List, Metadata,
+    Headers, List, Metadata, Paginator, ResponseMeta,
 };
 pub use crate::resources::*;
-
-#[cfg(not(feature = "async"))]
-mod config {
-    use crate::error::Error;
-
-    /// An alias for `Result`.
-    ///
-    /// ```rust,ignore
-    /// type Response<T> = Result<T, Error>;
-    /// ```
-    ///
-    /// If the `async` feature is enabled, this type is redefined as:
-    ///
-    /// ```rust,ignore
-    /// type Response<T> = Box<dyn Future<Item = T, Error = Error> + Send>
-    /// ```
-    pub type Response<T> = Result<T, Error>;
-
-    pub(crate) type Client = crate::client::Client;
-
-    #[inline]
-    pub fn ok<T>(ok: T) -> Response<T> {
-        Ok(ok)
-    }
-
-    #[inline]
-    pub fn err<T>(err: Error) -> Response<T> {
-        Err(err)
-    }
-}
-
-#[cfg(feature = "async")]
-mod config {
-    use crate::error::Error;
-    use futures::future::{self, Future};
-
-    // TODO: We'd rather use `impl Future<Result<T, Error>>` but that isn't so
-    //       easy to accomplish in generic code with futures 0.1.x
-    pub type Response<T> = Box<dyn Future<Item = T, Error = Error> + Send>;
-
-    pub(crate) type Client = crate::r#async::Client;
-
-    #[inline]
-    pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
-        Box::new(future::ok(ok))
-    }
-
-    #[inline]
-    pub(crate) fn err<T: Send + 'static>(err: Error) -> Response<T> {
-        Box::new(future::err(err))
-    }
-}
-
-// N.B. export for doc purposes
-pub use self::config::Response;
+pub use crate::retry::RetryPolicy;
+pub use crate::transport::{HttpRequest, HttpResponse, ReplayTransport};
+#[cfg(feature = "blocking")]
+pub use crate::transport::Transport;
+#[cfg(feature = "webhooks")]
+pub use crate::webhooks::{
+    DedupState, EventDedup, EventRouter, InMemoryDedup, RouteError, Routed, Webhook,
+    WebhookVerifier,
+};
+#[cfg(feature = "axum")]
+pub use crate::webhooks::StripeEvent;
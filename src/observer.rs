@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+/// Receives a `RequestLog` for every attempt a client makes to send a request to Stripe.
+///
+/// Closures taking a `&RequestLog` implement this trait, so logging every request
+/// can be as simple as:
+///
+/// ```rust
+/// let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET")
+///     .with_observer(|log: &stripe::RequestLog| eprintln!("{:?}", log));
+/// ```
+pub trait RequestObserver: Send + Sync {
+    fn observe(&self, log: &RequestLog);
+}
+
+impl<F> RequestObserver for F
+where
+    F: Fn(&RequestLog) + Send + Sync,
+{
+    fn observe(&self, log: &RequestLog) {
+        self(log)
+    }
+}
+
+/// A record of one attempt to send a request to Stripe.
+///
+/// Card numbers, CVCs and secret keys are redacted from the `path` and `body`
+/// so that the record is safe to write to a log.
+#[derive(Clone, Debug)]
+pub struct RequestLog {
+    /// The HTTP method of the request (e.g. `POST`).
+    pub method: String,
+    /// The path and query string of the request (e.g. `/v1/charges?limit=3`).
+    pub path: String,
+    /// The urlencoded form sent as the request body, if any.
+    pub body: Option<String>,
+    /// The HTTP status of the response, or `None` if no response was received.
+    pub status: Option<u16>,
+    /// The time between sending the request and receiving the response.
+    pub latency: Duration,
+    /// The value of the `Request-Id` header in the response, if present.
+    pub request_id: Option<String>,
+    /// The attempt number (starting at 1) if the request is retried.
+    pub attempt: u32,
+}
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Form fields (by the last segment of their name) whose values are always redacted.
+const SENSITIVE_FIELDS: &[&str] = &["number", "cvc", "account_number"];
+
+/// Redacts sensitive values from a urlencoded form or query string.
+///
+/// The values of `number`, `cvc` and `account_number` fields are always redacted,
+/// as is any value which looks like a secret key or a card number.
+pub(crate) fn redact(form: &str) -> String {
+    form.split('&')
+        .map(|pair| {
+            let mut key_and_value = pair.splitn(2, '=');
+            let key = key_and_value.next().unwrap_or_default();
+            match key_and_value.next() {
+                Some(value) if is_sensitive(key, value) => format!("{}={}", key, REDACTED),
+                _ => pair.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Redacts sensitive values from the query string of a path.
+pub(crate) fn redact_path(path: &str, query: Option<&str>) -> String {
+    match query {
+        Some(query) => format!("{}?{}", path, redact(query)),
+        None => path.to_string(),
+    }
+}
+
+fn is_sensitive(key: &str, value: &str) -> bool {
+    let key = key.replace("%5B", "[").replace("%5D", "]");
+    let field = key.trim_end_matches(']').rsplit('[').next().unwrap_or_default();
+    if SENSITIVE_FIELDS.contains(&field) {
+        return true;
+    }
+
+    let is_secret_key = ["sk_live_", "sk_test_", "rk_live_", "rk_test_", "whsec_"]
+        .iter()
+        .any(|prefix| value.starts_with(prefix));
+    let is_card_number = (12..=19).contains(&value.len()) && value.bytes().all(|b| b.is_ascii_digit());
+    is_secret_key || is_card_number
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redact, redact_path};
+
+    #[test]
+    fn redacts_card_details() {
+        assert_eq!(
+            redact("amount=1000&source%5Bobject%5D=card&source%5Bnumber%5D=4242424242424242&source%5Bcvc%5D=123&source%5Bexp_month%5D=12"),
+            "amount=1000&source%5Bobject%5D=card&source%5Bnumber%5D=[REDACTED]&source%5Bcvc%5D=[REDACTED]&source%5Bexp_month%5D=12"
+        );
+        assert_eq!(redact("card[number]=4000056655665556"), "card[number]=[REDACTED]");
+        assert_eq!(redact("bank_account[account_number]=000123456789"), "bank_account[account_number]=[REDACTED]");
+    }
+
+    #[test]
+    fn redacts_values_which_look_sensitive() {
+        assert_eq!(redact("metadata%5Bnote%5D=4242424242424242"), "metadata%5Bnote%5D=[REDACTED]");
+        assert_eq!(redact("metadata%5Bkey%5D=sk_live_abc123&limit=3"), "metadata%5Bkey%5D=[REDACTED]&limit=3");
+        assert_eq!(redact("email=jdoe%40example.org&amount=123456"), "email=jdoe%40example.org&amount=123456");
+        assert_eq!(redact(""), "");
+    }
+
+    #[test]
+    fn redacts_query_strings() {
+        assert_eq!(redact_path("/v1/customers", None), "/v1/customers");
+        assert_eq!(
+            redact_path("/v1/customers", Some("limit=3&email=sk_test_123")),
+            "/v1/customers?limit=3&email=[REDACTED]"
+        );
+    }
+}
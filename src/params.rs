@@ -1,8 +1,13 @@
-use crate::config::{err, ok, Client, Response};
+use crate::config::StripeClient;
 use crate::error::Error;
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::Url;
 use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Default)]
 pub struct Headers {
@@ -10,6 +15,42 @@ pub struct Headers {
     pub client_id: Option<String>,
 }
 
+/// Metadata about a response received from Stripe.
+///
+/// See `Client::with_response_meta` for how to obtain it alongside a response object.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    /// The value of the `Request-Id` header, which identifies the request to Stripe support.
+    pub request_id: Option<String>,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// All headers included in the response.
+    pub headers: HeaderMap,
+    /// Whether Stripe replayed the result of an earlier request with the same Idempotency-Key.
+    pub idempotent_replayed: bool,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(status: u16, headers: &HeaderMap) -> ResponseMeta {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        ResponseMeta {
+            request_id: header("request-id").map(str::to_string),
+            status,
+            headers: headers.clone(),
+            idempotent_replayed: header("idempotent-replayed") == Some("true"),
+        }
+    }
+
+    /// Returns the value of a response header (e.g. `Stripe-Version`), if it is valid text.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Shared between a client and the caller of `with_response_meta` to hand over
+/// the metadata of the last response received by the client.
+pub(crate) type ResponseMetaSlot = Arc<Mutex<Option<ResponseMeta>>>;
+
 /// Implemented by types which represent stripe objects.
 pub trait Object {
     /// The canonical id type for this object.
@@ -158,57 +199,285 @@ impl<T: Clone> Clone for List<T> {
 }
 
 impl<T: DeserializeOwned + Send + 'static> List<T> {
-    /// Prefer `List::next` when possible
-    pub fn get_next(client: &Client, url: &str, last_id: &str) -> Response<List<T>> {
-        if url.starts_with("/v1/") {
-            // TODO: Maybe parse the URL?  Perhaps `List` should always parse its `url` field.
-            let mut url = url.trim_start_matches("/v1/").to_string();
-            if url.contains('?') {
-                url.push_str(&format!("&starting_after={}", last_id));
-            } else {
-                url.push_str(&format!("?starting_after={}", last_id));
+    /// Requests the first page of the list at `path`.
+    ///
+    /// The `params` are kept in the `url` of the list, so that the same filters (and `limit`)
+    /// are used to request the pages after or before it.
+    pub(crate) fn get_query<C: StripeClient, P: serde::Serialize>(
+        client: &C,
+        path: &str,
+        params: P,
+    ) -> C::Response<List<T>> {
+        let query = match serde_qs::to_string(&params) {
+            Ok(query) => query,
+            Err(err) => return C::err(Error::serialize(err)),
+        };
+        C::and_then(client.get_query(path, params), move |mut list: List<T>| {
+            if !query.is_empty() && !list.url.contains('?') {
+                list.url = format!("{}?{}", list.url, query);
             }
-            client.get(&url)
-        } else {
-            err(Error::Unsupported("URL for fetching additional data uses different API version"))
+            C::ok(list)
+        })
+    }
+
+    /// Prefer `List::next` when possible
+    pub fn get_next<C: StripeClient>(client: &C, url: &str, last_id: &str) -> C::Response<List<T>> {
+        List::get_page(client, url, ("starting_after", last_id), None)
+    }
+
+    /// Prefer `List::previous` when possible
+    pub fn get_previous<C: StripeClient>(
+        client: &C,
+        url: &str,
+        first_id: &str,
+    ) -> C::Response<List<T>> {
+        List::get_page(client, url, ("ending_before", first_id), None)
+    }
+
+    fn get_page<C: StripeClient>(
+        client: &C,
+        url: &str,
+        cursor: (&str, &str),
+        limit: Option<usize>,
+    ) -> C::Response<List<T>> {
+        let path = match page_path(url, cursor, limit) {
+            Ok(path) => path,
+            Err(err) => return C::err(err),
+        };
+        // N.B. Stripe's `url` omits the parameters, which are kept for the page after this one
+        let url = format!("/v1{}", path);
+        C::and_then(client.get(&path), move |mut list: List<T>| {
+            list.url = url;
+            C::ok(list)
+        })
+    }
+}
+
+/// Returns the path (relative to `/v1`) to request the page next to `cursor` of the list at `url`.
+///
+/// Any cursor already in `url` is replaced, while its other parameters are kept.
+/// If `url` doesn't include a `limit`, the `limit` given is used instead.
+fn page_path(url: &str, cursor: (&str, &str), limit: Option<usize>) -> Result<String, Error> {
+    let mut url = Url::parse("https://api.stripe.com/")
+        .and_then(|base| base.join(url))
+        .map_err(Error::deserialize)?;
+    if !url.path().starts_with("/v1/") {
+        return Err(Error::Unsupported(
+            "URL for fetching additional data uses different API version",
+        ));
+    }
+
+    let mut params: Vec<(String, String)> = url
+        .query_pairs()
+        .into_owned()
+        .filter(|(name, _)| name != "starting_after" && name != "ending_before")
+        .collect();
+    if let Some(limit) = limit {
+        if !params.iter().any(|(name, _)| name == "limit") {
+            params.push(("limit".into(), limit.to_string()));
         }
     }
+    params.push((cursor.0.into(), cursor.1.into()));
+    url.query_pairs_mut().clear().extend_pairs(params);
+
+    let path = url.path().trim_start_matches("/v1");
+    Ok(format!("{}?{}", path, url.query().unwrap_or_default()))
 }
 
 impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
-    /// Repeatedly queries Stripe for more data until all elements in list are fetched, using
-    /// Stripe's default page size.
+    /// Repeatedly queries Stripe for more data until all elements in list are fetched, with the
+    /// same page size as this page.
     ///
-    /// Not supported by `stripe::async::Client`.
-    #[cfg(not(feature = "async"))]
-    pub fn get_all(self, client: &Client) -> Response<Vec<T>> {
-        let mut data = Vec::new();
-        let mut next = self;
-        loop {
-            if next.has_more {
-                let resp = next.next(&client)?;
-                data.extend(next.data);
-                next = resp;
-            } else {
-                data.extend(next.data);
-                break;
-            }
-        }
-        Ok(data)
+    /// The pages are fetched one after the other by `List::paginate`, so fetching a long list
+    /// doesn't nest requests.
+    pub fn get_all<C: StripeClient>(self, client: &C) -> C::Response<Vec<T>> {
+        C::collect_all(self.paginate(client))
     }
 
     /// Fetch an additional page of data from stripe.
-    pub fn next(&self, client: &Client) -> Response<List<T>> {
+    pub fn next<C: StripeClient>(&self, client: &C) -> C::Response<List<T>> {
         if let Some(last_id) = self.data.last().map(|d| d.cursor()) {
             List::get_next(client, &self.url, last_id.as_ref())
         } else {
-            ok(List {
-                object: self.object.clone(),
-                data: Vec::new(),
-                has_more: false,
-                total_count: self.total_count,
-                url: self.url.clone(),
-            })
+            C::ok(self.empty())
+        }
+    }
+
+    /// Fetch the page of data before this one from stripe.
+    ///
+    /// N.B. For a page fetched backwards, `has_more` tells whether there are more elements
+    /// before it (rather than after it).
+    pub fn previous<C: StripeClient>(&self, client: &C) -> C::Response<List<T>> {
+        if let Some(first_id) = self.data.first().map(|d| d.cursor()) {
+            List::get_previous(client, &self.url, first_id.as_ref())
+        } else {
+            C::ok(self.empty())
+        }
+    }
+
+    fn empty(&self) -> List<T> {
+        List {
+            object: self.object.clone(),
+            data: Vec::new(),
+            has_more: false,
+            total_count: self.total_count,
+            url: self.url.clone(),
+        }
+    }
+}
+
+impl<T> List<T> {
+    /// Returns an iterator (or with the async client, a stream) over every element of the list,
+    /// starting with the elements of this page.
+    ///
+    /// Each following page is only fetched once the elements before it have been consumed,
+    /// with the same page size as this page.  Once a page fails to load, the error is returned
+    /// as the last item.
+    ///
+    /// ```rust,no_run
+    /// # let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET");
+    /// let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).unwrap();
+    /// for coupon in coupons.paginate(&client) {
+    ///     println!("{}", coupon.unwrap().id);
+    /// }
+    /// ```
+    pub fn paginate<C: StripeClient>(self, client: &C) -> Paginator<T, C>
+    where
+        T: Paginate,
+    {
+        let mut paginator = Paginator {
+            client: client.clone(),
+            data: Vec::new().into_iter(),
+            url: String::new(),
+            cursor: None,
+            limit: None,
+            has_more: false,
+            backward: false,
+            #[cfg(feature = "async")]
+            pending: None,
+        };
+        paginator.set_page(self);
+        paginator
+    }
+}
+
+/// Iterates over the elements of a `List` and its following pages.
+///
+/// This is an `Iterator` for the blocking `Client` and a `Stream` for the async client.
+/// See `List::paginate`.
+pub struct Paginator<T, C> {
+    client: C,
+    data: std::vec::IntoIter<T>,
+    url: String,
+    cursor: Option<String>,
+    limit: Option<usize>,
+    has_more: bool,
+    backward: bool,
+    #[cfg(feature = "async")]
+    pending: Option<crate::r#async::Response<List<T>>>,
+}
+
+// N.B. No field is ever pinned, the pending future is boxed.
+impl<T, C> Unpin for Paginator<T, C> {}
+
+impl<T, C: StripeClient> Paginator<T, C> {
+    /// Creates a paginator over the elements which come before `cursor` in the list at `url`,
+    /// in reverse order (e.g. from the oldest to the newest element created after `cursor`).
+    pub(crate) fn before(client: &C, url: String, cursor: String) -> Paginator<T, C> {
+        Paginator {
+            client: client.clone(),
+            data: Vec::new().into_iter(),
+            url,
+            cursor: Some(cursor),
+            limit: None,
+            has_more: true,
+            backward: true,
+            #[cfg(feature = "async")]
+            pending: None,
+        }
+    }
+}
+
+impl<T: Paginate, C> Paginator<T, C> {
+    fn set_page(&mut self, page: List<T>) {
+        let mut data = page.data;
+        if self.backward {
+            // N.B. A page fetched backwards is still in the order of the list
+            data.reverse();
+        }
+        self.cursor = data.last().map(|last| last.cursor().as_ref().to_string());
+        self.has_more = page.has_more;
+        if page.has_more {
+            // A page which has more after it is full, so its length is the requested limit
+            self.limit = Some(data.len());
+        }
+        self.url = page.url;
+        self.data = data.into_iter();
+    }
+
+    /// Returns the cursor for the next page, if there is one left to fetch.
+    fn next_cursor(&mut self) -> Option<(&'static str, String)> {
+        if self.has_more {
+            self.has_more = false;
+            let name = if self.backward { "ending_before" } else { "starting_after" };
+            self.cursor.take().map(|cursor| (name, cursor))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: Paginate + DeserializeOwned + Send + 'static> Iterator for Paginator<T, crate::Client> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.data.next() {
+                return Some(Ok(item));
+            }
+            let (name, cursor) = self.next_cursor()?;
+            match List::get_page(&self.client, &self.url, (name, &cursor), self.limit) {
+                Ok(page) => self.set_page(page),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> futures_core::Stream for Paginator<T, crate::r#async::Client>
+where
+    T: Paginate + DeserializeOwned + Send + 'static,
+{
+    type Item = Result<T, Error>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        loop {
+            if let Some(pending) = &mut this.pending {
+                let page = futures_core::ready!(pending.as_mut().poll(cx));
+                this.pending = None;
+                match page {
+                    Ok(page) => this.set_page(page),
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+            }
+            if let Some(item) = this.data.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            match this.next_cursor() {
+                Some((name, cursor)) => {
+                    let page = List::get_page(&this.client, &this.url, (name, &cursor), this.limit);
+                    this.pending = Some(page);
+                }
+                None => return Poll::Ready(None),
+            }
         }
     }
 }
@@ -233,6 +502,22 @@ impl<T> Default for RangeBounds<T> {
 
 /// A set of generic request parameters that can be used on
 /// list endpoints to filter their results by some timestamp.
+///
+/// A `RangeQuery` can also be converted from a range (e.g. `start..end`), and a
+/// `RangeQuery<Timestamp>` from a `chrono::DateTime<Utc>` or a range of them.  As Stripe's
+/// timestamps are whole seconds, a time with a fraction of a second is rounded to the seconds
+/// within the range (e.g. `..end` is `lt` the second after `end`).
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use stripe::{ListCustomers, RangeQuery};
+///
+/// let mut params = ListCustomers::new();
+/// // The customers created in June 2019
+/// let june = Utc.with_ymd_and_hms(2019, 6, 1, 0, 0, 0).unwrap();
+/// let july = Utc.with_ymd_and_hms(2019, 7, 1, 0, 0, 0).unwrap();
+/// params.created = Some(RangeQuery::from(june..july));
+/// ```
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum RangeQuery<T> {
@@ -248,33 +533,137 @@ impl<T> RangeQuery<T> {
 
     /// Filter results to be after a given value
     pub fn gt(value: T) -> RangeQuery<T> {
-        let mut bounds = RangeBounds::default();
-        bounds.gt = Some(value);
-        RangeQuery::Bounds(bounds)
+        RangeQuery::Bounds(RangeBounds { gt: Some(value), ..Default::default() })
     }
 
     /// Filter results to be after or equal to a given value
     pub fn gte(value: T) -> RangeQuery<T> {
-        let mut bounds = RangeBounds::default();
-        bounds.gte = Some(value);
-        RangeQuery::Bounds(bounds)
+        RangeQuery::Bounds(RangeBounds { gte: Some(value), ..Default::default() })
     }
 
-    /// Filter results to be before to a given value
+    /// Filter results to be before a given value
     pub fn lt(value: T) -> RangeQuery<T> {
-        let mut bounds = RangeBounds::default();
-        bounds.gt = Some(value);
-        RangeQuery::Bounds(bounds)
+        RangeQuery::Bounds(RangeBounds { lt: Some(value), ..Default::default() })
     }
 
     /// Filter results to be before or equal to a given value
     pub fn lte(value: T) -> RangeQuery<T> {
-        let mut bounds = RangeBounds::default();
-        bounds.gte = Some(value);
-        RangeQuery::Bounds(bounds)
+        RangeQuery::Bounds(RangeBounds { lte: Some(value), ..Default::default() })
+    }
+}
+
+/// Filter results to be after or equal to `start` and before `end` (e.g. `start..end`).
+impl<T> From<Range<T>> for RangeQuery<T> {
+    fn from(range: Range<T>) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds {
+            gte: Some(range.start),
+            lt: Some(range.end),
+            ..RangeBounds::default()
+        })
+    }
+}
+
+/// Filter results to be after or equal to `start` and before or equal to `end`
+/// (e.g. `start..=end`).
+impl<T> From<RangeInclusive<T>> for RangeQuery<T> {
+    fn from(range: RangeInclusive<T>) -> RangeQuery<T> {
+        let (start, end) = range.into_inner();
+        RangeQuery::Bounds(RangeBounds {
+            gte: Some(start),
+            lte: Some(end),
+            ..RangeBounds::default()
+        })
+    }
+}
+
+/// Filter results to be after or equal to `start` (e.g. `start..`).
+impl<T> From<RangeFrom<T>> for RangeQuery<T> {
+    fn from(range: RangeFrom<T>) -> RangeQuery<T> {
+        RangeQuery::gte(range.start)
+    }
+}
+
+/// Filter results to be before `end` (e.g. `..end`).
+impl<T> From<RangeTo<T>> for RangeQuery<T> {
+    fn from(range: RangeTo<T>) -> RangeQuery<T> {
+        RangeQuery::lt(range.end)
     }
 }
 
+/// Filter results to be before or equal to `end` (e.g. `..=end`).
+impl<T> From<RangeToInclusive<T>> for RangeQuery<T> {
+    fn from(range: RangeToInclusive<T>) -> RangeQuery<T> {
+        RangeQuery::lte(range.end)
+    }
+}
+
+// N.B. The timestamps of Stripe objects are whole seconds, so the conversions from `chrono`
+//      times below round a time with a fraction of a second to the second which matches the
+//      same objects: up for a lower bound or an exclusive upper bound, and down (truncating
+//      it) for an inclusive upper bound or an exact time.
+
+/// Returns the timestamp of the first whole second at or after `time`.
+fn timestamp_ceil(time: &DateTime<Utc>) -> Timestamp {
+    time.timestamp() + Timestamp::from(time.timestamp_subsec_nanos() > 0)
+}
+
+/// Filter results to exactly match a given time.
+impl From<DateTime<Utc>> for RangeQuery<Timestamp> {
+    fn from(time: DateTime<Utc>) -> RangeQuery<Timestamp> {
+        RangeQuery::eq(time.timestamp())
+    }
+}
+
+impl From<Range<DateTime<Utc>>> for RangeQuery<Timestamp> {
+    fn from(range: Range<DateTime<Utc>>) -> RangeQuery<Timestamp> {
+        RangeQuery::from(timestamp_ceil(&range.start)..timestamp_ceil(&range.end))
+    }
+}
+
+impl From<RangeInclusive<DateTime<Utc>>> for RangeQuery<Timestamp> {
+    fn from(range: RangeInclusive<DateTime<Utc>>) -> RangeQuery<Timestamp> {
+        RangeQuery::from(timestamp_ceil(range.start())..=range.end().timestamp())
+    }
+}
+
+impl From<RangeFrom<DateTime<Utc>>> for RangeQuery<Timestamp> {
+    fn from(range: RangeFrom<DateTime<Utc>>) -> RangeQuery<Timestamp> {
+        RangeQuery::gte(timestamp_ceil(&range.start))
+    }
+}
+
+impl From<RangeTo<DateTime<Utc>>> for RangeQuery<Timestamp> {
+    fn from(range: RangeTo<DateTime<Utc>>) -> RangeQuery<Timestamp> {
+        RangeQuery::lt(timestamp_ceil(&range.end))
+    }
+}
+
+impl From<RangeToInclusive<DateTime<Utc>>> for RangeQuery<Timestamp> {
+    fn from(range: RangeToInclusive<DateTime<Utc>>) -> RangeQuery<Timestamp> {
+        RangeQuery::lte(range.end.timestamp())
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for an enum of string values with its `as_str`
+/// and `From<&str>`, so that a value unknown to the library is kept in its `Unknown` variant
+/// instead of failing to deserialize.
+macro_rules! serde_string_enum {
+    ($enum_name:ident) => {
+        impl serde::Serialize for $enum_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value: String = serde::Deserialize::deserialize(deserializer)?;
+                Ok($enum_name::from(value.as_str()))
+            }
+        }
+    };
+}
+
 // NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
 //   This function is used to convert static Rust identifiers to snakecase
 // TODO: pub(crate) fn
@@ -309,4 +698,24 @@ mod tests {
         assert_eq!(to_snakecase("UPPER").as_str(), "upper");
         assert_eq!(to_snakecase("lower").as_str(), "lower");
     }
+
+    #[test]
+    fn page_path() {
+        use super::page_path;
+
+        let url = "/v1/charges?customer=cus_1&starting_after=ch_1";
+        assert_eq!(
+            page_path(url, ("starting_after", "ch_2"), None).unwrap(),
+            "/charges?customer=cus_1&starting_after=ch_2"
+        );
+        assert_eq!(
+            page_path(url, ("ending_before", "ch_0"), Some(3)).unwrap(),
+            "/charges?customer=cus_1&limit=3&ending_before=ch_0"
+        );
+        assert_eq!(
+            page_path("/v1/charges?limit=5", ("starting_after", "ch_2"), Some(3)).unwrap(),
+            "/charges?limit=5&starting_after=ch_2"
+        );
+        assert!(page_path("/v2/charges", ("starting_after", "ch_2"), None).is_err());
+    }
 }
@@ -13,13 +13,16 @@ mod charge_v2;
 // mod charge;
 mod connect_collection_transfer;
 mod coupon;
+mod credit_note;
 mod currency;
 mod customer_v2;
 // mod customer;
 mod customer_ext;
 mod discount;
 mod dispute;
+#[macro_use]
 mod event;
+mod event_types;
 mod fee_refund;
 mod file;
 mod file_link;
@@ -30,10 +33,12 @@ mod issuing_authorization;
 mod issuing_card;
 mod issuing_cardholder;
 mod issuing_dispute;
+mod issuing_settlement;
 mod issuing_merchant_data;
 mod issuing_transaction;
 mod line_item;
 mod line_item_ext;
+mod money;
 mod order;
 mod order_item;
 mod order_return;
@@ -49,6 +54,8 @@ mod plan;
 mod product;
 mod recipient;
 mod refund;
+mod reporting_report_run;
+mod reporting_report_type;
 mod reserve_transaction;
 mod review;
 mod scheduled_query_run;
@@ -56,6 +63,8 @@ mod session;
 mod setup_intent;
 mod sku;
 mod source;
+mod source_mandate_notification;
+mod source_transaction;
 mod subscription;
 mod subscription_ext;
 mod subscription_item;
@@ -66,6 +75,7 @@ mod topup;
 mod transfer;
 mod transfer_reversal;
 mod webhook_endpoint;
+mod webhook_endpoint_ext;
 
 mod types;
 pub use self::types::*;
@@ -82,7 +92,9 @@ pub use self::card::*;
 // pub use self::charge::*;
 pub use self::charge_v2::*;
 pub use self::connect_collection_transfer::*;
+pub use self::checkout_session::*;
 pub use self::coupon::*;
+pub use self::credit_note::*;
 pub use self::currency::*;
 pub use self::customer_v2::*;
 // pub use self::customer::*;
@@ -90,6 +102,7 @@ pub use self::customer_v2::*;
 pub use self::discount::*;
 pub use self::dispute::*;
 pub use self::event::*;
+pub use self::event_types::*;
 pub use self::fee_refund::*;
 pub use self::file::*;
 pub use self::file_link::*;
@@ -100,10 +113,12 @@ pub use self::issuing_authorization::*;
 pub use self::issuing_card::*;
 pub use self::issuing_cardholder::*;
 pub use self::issuing_dispute::*;
+pub use self::issuing_settlement::*;
 pub use self::issuing_merchant_data::*;
 pub use self::issuing_transaction::*;
 pub use self::line_item::*;
 pub use self::line_item_ext::*;
+pub use self::money::*;
 pub use self::order::*;
 pub use self::order_item::*;
 pub use self::order_return::*;
@@ -122,6 +137,8 @@ pub use self::plan::*;
 pub use self::product::*;
 pub use self::recipient::*;
 pub use self::refund::*;
+pub use self::reporting_report_run::*;
+pub use self::reporting_report_type::*;
 pub use self::reserve_transaction::*;
 pub use self::review::*;
 pub use self::scheduled_query_run::*;
@@ -133,6 +150,8 @@ pub use self::session::{
 pub use self::setup_intent::*;
 pub use self::sku::*;
 pub use self::source::*;
+pub use self::source_mandate_notification::*;
+pub use self::source_transaction::*;
 pub use self::subscription::*;
 pub use self::subscription_ext::*;
 pub use self::subscription_item::*;
@@ -142,3 +161,5 @@ pub use self::token::*;
 pub use self::topup::*;
 pub use self::transfer::*;
 pub use self::transfer_reversal::*;
+pub use self::webhook_endpoint::*;
+pub use self::webhook_endpoint_ext::*;
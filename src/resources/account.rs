@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 
 // ======================================
 // This file was automatically generated.
@@ -107,8 +107,11 @@ impl Account {
     /// Returns a list of accounts connected to your platform via [Connect](https://stripe.com/docs/connect).
     ///
     /// If you’re not a platform, the list is empty.
-    pub fn list(client: &Client, params: ListAccounts<'_>) -> Response<List<Account>> {
-        client.get_query("/accounts", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListAccounts<'_>,
+    ) -> C::Response<List<Account>> {
+        List::get_query(client, "/accounts", &params)
     }
 
     /// With [Connect](https://stripe.com/docs/connect), you can create Stripe accounts for your users.
@@ -116,12 +119,16 @@ impl Account {
     ///
     /// For Standard accounts, parameters other than `country`, `email`, and `type`
     /// are used to prefill the account application that we ask the account holder to complete.
-    pub fn create(client: &Client, params: CreateAccount<'_>) -> Response<Account> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateAccount<'_>) -> C::Response<Account> {
         client.post_form("/accounts", &params)
     }
 
     /// Retrieves the details of an account.
-    pub fn retrieve(client: &Client, id: &AccountId, expand: &[&str]) -> Response<Account> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &AccountId,
+        expand: &[&str],
+    ) -> C::Response<Account> {
         client.get_query(&format!("/accounts/{}", id), &Expand { expand })
     }
 
@@ -131,7 +138,11 @@ impl Account {
     /// Most parameters can be changed only for Custom accounts.
     /// (These are marked **Custom Only** below.) Parameters marked **Custom and Express** are supported by both account types.  To update your own account, use the [Dashboard](https://dashboard.stripe.com/account).
     /// Refer to our [Connect](https://stripe.com/docs/connect/updating-accounts) documentation to learn more about updating accounts.
-    pub fn update(client: &Client, id: &AccountId, params: UpdateAccount<'_>) -> Response<Account> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &AccountId,
+        params: UpdateAccount<'_>,
+    ) -> C::Response<Account> {
         client.post_form(&format!("/accounts/{}", id), &params)
     }
 
@@ -140,7 +151,7 @@ impl Account {
     /// Custom accounts created using test-mode keys can be deleted at any time.
     ///
     /// Custom accounts created using live-mode keys may only be deleted once all balances are zero.  If you are looking to close your own account, use the [data tab in your account settings](https://dashboard.stripe.com/account/data) instead.
-    pub fn delete(client: &Client, id: &AccountId) -> Response<Deleted<AccountId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &AccountId) -> C::Response<Deleted<AccountId>> {
         client.delete(&format!("/accounts/{}", id))
     }
 }
@@ -905,20 +916,33 @@ pub enum ExternalAccount {
 }
 
 /// An enum representing the possible values of an `Account`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AccountType {
     Custom,
     Express,
     Standard,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl AccountType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             AccountType::Custom => "custom",
             AccountType::Express => "express",
             AccountType::Standard => "standard",
+            AccountType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for AccountType {
+    fn from(value: &str) -> Self {
+        match value {
+            "custom" => AccountType::Custom,
+            "express" => AccountType::Express,
+            "standard" => AccountType::Standard,
+            _ => AccountType::Unknown(value.to_string()),
         }
     }
 }
@@ -935,19 +959,33 @@ impl std::fmt::Display for AccountType {
     }
 }
 
+serde_string_enum!(AccountType);
+
 /// An enum representing the possible values of an `Account`'s `business_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BusinessType {
     Company,
     Individual,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl BusinessType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             BusinessType::Company => "company",
             BusinessType::Individual => "individual",
+            BusinessType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for BusinessType {
+    fn from(value: &str) -> Self {
+        match value {
+            "company" => BusinessType::Company,
+            "individual" => BusinessType::Individual,
+            _ => BusinessType::Unknown(value.to_string()),
         }
     }
 }
@@ -964,21 +1002,36 @@ impl std::fmt::Display for BusinessType {
     }
 }
 
+serde_string_enum!(BusinessType);
+
 /// An enum representing the possible values of an `AccountCapabilities`'s `card_payments` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CapabilityStatus {
     Active,
     Inactive,
     Pending,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CapabilityStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CapabilityStatus::Active => "active",
             CapabilityStatus::Inactive => "inactive",
             CapabilityStatus::Pending => "pending",
+            CapabilityStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CapabilityStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "active" => CapabilityStatus::Active,
+            "inactive" => CapabilityStatus::Inactive,
+            "pending" => CapabilityStatus::Pending,
+            _ => CapabilityStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -995,23 +1048,39 @@ impl std::fmt::Display for CapabilityStatus {
     }
 }
 
+serde_string_enum!(CapabilityStatus);
+
 /// An enum representing the possible values of an `CreateAccount`'s `requested_capabilities` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RequestedCapability {
     CardIssuing,
     CardPayments,
     LegacyPayments,
     PlatformPayments,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl RequestedCapability {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             RequestedCapability::CardIssuing => "card_issuing",
             RequestedCapability::CardPayments => "card_payments",
             RequestedCapability::LegacyPayments => "legacy_payments",
             RequestedCapability::PlatformPayments => "platform_payments",
+            RequestedCapability::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for RequestedCapability {
+    fn from(value: &str) -> Self {
+        match value {
+            "card_issuing" => RequestedCapability::CardIssuing,
+            "card_payments" => RequestedCapability::CardPayments,
+            "legacy_payments" => RequestedCapability::LegacyPayments,
+            "platform_payments" => RequestedCapability::PlatformPayments,
+            _ => RequestedCapability::Unknown(value.to_string()),
         }
     }
 }
@@ -1028,23 +1097,39 @@ impl std::fmt::Display for RequestedCapability {
     }
 }
 
+serde_string_enum!(RequestedCapability);
+
 /// An enum representing the possible values of an `TransferScheduleParams`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransferScheduleInterval {
     Daily,
     Manual,
     Monthly,
     Weekly,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TransferScheduleInterval {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TransferScheduleInterval::Daily => "daily",
             TransferScheduleInterval::Manual => "manual",
             TransferScheduleInterval::Monthly => "monthly",
             TransferScheduleInterval::Weekly => "weekly",
+            TransferScheduleInterval::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TransferScheduleInterval {
+    fn from(value: &str) -> Self {
+        match value {
+            "daily" => TransferScheduleInterval::Daily,
+            "manual" => TransferScheduleInterval::Manual,
+            "monthly" => TransferScheduleInterval::Monthly,
+            "weekly" => TransferScheduleInterval::Weekly,
+            _ => TransferScheduleInterval::Unknown(value.to_string()),
         }
     }
 }
@@ -1060,3 +1145,5 @@ impl std::fmt::Display for TransferScheduleInterval {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TransferScheduleInterval);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{ApplicationFeeId, ChargeId};
 use crate::params::{Expand, Expandable, List, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -67,21 +67,21 @@ impl ApplicationFee {
     /// Returns a list of application fees you’ve previously collected.
     ///
     /// The application fees are returned in sorted order, with the most recent fees appearing first.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: ListApplicationFees<'_>,
-    ) -> Response<List<ApplicationFee>> {
-        client.get_query("/application_fees", &params)
+    ) -> C::Response<List<ApplicationFee>> {
+        List::get_query(client, "/application_fees", &params)
     }
 
     /// Retrieves the details of an application fee that your account has collected.
     ///
     /// The same information is returned when refunding the application fee.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &ApplicationFeeId,
         expand: &[&str],
-    ) -> Response<ApplicationFee> {
+    ) -> C::Response<ApplicationFee> {
         client.get_query(&format!("/application_fees/{}", id), &Expand { expand })
     }
 }
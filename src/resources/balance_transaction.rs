@@ -123,18 +123,30 @@ pub enum BalanceTransactionSource {
 }
 
 /// An enum representing the possible values of an `BalanceTransaction`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BalanceTransactionStatus {
     Available,
     Pending,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl BalanceTransactionStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             BalanceTransactionStatus::Available => "available",
             BalanceTransactionStatus::Pending => "pending",
+            BalanceTransactionStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for BalanceTransactionStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "available" => BalanceTransactionStatus::Available,
+            "pending" => BalanceTransactionStatus::Pending,
+            _ => BalanceTransactionStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -151,9 +163,10 @@ impl std::fmt::Display for BalanceTransactionStatus {
     }
 }
 
+serde_string_enum!(BalanceTransactionStatus);
+
 /// An enum representing the possible values of an `BalanceTransaction`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BalanceTransactionType {
     Adjustment,
     Advance,
@@ -184,10 +197,12 @@ pub enum BalanceTransactionType {
     TransferCancel,
     TransferFailure,
     TransferRefund,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl BalanceTransactionType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             BalanceTransactionType::Adjustment => "adjustment",
             BalanceTransactionType::Advance => "advance",
@@ -218,6 +233,44 @@ impl BalanceTransactionType {
             BalanceTransactionType::TransferCancel => "transfer_cancel",
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
+            BalanceTransactionType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for BalanceTransactionType {
+    fn from(value: &str) -> Self {
+        match value {
+            "adjustment" => BalanceTransactionType::Adjustment,
+            "advance" => BalanceTransactionType::Advance,
+            "advance_funding" => BalanceTransactionType::AdvanceFunding,
+            "application_fee" => BalanceTransactionType::ApplicationFee,
+            "application_fee_refund" => BalanceTransactionType::ApplicationFeeRefund,
+            "charge" => BalanceTransactionType::Charge,
+            "connect_collection_transfer" => BalanceTransactionType::ConnectCollectionTransfer,
+            "issuing_authorization_hold" => BalanceTransactionType::IssuingAuthorizationHold,
+            "issuing_authorization_release" => BalanceTransactionType::IssuingAuthorizationRelease,
+            "issuing_transaction" => BalanceTransactionType::IssuingTransaction,
+            "payment" => BalanceTransactionType::Payment,
+            "payment_failure_refund" => BalanceTransactionType::PaymentFailureRefund,
+            "payment_refund" => BalanceTransactionType::PaymentRefund,
+            "payout" => BalanceTransactionType::Payout,
+            "payout_cancel" => BalanceTransactionType::PayoutCancel,
+            "payout_failure" => BalanceTransactionType::PayoutFailure,
+            "refund" => BalanceTransactionType::Refund,
+            "refund_failure" => BalanceTransactionType::RefundFailure,
+            "reserve_transaction" => BalanceTransactionType::ReserveTransaction,
+            "reserved_funds" => BalanceTransactionType::ReservedFunds,
+            "stripe_fee" => BalanceTransactionType::StripeFee,
+            "stripe_fx_fee" => BalanceTransactionType::StripeFxFee,
+            "tax_fee" => BalanceTransactionType::TaxFee,
+            "topup" => BalanceTransactionType::Topup,
+            "topup_reversal" => BalanceTransactionType::TopupReversal,
+            "transfer" => BalanceTransactionType::Transfer,
+            "transfer_cancel" => BalanceTransactionType::TransferCancel,
+            "transfer_failure" => BalanceTransactionType::TransferFailure,
+            "transfer_refund" => BalanceTransactionType::TransferRefund,
+            _ => BalanceTransactionType::Unknown(value.to_string()),
         }
     }
 }
@@ -234,21 +287,36 @@ impl std::fmt::Display for BalanceTransactionType {
     }
 }
 
+serde_string_enum!(BalanceTransactionType);
+
 /// An enum representing the possible values of an `Fee`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FeeType {
     ApplicationFee,
     StripeFee,
     Tax,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl FeeType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             FeeType::ApplicationFee => "application_fee",
             FeeType::StripeFee => "stripe_fee",
             FeeType::Tax => "tax",
+            FeeType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for FeeType {
+    fn from(value: &str) -> Self {
+        match value {
+            "application_fee" => FeeType::ApplicationFee,
+            "stripe_fee" => FeeType::StripeFee,
+            "tax" => FeeType::Tax,
+            _ => FeeType::Unknown(value.to_string()),
         }
     }
 }
@@ -264,3 +332,5 @@ impl std::fmt::Display for FeeType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(FeeType);
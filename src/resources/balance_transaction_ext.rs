@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{BalanceTransactionId, BalanceTransactionSourceId};
 use crate::params::Object;
 use crate::resources::{BalanceTransaction, BalanceTransactionSource};
@@ -7,7 +7,10 @@ impl BalanceTransaction {
     /// Retrieves the balance transaction with the given ID.
     ///
     /// For more details see [https://stripe.com/docs/api/balance/balance_transaction_retrieve](https://stripe.com/docs/api/balance/balance_transaction_retrieve).
-    pub fn retrieve(client: &Client, id: &BalanceTransactionId) -> Response<BalanceTransaction> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &BalanceTransactionId,
+    ) -> C::Response<BalanceTransaction> {
         client.get(&format!("/balance/history/{}", id))
     }
 }
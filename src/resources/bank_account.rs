@@ -93,24 +93,39 @@ impl Object for BankAccount {
 }
 
 /// An enum representing the possible values of an `BankAccount`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BankAccountStatus {
     Errored,
     New,
     Validated,
     VerificationFailed,
     Verified,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl BankAccountStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             BankAccountStatus::Errored => "errored",
             BankAccountStatus::New => "new",
             BankAccountStatus::Validated => "validated",
             BankAccountStatus::VerificationFailed => "verification_failed",
             BankAccountStatus::Verified => "verified",
+            BankAccountStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for BankAccountStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "errored" => BankAccountStatus::Errored,
+            "new" => BankAccountStatus::New,
+            "validated" => BankAccountStatus::Validated,
+            "verification_failed" => BankAccountStatus::VerificationFailed,
+            "verified" => BankAccountStatus::Verified,
+            _ => BankAccountStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -126,3 +141,5 @@ impl std::fmt::Display for BankAccountStatus {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(BankAccountStatus);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{ChargeId, CustomerId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -208,15 +208,15 @@ impl Charge {
     /// Returns a list of charges you’ve previously created.
     ///
     /// The charges are returned in sorted order, with the most recent charges appearing first.
-    pub fn list(client: &Client, params: ListCharges<'_>) -> Response<List<Charge>> {
-        client.get_query("/charges", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListCharges<'_>) -> C::Response<List<Charge>> {
+        List::get_query(client, "/charges", &params)
     }
 
     /// To charge a credit card or other payment source, you create a `Charge` object.
     ///
     /// If your API key is in test mode, the supplied payment source (e.g., card) won’t actually be charged, although everything else will occur as if in live mode.
     /// (Stripe assumes that the charge would have completed successfully).
-    pub fn create(client: &Client, params: CreateCharge<'_>) -> Response<Charge> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateCharge<'_>) -> C::Response<Charge> {
         client.post_form("/charges", &params)
     }
 
@@ -224,14 +224,22 @@ impl Charge {
     ///
     /// Supply the unique charge ID that was returned from your previous request, and Stripe will return the corresponding charge information.
     /// The same information is returned when creating or refunding the charge.
-    pub fn retrieve(client: &Client, id: &ChargeId, expand: &[&str]) -> Response<Charge> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ChargeId,
+        expand: &[&str],
+    ) -> C::Response<Charge> {
         client.get_query(&format!("/charges/{}", id), &Expand { expand })
     }
 
     /// Updates the specified charge by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
-    pub fn update(client: &Client, id: &ChargeId, params: UpdateCharge<'_>) -> Response<Charge> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &ChargeId,
+        params: UpdateCharge<'_>,
+    ) -> C::Response<Charge> {
         client.post_form(&format!("/charges/{}", id), &params)
     }
 }
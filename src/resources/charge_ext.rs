@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::ChargeId;
 use crate::params::Object;
 use crate::resources::{Charge, Rule};
@@ -23,11 +23,11 @@ impl Charge {
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see [https://stripe.com/docs/api#charge_capture](https://stripe.com/docs/api#charge_capture).
-    pub fn capture(
-        client: &Client,
+    pub fn capture<C: StripeClient>(
+        client: &C,
         charge_id: &ChargeId,
         params: CaptureCharge<'_>,
-    ) -> Response<Charge> {
+    ) -> C::Response<Charge> {
         client.post_form(&format!("/charges/{}/capture", charge_id), params)
     }
 }
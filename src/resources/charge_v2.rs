@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::error::ErrorCode;
 use crate::ids::{ChargeId, CustomerId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
@@ -208,44 +208,51 @@ impl Charge {
     /// Creates a new charge.
     ///
     /// For more details see [https://stripe.com/docs/api#create_charge](https://stripe.com/docs/api#create_charge).
-    pub fn create(client: &Client, params: ChargeParams<'_>) -> Response<Charge> {
+    pub fn create<C: StripeClient>(client: &C, params: ChargeParams<'_>) -> C::Response<Charge> {
         client.post_form("/charges", params)
     }
 
     /// Retrieves the details of a charge.
     ///
     /// For more details see [https://stripe.com/docs/api#retrieve_charge](https://stripe.com/docs/api#retrieve_charge).
-    pub fn retrieve(client: &Client, charge_id: &ChargeId, expand: &[&str]) -> Response<Charge> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        charge_id: &ChargeId,
+        expand: &[&str],
+    ) -> C::Response<Charge> {
         client.get_query(&format!("/charges/{}", charge_id), &Expand { expand })
     }
 
     /// Updates a charge's properties.
     ///
     /// For more details see [https://stripe.com/docs/api#update_charge](https://stripe.com/docs/api#update_charge).
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         charge_id: &ChargeId,
         params: ChargeParams,
-    ) -> Response<Charge> {
+    ) -> C::Response<Charge> {
         client.post_form(&format!("/charges/{}", charge_id), params)
     }
 
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see [https://stripe.com/docs/api#charge_capture](https://stripe.com/docs/api#charge_capture).
-    pub fn capture(
-        client: &Client,
+    pub fn capture<C: StripeClient>(
+        client: &C,
         charge_id: &ChargeId,
         params: CaptureParams,
-    ) -> Response<Charge> {
+    ) -> C::Response<Charge> {
         client.post_form(&format!("/charges/{}/capture", charge_id), params)
     }
 
     /// List all charges.
     ///
     /// For more details see [https://stripe.com/docs/api#list_charges](https://stripe.com/docs/api#list_charges).
-    pub fn list(client: &Client, params: ChargeListParams<'_>) -> Response<List<Charge>> {
-        client.get_query("/charges", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ChargeListParams<'_>,
+    ) -> C::Response<List<Charge>> {
+        List::get_query(client, "/charges", &params)
     }
 }
 
@@ -130,8 +130,7 @@ pub struct CheckoutSessionCustomDisplayItemDescription {
 }
 
 /// An enum representing the possible values of an `CheckoutSession`'s `locale` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CheckoutSessionLocale {
     Auto,
     Da,
@@ -148,10 +147,12 @@ pub enum CheckoutSessionLocale {
     Pt,
     Sv,
     Zh,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CheckoutSessionLocale {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CheckoutSessionLocale::Auto => "auto",
             CheckoutSessionLocale::Da => "da",
@@ -168,6 +169,30 @@ impl CheckoutSessionLocale {
             CheckoutSessionLocale::Pt => "pt",
             CheckoutSessionLocale::Sv => "sv",
             CheckoutSessionLocale::Zh => "zh",
+            CheckoutSessionLocale::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CheckoutSessionLocale {
+    fn from(value: &str) -> Self {
+        match value {
+            "auto" => CheckoutSessionLocale::Auto,
+            "da" => CheckoutSessionLocale::Da,
+            "de" => CheckoutSessionLocale::De,
+            "en" => CheckoutSessionLocale::En,
+            "es" => CheckoutSessionLocale::Es,
+            "fi" => CheckoutSessionLocale::Fi,
+            "fr" => CheckoutSessionLocale::Fr,
+            "it" => CheckoutSessionLocale::It,
+            "ja" => CheckoutSessionLocale::Ja,
+            "nb" => CheckoutSessionLocale::Nb,
+            "nl" => CheckoutSessionLocale::Nl,
+            "pl" => CheckoutSessionLocale::Pl,
+            "pt" => CheckoutSessionLocale::Pt,
+            "sv" => CheckoutSessionLocale::Sv,
+            "zh" => CheckoutSessionLocale::Zh,
+            _ => CheckoutSessionLocale::Unknown(value.to_string()),
         }
     }
 }
@@ -183,3 +208,5 @@ impl std::fmt::Display for CheckoutSessionLocale {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(CheckoutSessionLocale);
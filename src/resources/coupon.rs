@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::CouponId;
 use crate::params::{Deleted, Expand, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::Currency;
@@ -85,8 +85,8 @@ pub struct Coupon {
 
 impl Coupon {
     /// Returns a list of your coupons.
-    pub fn list(client: &Client, params: ListCoupons<'_>) -> Response<List<Coupon>> {
-        client.get_query("/coupons", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListCoupons<'_>) -> C::Response<List<Coupon>> {
+        List::get_query(client, "/coupons", &params)
     }
 
     /// You can create coupons easily via the [coupon management](https://dashboard.stripe.com/coupons) page of the Stripe dashboard.
@@ -94,19 +94,27 @@ impl Coupon {
     /// Coupon creation is also accessible via the API if you need to create coupons on the fly.  A coupon has either a `percent_off` or an `amount_off` and `currency`.
     /// If you set an `amount_off`, that amount will be subtracted from any invoice’s subtotal.
     /// For example, an invoice with a subtotal of $100 will have a final total of $0 if a coupon with an `amount_off` of 20000 is applied to it and an invoice with a subtotal of $300 will have a final total of $100 if a coupon with an `amount_off` of 20000 is applied to it.
-    pub fn create(client: &Client, params: CreateCoupon<'_>) -> Response<Coupon> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateCoupon<'_>) -> C::Response<Coupon> {
         client.post_form("/coupons", &params)
     }
 
     /// Retrieves the coupon with the given ID.
-    pub fn retrieve(client: &Client, id: &CouponId, expand: &[&str]) -> Response<Coupon> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &CouponId,
+        expand: &[&str],
+    ) -> C::Response<Coupon> {
         client.get_query(&format!("/coupons/{}", id), &Expand { expand })
     }
 
     /// Updates the metadata of a coupon.
     ///
     /// Other coupon details (currency, duration, amount_off) are, by design, not editable.
-    pub fn update(client: &Client, id: &CouponId, params: UpdateCoupon<'_>) -> Response<Coupon> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &CouponId,
+        params: UpdateCoupon<'_>,
+    ) -> C::Response<Coupon> {
         client.post_form(&format!("/coupons/{}", id), &params)
     }
 
@@ -114,7 +122,7 @@ impl Coupon {
     ///
     /// However, deleting a coupon does not affect any customers who have already applied the coupon; it means that new customers can’t redeem the coupon.
     /// You can also delete coupons via the API.
-    pub fn delete(client: &Client, id: &CouponId) -> Response<Deleted<CouponId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &CouponId) -> C::Response<Deleted<CouponId>> {
         client.delete(&format!("/coupons/{}", id))
     }
 }
@@ -284,20 +292,33 @@ impl<'a> UpdateCoupon<'a> {
 }
 
 /// An enum representing the possible values of an `Coupon`'s `duration` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CouponDuration {
     Forever,
     Once,
     Repeating,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CouponDuration {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CouponDuration::Forever => "forever",
             CouponDuration::Once => "once",
             CouponDuration::Repeating => "repeating",
+            CouponDuration::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CouponDuration {
+    fn from(value: &str) -> Self {
+        match value {
+            "forever" => CouponDuration::Forever,
+            "once" => CouponDuration::Once,
+            "repeating" => CouponDuration::Repeating,
+            _ => CouponDuration::Unknown(value.to_string()),
         }
     }
 }
@@ -313,3 +334,5 @@ impl std::fmt::Display for CouponDuration {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(CouponDuration);
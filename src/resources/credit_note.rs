@@ -0,0 +1,393 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::StripeClient;
+use crate::ids::{CreditNoteId, InvoiceId, RefundId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::resources::{Currency, Customer, Invoice, Refund};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "CreditNote".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreditNote {
+    /// Unique identifier for the object.
+    pub id: CreditNoteId,
+
+    /// The integer amount in **%s** representing the total amount of the credit note.
+    pub amount: i64,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// ID of the customer.
+    pub customer: Expandable<Customer>,
+
+    /// ID of the invoice.
+    pub invoice: Expandable<Invoice>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Customer-facing text that appears on the credit note PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Metadata,
+
+    /// A unique number that identifies this particular credit note and appears on the PDF of the credit note and its associated invoice.
+    pub number: String,
+
+    /// The link to download the PDF of the credit note.
+    pub pdf: String,
+
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+
+    /// Refund related to this credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<Expandable<Refund>>,
+
+    /// Status of this credit note, one of `issued` or `void`.
+    ///
+    /// Learn more about [voiding credit notes](https://stripe.com/docs/billing/invoices/credit-notes#voiding).
+    pub status: CreditNoteStatus,
+
+    /// Type of this credit note, one of `post_payment` or `pre_payment`.
+    ///
+    /// A `pre_payment` credit note means it was issued when the invoice was open.
+    /// A `post_payment` credit note means it was issued when the invoice was paid.
+    #[serde(rename = "type")]
+    pub type_: CreditNoteType,
+}
+
+impl CreditNote {
+    /// Returns a list of credit notes.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListCreditNotes<'_>,
+    ) -> C::Response<List<CreditNote>> {
+        List::get_query(client, "/credit_notes", &params)
+    }
+
+    /// Issue a credit note to adjust the amount of a finalized invoice.
+    ///
+    /// For a `status=open` invoice, a credit note reduces its `amount_due`.
+    /// For a `status=paid` invoice, a credit note does not affect its `amount_due`.
+    /// Instead, it can result in any combination of the following:  <ul>  <li>Refund: create a new refund (using `refund_amount`) or link an existing refund (using `refund`).</li>  <li>Customer balance credit: credit the customer’s balance (using `credit_amount`) which will be automatically applied to their next invoice when it’s finalized.</li>  <li>Outside of Stripe credit: any positive value from the result of `amount - refund_amount - credit_amount` is represented as an “outside of Stripe” credit.</li> </ul>  You may issue multiple credit notes for an invoice.
+    /// Each credit note will increment the invoice’s `pre_payment_credit_notes_amount` or `post_payment_credit_notes_amount` depending on its `status` at the time of credit note creation.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateCreditNote<'_>,
+    ) -> C::Response<CreditNote> {
+        client.post_form("/credit_notes", &params)
+    }
+
+    /// Retrieves the credit note object with the given identifier.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &CreditNoteId,
+        expand: &[&str],
+    ) -> C::Response<CreditNote> {
+        client.get_query(&format!("/credit_notes/{}", id), &Expand { expand })
+    }
+
+    /// Updates an existing credit note.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &CreditNoteId,
+        params: UpdateCreditNote<'_>,
+    ) -> C::Response<CreditNote> {
+        client.post_form(&format!("/credit_notes/{}", id), &params)
+    }
+}
+
+impl Object for CreditNote {
+    type Id = CreditNoteId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "credit_note"
+    }
+}
+
+/// The parameters for `CreditNote::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCreditNote<'a> {
+    /// The integer amount in **%s** representing the total amount of the credit note.
+    pub amount: i64,
+
+    /// The integer amount in **%s** representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of the invoice.
+    pub invoice: InvoiceId,
+
+    /// The credit note's memo appears on the credit note PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+
+    /// ID of an existing refund to link this credit note to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<RefundId>,
+
+    /// The integer amount in **%s** representing the amount to refund.
+    ///
+    /// If set, a refund will be created for the charge associated with the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_amount: Option<i64>,
+}
+
+impl<'a> CreateCreditNote<'a> {
+    pub fn new(amount: i64, invoice: InvoiceId) -> Self {
+        CreateCreditNote {
+            amount,
+            credit_amount: Default::default(),
+            expand: Default::default(),
+            invoice,
+            memo: Default::default(),
+            metadata: Default::default(),
+            reason: Default::default(),
+            refund: Default::default(),
+            refund_amount: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `CreditNote::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCreditNotes<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CreditNoteId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<InvoiceId>,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CreditNoteId>,
+}
+
+impl<'a> ListCreditNotes<'a> {
+    pub fn new() -> Self {
+        ListCreditNotes {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            invoice: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `CreditNote::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateCreditNote<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Credit note memo.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateCreditNote<'a> {
+    pub fn new() -> Self {
+        UpdateCreditNote {
+            expand: Default::default(),
+            memo: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `CreditNote`'s `reason` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreditNoteReason {
+    Duplicate,
+    Fraudulent,
+    OrderChange,
+    ProductUnsatisfactory,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl CreditNoteReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CreditNoteReason::Duplicate => "duplicate",
+            CreditNoteReason::Fraudulent => "fraudulent",
+            CreditNoteReason::OrderChange => "order_change",
+            CreditNoteReason::ProductUnsatisfactory => "product_unsatisfactory",
+            CreditNoteReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CreditNoteReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "duplicate" => CreditNoteReason::Duplicate,
+            "fraudulent" => CreditNoteReason::Fraudulent,
+            "order_change" => CreditNoteReason::OrderChange,
+            "product_unsatisfactory" => CreditNoteReason::ProductUnsatisfactory,
+            _ => CreditNoteReason::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for CreditNoteReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreditNoteReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(CreditNoteReason);
+
+/// An enum representing the possible values of an `CreditNote`'s `status` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreditNoteStatus {
+    Issued,
+    Void,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl CreditNoteStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CreditNoteStatus::Issued => "issued",
+            CreditNoteStatus::Void => "void",
+            CreditNoteStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CreditNoteStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "issued" => CreditNoteStatus::Issued,
+            "void" => CreditNoteStatus::Void,
+            _ => CreditNoteStatus::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for CreditNoteStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreditNoteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(CreditNoteStatus);
+
+/// An enum representing the possible values of an `CreditNote`'s `type` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreditNoteType {
+    PostPayment,
+    PrePayment,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl CreditNoteType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CreditNoteType::PostPayment => "post_payment",
+            CreditNoteType::PrePayment => "pre_payment",
+            CreditNoteType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CreditNoteType {
+    fn from(value: &str) -> Self {
+        match value {
+            "post_payment" => CreditNoteType::PostPayment,
+            "pre_payment" => CreditNoteType::PrePayment,
+            _ => CreditNoteType::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for CreditNoteType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreditNoteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(CreditNoteType);
@@ -1,295 +1,157 @@
-use crate::params::to_snakecase;
-use serde_derive::{Deserialize, Serialize};
-
 /// Currency is the list of supported currencies.
 ///
 /// For more details see https://support.stripe.com/questions/which-currencies-does-stripe-support.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Currency {
-    #[serde(rename = "btc")]
     BTC, // Bitcoin
-    #[serde(rename = "eth")]
     ETH, // Ethereum
-    #[serde(rename = "xmr")]
     XMR, // Monero
-    #[serde(rename = "aed")]
     AED, // United Arab Emirates Dirham
-    #[serde(rename = "afn")]
     AFN, // Afghan Afghani
-    #[serde(rename = "all")]
     ALL, // Albanian Lek
-    #[serde(rename = "amd")]
     AMD, // Armenian Dram
-    #[serde(rename = "ang")]
     ANG, // Netherlands Antillean Gulden
-    #[serde(rename = "aoa")]
     AOA, // Angolan Kwanza
-    #[serde(rename = "ars")]
     ARS, // Argentine Peso
-    #[serde(rename = "aud")]
     AUD, // Australian Dollar
-    #[serde(rename = "awg")]
     AWG, // Aruban Florin
-    #[serde(rename = "azn")]
     AZN, // Azerbaijani Manat
-    #[serde(rename = "bam")]
     BAM, // Bosnia & Herzegovina Convertible Mark
-    #[serde(rename = "bbd")]
     BBD, // Barbadian Dollar
-    #[serde(rename = "bdt")]
     BDT, // Bangladeshi Taka
-    #[serde(rename = "bgn")]
     BGN, // Bulgarian Lev
-    #[serde(rename = "bif")]
+    BHD, // Bahraini Dinar
     BIF, // Burundian Franc
-    #[serde(rename = "bmd")]
     BMD, // Bermudian Dollar
-    #[serde(rename = "bnd")]
     BND, // Brunei Dollar
-    #[serde(rename = "bob")]
     BOB, // Bolivian Boliviano
-    #[serde(rename = "brl")]
     BRL, // Brazilian Real
-    #[serde(rename = "bsd")]
     BSD, // Bahamian Dollar
-    #[serde(rename = "bwp")]
     BWP, // Botswana Pula
-    #[serde(rename = "bzd")]
     BZD, // Belize Dollar
-    #[serde(rename = "cad")]
     CAD, // Canadian Dollar
-    #[serde(rename = "cdf")]
     CDF, // Congolese Franc
-    #[serde(rename = "chf")]
     CHF, // Swiss Franc
-    #[serde(rename = "clp")]
     CLP, // Chilean Peso
-    #[serde(rename = "cny")]
     CNY, // Chinese Renminbi Yuan
-    #[serde(rename = "cop")]
     COP, // Colombian Peso
-    #[serde(rename = "crc")]
     CRC, // Costa Rican Colón
-    #[serde(rename = "cve")]
     CVE, // Cape Verdean Escudo
-    #[serde(rename = "czk")]
     CZK, // Czech Koruna
-    #[serde(rename = "djf")]
     DJF, // Djiboutian Franc
-    #[serde(rename = "dkk")]
     DKK, // Danish Krone
-    #[serde(rename = "dop")]
     DOP, // Dominican Peso
-    #[serde(rename = "dzd")]
     DZD, // Algerian Dinar
-    #[serde(rename = "eek")]
     EEK, // Estonian Kroon
-    #[serde(rename = "egp")]
     EGP, // Egyptian Pound
-    #[serde(rename = "etb")]
     ETB, // Ethiopian Birr
-    #[serde(rename = "eur")]
     EUR, // Euro
-    #[serde(rename = "fjd")]
     FJD, // Fijian Dollar
-    #[serde(rename = "fkp")]
     FKP, // Falkland Islands Pound
-    #[serde(rename = "gbp")]
     GBP, // British Pound
-    #[serde(rename = "gel")]
     GEL, // Georgian Lari
-    #[serde(rename = "gip")]
     GIP, // Gibraltar Pound
-    #[serde(rename = "gmd")]
     GMD, // Gambian Dalasi
-    #[serde(rename = "gnf")]
     GNF, // Guinean Franc
-    #[serde(rename = "gtq")]
     GTQ, // Guatemalan Quetzal
-    #[serde(rename = "gyd")]
     GYD, // Guyanese Dollar
-    #[serde(rename = "hkd")]
     HKD, // Hong Kong Dollar
-    #[serde(rename = "hnl")]
     HNL, // Honduran Lempira
-    #[serde(rename = "hrk")]
     HRK, // Croatian Kuna
-    #[serde(rename = "htg")]
     HTG, // Haitian Gourde
-    #[serde(rename = "huf")]
     HUF, // Hungarian Forint
-    #[serde(rename = "idr")]
     IDR, // Indonesian Rupiah
-    #[serde(rename = "ils")]
     ILS, // Israeli New Sheqel
-    #[serde(rename = "inr")]
     INR, // Indian Rupee
-    #[serde(rename = "isk")]
     ISK, // Icelandic Króna
-    #[serde(rename = "jmd")]
     JMD, // Jamaican Dollar
-    #[serde(rename = "jpy")]
+    JOD, // Jordanian Dinar
     JPY, // Japanese Yen
-    #[serde(rename = "kes")]
     KES, // Kenyan Shilling
-    #[serde(rename = "kgs")]
     KGS, // Kyrgyzstani Som
-    #[serde(rename = "khr")]
     KHR, // Cambodian Riel
-    #[serde(rename = "kmf")]
     KMF, // Comorian Franc
-    #[serde(rename = "krw")]
     KRW, // South Korean Won
-    #[serde(rename = "kyd")]
+    KWD, // Kuwaiti Dinar
     KYD, // Cayman Islands Dollar
-    #[serde(rename = "kzt")]
     KZT, // Kazakhstani Tenge
-    #[serde(rename = "lak")]
     LAK, // Lao Kip
-    #[serde(rename = "lbp")]
     LBP, // Lebanese Pound
-    #[serde(rename = "lkr")]
     LKR, // Sri Lankan Rupee
-    #[serde(rename = "lrd")]
     LRD, // Liberian Dollar
-    #[serde(rename = "lsl")]
     LSL, // Lesotho Loti
-    #[serde(rename = "ltl")]
     LTL, // Lithuanian Litas
-    #[serde(rename = "lvl")]
     LVL, // Latvian Lats
-    #[serde(rename = "mad")]
     MAD, // Moroccan Dirham
-    #[serde(rename = "mdl")]
     MDL, // Moldovan Leu
-    #[serde(rename = "mga")]
     MGA, // Malagasy Ariary
-    #[serde(rename = "mkd")]
     MKD, // Macedonian Denar
-    #[serde(rename = "mnt")]
     MNT, // Mongolian Tögrög
-    #[serde(rename = "mop")]
     MOP, // Macanese Pataca
-    #[serde(rename = "mro")]
     MRO, // Mauritanian Ouguiya
-    #[serde(rename = "mur")]
     MUR, // Mauritian Rupee
-    #[serde(rename = "mvr")]
     MVR, // Maldivian Rufiyaa
-    #[serde(rename = "mwk")]
     MWK, // Malawian Kwacha
-    #[serde(rename = "mxn")]
     MXN, // Mexican Peso
-    #[serde(rename = "myr")]
     MYR, // Malaysian Ringgit
-    #[serde(rename = "mzn")]
     MZN, // Mozambican Metical
-    #[serde(rename = "nad")]
     NAD, // Namibian Dollar
-    #[serde(rename = "ngn")]
     NGN, // Nigerian Naira
-    #[serde(rename = "nio")]
     NIO, // Nicaraguan Córdoba
-    #[serde(rename = "nok")]
     NOK, // Norwegian Krone
-    #[serde(rename = "npr")]
     NPR, // Nepalese Rupee
-    #[serde(rename = "nzd")]
     NZD, // New Zealand Dollar
-    #[serde(rename = "pab")]
+    OMR, // Omani Rial
     PAB, // Panamanian Balboa
-    #[serde(rename = "pen")]
     PEN, // Peruvian Nuevo Sol
-    #[serde(rename = "pgk")]
     PGK, // Papua New Guinean Kina
-    #[serde(rename = "php")]
     PHP, // Philippine Peso
-    #[serde(rename = "pkr")]
     PKR, // Pakistani Rupee
-    #[serde(rename = "pln")]
     PLN, // Polish Złoty
-    #[serde(rename = "pyg")]
     PYG, // Paraguayan Guaraní
-    #[serde(rename = "qar")]
     QAR, // Qatari Riyal
-    #[serde(rename = "ron")]
     RON, // Romanian Leu
-    #[serde(rename = "rsd")]
     RSD, // Serbian Dinar
-    #[serde(rename = "rub")]
     RUB, // Russian Ruble
-    #[serde(rename = "rwf")]
     RWF, // Rwandan Franc
-    #[serde(rename = "sar")]
     SAR, // Saudi Riyal
-    #[serde(rename = "sbd")]
     SBD, // Solomon Islands Dollar
-    #[serde(rename = "scr")]
     SCR, // Seychellois Rupee
-    #[serde(rename = "sek")]
     SEK, // Swedish Krona
-    #[serde(rename = "sgd")]
     SGD, // Singapore Dollar
-    #[serde(rename = "shp")]
     SHP, // Saint Helenian Pound
-    #[serde(rename = "sll")]
     SLL, // Sierra Leonean Leone
-    #[serde(rename = "sos")]
     SOS, // Somali Shilling
-    #[serde(rename = "srd")]
     SRD, // Surinamese Dollar
-    #[serde(rename = "std")]
     STD, // São Tomé and Príncipe Dobra
-    #[serde(rename = "svc")]
     SVC, // Salvadoran Colón
-    #[serde(rename = "szl")]
     SZL, // Swazi Lilangeni
-    #[serde(rename = "thb")]
     THB, // Thai Baht
-    #[serde(rename = "tjs")]
     TJS, // Tajikistani Somoni
-    #[serde(rename = "top")]
+    TND, // Tunisian Dinar
     TOP, // Tongan Paʻanga
-    #[serde(rename = "try")]
     TRY, // Turkish Lira
-    #[serde(rename = "ttd")]
     TTD, // Trinidad and Tobago Dollar
-    #[serde(rename = "twd")]
     TWD, // New Taiwan Dollar
-    #[serde(rename = "tzs")]
     TZS, // Tanzanian Shilling
-    #[serde(rename = "uah")]
     UAH, // Ukrainian Hryvnia
-    #[serde(rename = "ugx")]
     UGX, // Ugandan Shilling
-    #[serde(rename = "usd")]
     USD, // United States Dollar
-    #[serde(rename = "uyu")]
     UYU, // Uruguayan Peso
-    #[serde(rename = "uzs")]
     UZS, // Uzbekistani Som
-    #[serde(rename = "vef")]
     VEF, // Venezuelan Bolívar
-    #[serde(rename = "vnd")]
     VND, // Vietnamese Đồng
-    #[serde(rename = "vuv")]
     VUV, // Vanuatu Vatu
-    #[serde(rename = "wst")]
     WST, // Samoan Tala
-    #[serde(rename = "xaf")]
     XAF, // Central African Cfa Franc
-    #[serde(rename = "xcd")]
     XCD, // East Caribbean Dollar
-    #[serde(rename = "xof")]
     XOF, // West African Cfa Franc
-    #[serde(rename = "xpf")]
     XPF, // Cfp Franc
-    #[serde(rename = "yer")]
     YER, // Yemeni Rial
-    #[serde(rename = "zar")]
     ZAR, // South African Rand
-    #[serde(rename = "zmw")]
     ZMW, // Zambian Kwacha
+    /// A currency not yet supported by the library, e.g. one added by Stripe since.
+    Unknown(String),
 }
 
 impl Default for Currency {
@@ -298,12 +160,255 @@ impl Default for Currency {
     }
 }
 
+impl Currency {
+    /// The currency's three-letter ISO code, in lowercase.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::BTC => "btc",
+            Currency::ETH => "eth",
+            Currency::XMR => "xmr",
+            Currency::AED => "aed",
+            Currency::AFN => "afn",
+            Currency::ALL => "all",
+            Currency::AMD => "amd",
+            Currency::ANG => "ang",
+            Currency::AOA => "aoa",
+            Currency::ARS => "ars",
+            Currency::AUD => "aud",
+            Currency::AWG => "awg",
+            Currency::AZN => "azn",
+            Currency::BAM => "bam",
+            Currency::BBD => "bbd",
+            Currency::BDT => "bdt",
+            Currency::BGN => "bgn",
+            Currency::BHD => "bhd",
+            Currency::BIF => "bif",
+            Currency::BMD => "bmd",
+            Currency::BND => "bnd",
+            Currency::BOB => "bob",
+            Currency::BRL => "brl",
+            Currency::BSD => "bsd",
+            Currency::BWP => "bwp",
+            Currency::BZD => "bzd",
+            Currency::CAD => "cad",
+            Currency::CDF => "cdf",
+            Currency::CHF => "chf",
+            Currency::CLP => "clp",
+            Currency::CNY => "cny",
+            Currency::COP => "cop",
+            Currency::CRC => "crc",
+            Currency::CVE => "cve",
+            Currency::CZK => "czk",
+            Currency::DJF => "djf",
+            Currency::DKK => "dkk",
+            Currency::DOP => "dop",
+            Currency::DZD => "dzd",
+            Currency::EEK => "eek",
+            Currency::EGP => "egp",
+            Currency::ETB => "etb",
+            Currency::EUR => "eur",
+            Currency::FJD => "fjd",
+            Currency::FKP => "fkp",
+            Currency::GBP => "gbp",
+            Currency::GEL => "gel",
+            Currency::GIP => "gip",
+            Currency::GMD => "gmd",
+            Currency::GNF => "gnf",
+            Currency::GTQ => "gtq",
+            Currency::GYD => "gyd",
+            Currency::HKD => "hkd",
+            Currency::HNL => "hnl",
+            Currency::HRK => "hrk",
+            Currency::HTG => "htg",
+            Currency::HUF => "huf",
+            Currency::IDR => "idr",
+            Currency::ILS => "ils",
+            Currency::INR => "inr",
+            Currency::ISK => "isk",
+            Currency::JMD => "jmd",
+            Currency::JOD => "jod",
+            Currency::JPY => "jpy",
+            Currency::KES => "kes",
+            Currency::KGS => "kgs",
+            Currency::KHR => "khr",
+            Currency::KMF => "kmf",
+            Currency::KRW => "krw",
+            Currency::KWD => "kwd",
+            Currency::KYD => "kyd",
+            Currency::KZT => "kzt",
+            Currency::LAK => "lak",
+            Currency::LBP => "lbp",
+            Currency::LKR => "lkr",
+            Currency::LRD => "lrd",
+            Currency::LSL => "lsl",
+            Currency::LTL => "ltl",
+            Currency::LVL => "lvl",
+            Currency::MAD => "mad",
+            Currency::MDL => "mdl",
+            Currency::MGA => "mga",
+            Currency::MKD => "mkd",
+            Currency::MNT => "mnt",
+            Currency::MOP => "mop",
+            Currency::MRO => "mro",
+            Currency::MUR => "mur",
+            Currency::MVR => "mvr",
+            Currency::MWK => "mwk",
+            Currency::MXN => "mxn",
+            Currency::MYR => "myr",
+            Currency::MZN => "mzn",
+            Currency::NAD => "nad",
+            Currency::NGN => "ngn",
+            Currency::NIO => "nio",
+            Currency::NOK => "nok",
+            Currency::NPR => "npr",
+            Currency::NZD => "nzd",
+            Currency::OMR => "omr",
+            Currency::PAB => "pab",
+            Currency::PEN => "pen",
+            Currency::PGK => "pgk",
+            Currency::PHP => "php",
+            Currency::PKR => "pkr",
+            Currency::PLN => "pln",
+            Currency::PYG => "pyg",
+            Currency::QAR => "qar",
+            Currency::RON => "ron",
+            Currency::RSD => "rsd",
+            Currency::RUB => "rub",
+            Currency::RWF => "rwf",
+            Currency::SAR => "sar",
+            Currency::SBD => "sbd",
+            Currency::SCR => "scr",
+            Currency::SEK => "sek",
+            Currency::SGD => "sgd",
+            Currency::SHP => "shp",
+            Currency::SLL => "sll",
+            Currency::SOS => "sos",
+            Currency::SRD => "srd",
+            Currency::STD => "std",
+            Currency::SVC => "svc",
+            Currency::SZL => "szl",
+            Currency::THB => "thb",
+            Currency::TJS => "tjs",
+            Currency::TND => "tnd",
+            Currency::TOP => "top",
+            Currency::TRY => "try",
+            Currency::TTD => "ttd",
+            Currency::TWD => "twd",
+            Currency::TZS => "tzs",
+            Currency::UAH => "uah",
+            Currency::UGX => "ugx",
+            Currency::USD => "usd",
+            Currency::UYU => "uyu",
+            Currency::UZS => "uzs",
+            Currency::VEF => "vef",
+            Currency::VND => "vnd",
+            Currency::VUV => "vuv",
+            Currency::WST => "wst",
+            Currency::XAF => "xaf",
+            Currency::XCD => "xcd",
+            Currency::XOF => "xof",
+            Currency::XPF => "xpf",
+            Currency::YER => "yer",
+            Currency::ZAR => "zar",
+            Currency::ZMW => "zmw",
+            Currency::Unknown(code) => code,
+        }
+    }
+
+    /// The number of decimal places of the currency's smallest unit, in which Stripe
+    /// represents amounts (e.g. `2` for USD, where an amount of `1000` is $10.00,
+    /// and `0` for JPY, where an amount of `1000` is ¥1000).
+    ///
+    /// For more details see https://stripe.com/docs/currencies#zero-decimal.
+    ///
+    /// The exponent of a `Currency::Unknown` isn't known, so `2` (the exponent of most
+    /// currencies) is assumed; `Money::from_decimal` rejects unknown currencies instead.
+    pub fn exponent(&self) -> u32 {
+        match self {
+            Currency::BIF
+            | Currency::CLP
+            | Currency::DJF
+            | Currency::GNF
+            | Currency::JPY
+            | Currency::KMF
+            | Currency::KRW
+            | Currency::MGA
+            | Currency::PYG
+            | Currency::RWF
+            | Currency::UGX
+            | Currency::VND
+            | Currency::VUV
+            | Currency::XAF
+            | Currency::XOF
+            | Currency::XPF => 0,
+            Currency::BHD | Currency::JOD | Currency::KWD | Currency::OMR | Currency::TND => 3,
+            Currency::BTC => 8,  // satoshi
+            Currency::XMR => 12, // piconero
+            Currency::ETH => 18, // wei
+            _ => 2,
+        }
+    }
+
+    /// The minimum amount (in the currency's smallest unit) which can be charged in the
+    /// currency, if Stripe has a minimum for it.
+    ///
+    /// N.B. The minimum applies to the amount once converted to the currency of the account's
+    /// payouts, so a charge in another currency may need a higher amount.
+    ///
+    /// For more details see https://stripe.com/docs/currencies#minimum-and-maximum-charge-amounts.
+    pub fn minimum_charge_amount(&self) -> Option<i64> {
+        match self {
+            Currency::USD => Some(50),
+            Currency::AED => Some(200),
+            Currency::AUD => Some(50),
+            Currency::BGN => Some(100),
+            Currency::BRL => Some(50),
+            Currency::CAD => Some(50),
+            Currency::CHF => Some(50),
+            Currency::CZK => Some(1500),
+            Currency::DKK => Some(250),
+            Currency::EUR => Some(50),
+            Currency::GBP => Some(30),
+            Currency::HKD => Some(400),
+            Currency::HUF => Some(17500),
+            Currency::INR => Some(50),
+            Currency::JPY => Some(50),
+            Currency::MXN => Some(1000),
+            Currency::MYR => Some(200),
+            Currency::NOK => Some(300),
+            Currency::NZD => Some(50),
+            Currency::PLN => Some(200),
+            Currency::RON => Some(200),
+            Currency::SEK => Some(300),
+            Currency::SGD => Some(50),
+            Currency::THB => Some(1000),
+            _ => None,
+        }
+    }
+}
+
+impl AsRef<str> for Currency {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl std::fmt::Display for Currency {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+        self.as_str().fmt(f)
     }
 }
 
+impl From<&str> for Currency {
+    /// Converts a currency code, keeping a code which isn't supported (which `parse` rejects)
+    /// as `Currency::Unknown`.
+    fn from(code: &str) -> Self {
+        code.parse().unwrap_or_else(|_| Currency::Unknown(code.to_string()))
+    }
+}
+
+serde_string_enum!(Currency);
+
 impl std::str::FromStr for Currency {
     type Err = ParseCurrencyError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -326,6 +431,7 @@ impl std::str::FromStr for Currency {
             "bbd" => Ok(Currency::BBD),
             "bdt" => Ok(Currency::BDT),
             "bgn" => Ok(Currency::BGN),
+            "bhd" => Ok(Currency::BHD),
             "bif" => Ok(Currency::BIF),
             "bmd" => Ok(Currency::BMD),
             "bnd" => Ok(Currency::BND),
@@ -370,12 +476,14 @@ impl std::str::FromStr for Currency {
             "inr" => Ok(Currency::INR),
             "isk" => Ok(Currency::ISK),
             "jmd" => Ok(Currency::JMD),
+            "jod" => Ok(Currency::JOD),
             "jpy" => Ok(Currency::JPY),
             "kes" => Ok(Currency::KES),
             "kgs" => Ok(Currency::KGS),
             "khr" => Ok(Currency::KHR),
             "kmf" => Ok(Currency::KMF),
             "krw" => Ok(Currency::KRW),
+            "kwd" => Ok(Currency::KWD),
             "kyd" => Ok(Currency::KYD),
             "kzt" => Ok(Currency::KZT),
             "lak" => Ok(Currency::LAK),
@@ -404,6 +512,7 @@ impl std::str::FromStr for Currency {
             "nok" => Ok(Currency::NOK),
             "npr" => Ok(Currency::NPR),
             "nzd" => Ok(Currency::NZD),
+            "omr" => Ok(Currency::OMR),
             "pab" => Ok(Currency::PAB),
             "pen" => Ok(Currency::PEN),
             "pgk" => Ok(Currency::PGK),
@@ -430,6 +539,7 @@ impl std::str::FromStr for Currency {
             "szl" => Ok(Currency::SZL),
             "thb" => Ok(Currency::THB),
             "tjs" => Ok(Currency::TJS),
+            "tnd" => Ok(Currency::TND),
             "top" => Ok(Currency::TOP),
             "try" => Ok(Currency::TRY),
             "ttd" => Ok(Currency::TTD),
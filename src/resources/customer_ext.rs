@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{BankAccountId, CardId, CustomerId, PaymentSourceId};
 use crate::params::Deleted;
 use crate::resources::{BankAccount, Customer, PaymentSource, PaymentSourceParams, Source};
@@ -8,11 +8,11 @@ impl Customer {
     /// Attaches a source to a customer, does not change default Source for the Customer
     ///
     /// For more details see [https://stripe.com/docs/api#attach_source](https://stripe.com/docs/api#attach_source).
-    pub fn attach_source(
-        client: &Client,
+    pub fn attach_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source: PaymentSourceParams<'_>,
-    ) -> Response<PaymentSource> {
+    ) -> C::Response<PaymentSource> {
         #[derive(Serialize)]
         struct AttachSource<'a> {
             source: PaymentSourceParams<'a>,
@@ -24,32 +24,32 @@ impl Customer {
     /// Detaches a source from a customer
     ///
     /// For more details see [https://stripe.com/docs/api#detach_source](https://stripe.com/docs/api#detach_source).
-    pub fn detach_source(
-        client: &Client,
+    pub fn detach_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
-    ) -> Response<DetachedSource> {
+    ) -> C::Response<DetachedSource> {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
     /// Retrieves a Card, BankAccount, or Source for a Customer
-    pub fn retrieve_source(
-        client: &Client,
+    pub fn retrieve_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
-    ) -> Response<PaymentSource> {
+    ) -> C::Response<PaymentSource> {
         client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
     /// Verifies a Bank Account for a Customer.
     ///
     /// For more details see https://stripe.com/docs/api/customer_bank_accounts/verify.
-    pub fn verify_bank_account(
-        client: &Client,
+    pub fn verify_bank_account<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         bank_account_id: &BankAccountId,
         params: VerifyBankAccount<'_>,
-    ) -> Response<BankAccount> {
+    ) -> C::Response<BankAccount> {
         client.post_form(
             &format!("/customers/{}/sources/{}/verify", customer_id, bank_account_id),
             params,
@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{
     AlipayAccountId, BankAccountId, CardId, CouponId, CustomerId, PaymentMethodId, PaymentSourceId,
 };
@@ -149,19 +149,29 @@ impl Customer {
     /// Returns a list of your customers.
     ///
     /// The customers are returned sorted by creation date, with the most recent customers appearing first.
-    pub fn list(client: &Client, params: ListCustomers<'_>) -> Response<List<Customer>> {
-        client.get_query("/customers", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListCustomers<'_>,
+    ) -> C::Response<List<Customer>> {
+        List::get_query(client, "/customers", &params)
     }
 
     /// Creates a new customer object.
-    pub fn create(client: &Client, params: CreateCustomer<'_>) -> Response<Customer> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateCustomer<'_>,
+    ) -> C::Response<Customer> {
         client.post_form("/customers", &params)
     }
 
     /// Retrieves the details of an existing customer.
     ///
     /// You need only supply the unique customer identifier that was returned upon customer creation.
-    pub fn retrieve(client: &Client, id: &CustomerId, expand: &[&str]) -> Response<Customer> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &CustomerId,
+        expand: &[&str],
+    ) -> C::Response<Customer> {
         client.get_query(&format!("/customers/{}", id), &Expand { expand })
     }
 
@@ -172,11 +182,11 @@ impl Customer {
     /// When you update a customer to a new valid card source by passing the **source** parameter: for each of the customer’s current subscriptions, if the subscription bills automatically and is in the `past_due` state, then the latest open invoice for the subscription with automatic collection enabled will be retried.
     /// This retry will not count as an automatic retry, and will not affect the next regularly scheduled payment for the invoice.
     /// Changing the **default_source** for a customer will not trigger this behavior.  This request accepts mostly the same arguments as the customer creation call.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &CustomerId,
         params: UpdateCustomer<'_>,
-    ) -> Response<Customer> {
+    ) -> C::Response<Customer> {
         client.post_form(&format!("/customers/{}", id), &params)
     }
 
@@ -184,7 +194,10 @@ impl Customer {
     ///
     /// It cannot be undone.
     /// Also immediately cancels any active subscriptions on the customer.
-    pub fn delete(client: &Client, id: &CustomerId) -> Response<Deleted<CustomerId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &CustomerId,
+    ) -> C::Response<Deleted<CustomerId>> {
         client.delete(&format!("/customers/{}", id))
     }
 }
@@ -661,20 +674,33 @@ pub struct TaxInfoParams {
 }
 
 /// An enum representing the possible values of an `Customer`'s `tax_exempt` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CustomerTaxExempt {
     Exempt,
     None,
     Reverse,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CustomerTaxExempt {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CustomerTaxExempt::Exempt => "exempt",
             CustomerTaxExempt::None => "none",
             CustomerTaxExempt::Reverse => "reverse",
+            CustomerTaxExempt::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CustomerTaxExempt {
+    fn from(value: &str) -> Self {
+        match value {
+            "exempt" => CustomerTaxExempt::Exempt,
+            "none" => CustomerTaxExempt::None,
+            "reverse" => CustomerTaxExempt::Reverse,
+            _ => CustomerTaxExempt::Unknown(value.to_string()),
         }
     }
 }
@@ -691,21 +717,36 @@ impl std::fmt::Display for CustomerTaxExempt {
     }
 }
 
+serde_string_enum!(CustomerTaxExempt);
+
 /// An enum representing the possible values of an `CreateCustomer`'s `tax_exempt` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CustomerTaxExemptFilter {
     Exempt,
     None,
     Reverse,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CustomerTaxExemptFilter {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CustomerTaxExemptFilter::Exempt => "exempt",
             CustomerTaxExemptFilter::None => "none",
             CustomerTaxExemptFilter::Reverse => "reverse",
+            CustomerTaxExemptFilter::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CustomerTaxExemptFilter {
+    fn from(value: &str) -> Self {
+        match value {
+            "exempt" => CustomerTaxExemptFilter::Exempt,
+            "none" => CustomerTaxExemptFilter::None,
+            "reverse" => CustomerTaxExemptFilter::Reverse,
+            _ => CustomerTaxExemptFilter::Unknown(value.to_string()),
         }
     }
 }
@@ -722,21 +763,36 @@ impl std::fmt::Display for CustomerTaxExemptFilter {
     }
 }
 
+serde_string_enum!(CustomerTaxExemptFilter);
+
 /// An enum representing the possible values of an `TaxIdData`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaxIdDataType {
     AuAbn,
     EuVat,
     NzGst,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TaxIdDataType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdDataType::AuAbn => "au_abn",
             TaxIdDataType::EuVat => "eu_vat",
             TaxIdDataType::NzGst => "nz_gst",
+            TaxIdDataType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TaxIdDataType {
+    fn from(value: &str) -> Self {
+        match value {
+            "au_abn" => TaxIdDataType::AuAbn,
+            "eu_vat" => TaxIdDataType::EuVat,
+            "nz_gst" => TaxIdDataType::NzGst,
+            _ => TaxIdDataType::Unknown(value.to_string()),
         }
     }
 }
@@ -753,17 +809,30 @@ impl std::fmt::Display for TaxIdDataType {
     }
 }
 
+serde_string_enum!(TaxIdDataType);
+
 /// An enum representing the possible values of an `TaxInfoParams`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaxInfoType {
     Vat,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TaxInfoType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxInfoType::Vat => "vat",
+            TaxInfoType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TaxInfoType {
+    fn from(value: &str) -> Self {
+        match value {
+            "vat" => TaxInfoType::Vat,
+            _ => TaxInfoType::Unknown(value.to_string()),
         }
     }
 }
@@ -779,3 +848,5 @@ impl std::fmt::Display for TaxInfoType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TaxInfoType);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::DisputeId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Charge, Currency, File};
@@ -68,12 +68,19 @@ pub struct Dispute {
 
 impl Dispute {
     /// Returns a list of your disputes.
-    pub fn list(client: &Client, params: ListDisputes<'_>) -> Response<List<Dispute>> {
-        client.get_query("/disputes", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListDisputes<'_>,
+    ) -> C::Response<List<Dispute>> {
+        List::get_query(client, "/disputes", &params)
     }
 
     /// Retrieves the dispute with the given ID.
-    pub fn retrieve(client: &Client, id: &DisputeId, expand: &[&str]) -> Response<Dispute> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &DisputeId,
+        expand: &[&str],
+    ) -> C::Response<Dispute> {
         client.get_query(&format!("/disputes/{}", id), &Expand { expand })
     }
 }
@@ -283,8 +290,7 @@ impl<'a> ListDisputes<'a> {
 }
 
 /// An enum representing the possible values of an `Dispute`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DisputeStatus {
     ChargeRefunded,
     Lost,
@@ -294,10 +300,12 @@ pub enum DisputeStatus {
     WarningNeedsResponse,
     WarningUnderReview,
     Won,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl DisputeStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             DisputeStatus::ChargeRefunded => "charge_refunded",
             DisputeStatus::Lost => "lost",
@@ -307,6 +315,23 @@ impl DisputeStatus {
             DisputeStatus::WarningNeedsResponse => "warning_needs_response",
             DisputeStatus::WarningUnderReview => "warning_under_review",
             DisputeStatus::Won => "won",
+            DisputeStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for DisputeStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "charge_refunded" => DisputeStatus::ChargeRefunded,
+            "lost" => DisputeStatus::Lost,
+            "needs_response" => DisputeStatus::NeedsResponse,
+            "under_review" => DisputeStatus::UnderReview,
+            "warning_closed" => DisputeStatus::WarningClosed,
+            "warning_needs_response" => DisputeStatus::WarningNeedsResponse,
+            "warning_under_review" => DisputeStatus::WarningUnderReview,
+            "won" => DisputeStatus::Won,
+            _ => DisputeStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -322,3 +347,5 @@ impl std::fmt::Display for DisputeStatus {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(DisputeStatus);
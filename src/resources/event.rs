@@ -1,388 +1,545 @@
-use crate::error::WebhookError;
+use crate::config::StripeClient;
+use crate::error::Error;
+use crate::ids::{AccountId, EventId};
+use crate::params::{Expand, List, Object, Paginator, RangeQuery, Timestamp};
 use crate::resources::*;
-use chrono::Utc;
-#[cfg(feature = "webhooks")]
-use hmac::{Hmac, Mac};
 use serde_derive::{Deserialize, Serialize};
-#[cfg(feature = "webhooks")]
-use sha2::Sha256;
-
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-pub enum EventType {
-    #[serde(rename = "account.updated")]
-    AccountUpdated,
-    #[serde(rename = "account.application.deauthorized")]
-    AccountApplicationDeauthorized,
-    #[serde(rename = "account.external_account.created")]
-    AccountExternalAccountCreated,
-    #[serde(rename = "account.external_account.deleted")]
-    AccountExternalAccountDeleted,
-    #[serde(rename = "account.external_account.updated")]
-    AccountExternalAccountUpdated,
-    #[serde(rename = "application_fee.created")]
-    ApplicationFeeCreated,
-    #[serde(rename = "application_fee.refunded")]
-    ApplicationFeeRefunded,
-    #[serde(rename = "application_fee.refund.updated")]
-    ApplicationFeeRefundUpdated,
-    #[serde(rename = "balance.available")]
-    BalanceAvailable,
-    #[serde(rename = "charge.captured")]
-    ChargeCaptured,
-    #[serde(rename = "charge.failed")]
-    ChargeFailed,
-    #[serde(rename = "charge.pending")]
-    ChargePending,
-    #[serde(rename = "charge.refunded")]
-    ChargeRefunded,
-    #[serde(rename = "charge.succeeded")]
-    ChargeSucceeded,
-    #[serde(rename = "charge.updated")]
-    ChargeUpdated,
-    #[serde(rename = "charge.dispute.closed")]
-    ChargeDisputeClosed,
-    #[serde(rename = "charge.dispute.created")]
-    ChargeDisputeCreated,
-    #[serde(rename = "charge.dispute.funds_reinstated")]
-    ChargeDisputeFundsReinstated,
-    #[serde(rename = "charge.dispute.funds_withdrawn")]
-    ChargeDisputeFundsWithdrawn,
-    #[serde(rename = "charge.dispute.updated")]
-    ChargeDisputeUpdated,
-    #[serde(rename = "charge.refund.updated")]
-    ChargeRefundUpdated,
-    #[serde(rename = "coupon.created")]
-    CouponCreated,
-    #[serde(rename = "coupon.deleted")]
-    CouponDeleted,
-    #[serde(rename = "coupon.updated")]
-    CouponUpdated,
-    #[serde(rename = "customer.created")]
-    CustomerCreated,
-    #[serde(rename = "customer.deleted")]
-    CustomerDeleted,
-    #[serde(rename = "customer.updated")]
-    CustomerUpdated,
-    #[serde(rename = "customer.discount.created")]
-    CustomerDiscountCreated,
-    #[serde(rename = "customer.discount.deleted")]
-    CustomerDiscountDeleted,
-    #[serde(rename = "customer.discount.updated")]
-    CustomerDiscountUpdated,
-    #[serde(rename = "customer.source.created")]
-    CustomerSourceCreated,
-    #[serde(rename = "customer.source.deleted")]
-    CustomerSourceDeleted,
-    #[serde(rename = "customer.source.updated")]
-    CustomerSourceUpdated,
-    #[serde(rename = "customer.subscription.created")]
-    CustomerSubscriptionCreated,
-    #[serde(rename = "customer.subscription.deleted")]
-    CustomerSubscriptionDeleted,
-    #[serde(rename = "customer.subscription.trial_will_end")]
-    CustomerSubscriptionTrialWillEnd,
-    #[serde(rename = "customer.subscription.updated")]
-    CustomerSubscriptionUpdated,
-    #[serde(rename = "file.created")]
-    FileCreated,
-    #[serde(rename = "invoice.created")]
-    InvoiceCreated,
-    #[serde(rename = "invoice.payment_failed")]
-    InvoicePaymentFailed,
-    #[serde(rename = "invoice.payment_succeeded")]
-    InvoicePaymentSucceeded,
-    #[serde(rename = "invoice.updated")]
-    InvoiceUpdated,
-    #[serde(rename = "invoice.upcoming")]
-    InvoiceUpcoming,
-    #[serde(rename = "invoiceitem.created")]
-    InvoiceItemCreated,
-    #[serde(rename = "invoiceitem.deleted")]
-    InvoiceItemDeleted,
-    #[serde(rename = "invoiceitem.updated")]
-    InvoiceItemUpdated,
-    #[serde(rename = "order.created")]
-    OrderCreated,
-    #[serde(rename = "order.payment_failed")]
-    OrderPaymentFailed,
-    #[serde(rename = "order.payment_succeeded")]
-    OrderPaymentSucceeded,
-    #[serde(rename = "order.updated")]
-    OrderUpdated,
-    #[serde(rename = "order_return.updated")]
-    OrderReturnUpdated,
-    #[serde(rename = "payment_intent.amount_capturable_updated")]
-    PaymentIntentAmountCapturableUpdated,
-    #[serde(rename = "payment_intent.created")]
-    PaymentIntentCreated,
-    #[serde(rename = "payment_intent.payment_failed")]
-    PaymentIntentPaymentFailed,
-    #[serde(rename = "payment_intent.requires_capture")]
-    PaymentIntentRequiresCapture,
-    #[serde(rename = "payment_intent.succeeded")]
-    PaymentIntentSucceeded,
-    #[serde(rename = "payout.canceled")]
-    PayoutCanceled,
-    #[serde(rename = "payout.created")]
-    PayoutCreated,
-    #[serde(rename = "payout.failed")]
-    PayoutFailed,
-    #[serde(rename = "payout.paid")]
-    PayoutPaid,
-    #[serde(rename = "payout.updated")]
-    PayoutUpdated,
-    #[serde(rename = "plan.created")]
-    PlanCreated,
-    #[serde(rename = "plan.deleted")]
-    PlanDeleted,
-    #[serde(rename = "plan.updated")]
-    PlanUpdated,
-    #[serde(rename = "product.created")]
-    ProductCreated,
-    #[serde(rename = "product.deleted")]
-    ProductDeleted,
-    #[serde(rename = "product.updated")]
-    ProductUpdated,
-    #[serde(rename = "review.closed")]
-    ReviewClosed,
-    #[serde(rename = "review.opened")]
-    ReviewOpened,
-    #[serde(rename = "sigma.scheduled_query_run.created")]
-    SigmaScheduledQueryRunCreated,
-    #[serde(rename = "sku.created")]
-    SkuCreated,
-    #[serde(rename = "sku.deleted")]
-    SkuDeleted,
-    #[serde(rename = "sku.updated")]
-    SkuUpdated,
-    #[serde(rename = "source.canceled")]
-    SourceCanceled,
-    #[serde(rename = "source.chargeable")]
-    Sourcechargeable,
-    #[serde(rename = "source.failed")]
-    SourceFailed,
-    #[serde(rename = "source.transaction.created")]
-    SourceTransactionCreated,
-    #[serde(rename = "transfer.created")]
-    TransferCreated,
-    #[serde(rename = "transfer.reversed")]
-    TransferReversed,
-    #[serde(rename = "transfer.updated")]
-    TransferUpdated,
+
+/// Defines `EventType` with a variant for each of the event types named.
+///
+/// N.B. It's invoked with every event type in the spec by the generated `event_types.rs`.
+macro_rules! def_event_types {
+    ($($variant:ident = $name:literal,)*) => {
+        /// The type of an `Event`, e.g. `invoice.created` or `charge.refunded`.
+        #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+        pub enum EventType {
+            $($variant,)*
+
+            /// An event type not yet supported by the library, e.g. one introduced
+            /// by a newer API version.  The name of the event type is preserved.
+            Unknown(String),
+        }
+
+        impl EventType {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(EventType::$variant => $name,)*
+                    EventType::Unknown(name) => name.as_str(),
+                }
+            }
+        }
+
+        impl From<String> for EventType {
+            fn from(name: String) -> EventType {
+                match name.as_str() {
+                    $($name => EventType::$variant,)*
+                    _ => EventType::Unknown(name),
+                }
+            }
+        }
+    };
+}
+
+impl From<&str> for EventType {
+    fn from(name: &str) -> EventType {
+        EventType::from(name.to_string())
+    }
+}
+
+impl AsRef<str> for EventType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for EventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(EventType::from(name))
+    }
 }
 
+/// The resource representing a Stripe "Event".
+///
+/// For more details see [https://stripe.com/docs/api/events/object](https://stripe.com/docs/api/events/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Event {
+    /// Unique identifier for the object.
+    pub id: EventId,
+
+    /// The connected account which the event occurred on, for events sent to a Connect webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountId>,
+
+    /// The Stripe API version used to render `data`.
+    ///
+    /// *Note: This property is populated only for events on or after October 31, 2014*.
+    /// N.B. This is kept as a string so that events rendered with a newer version still parse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    pub data: EventData,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Number of webhooks that have yet to be successfully delivered (i.e., to return a 20x response) to the URLs you've specified.
+    pub pending_webhooks: u64,
+
+    /// Information on the API request that instigated the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<NotificationEventRequest>,
+
+    /// Description of the event (e.g., `invoice.created` or `charge.refunded`).
     #[serde(rename = "type")]
     pub event_type: EventType,
-    pub data: EventData,
-    // ...
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct EventData {
-    pub object: EventObject,
-    // previous_attributes: ...
+impl Event {
+    /// Returns `true` if `data` was rendered with another API version than `API_VERSION`,
+    /// in which case its objects may not match the types in this crate.
+    pub fn has_api_version_mismatch(&self) -> bool {
+        match &self.api_version {
+            Some(version) => version != API_VERSION.as_str(),
+            None => false,
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum EventObject {
-    Account(Account),
-    ApplicationFee(ApplicationFee),
-    #[serde(rename = "fee_refund")]
-    ApplicationFeeRefund(ApplicationFeeRefund),
-    Balance(Balance),
-    BankAccount(BankAccount),
-    Charge(Charge),
-    Dispute(Dispute),
-    File(File),
-    Invoice(Invoice),
-    #[serde(rename = "invoiceitem")]
-    InvoiceItem(InvoiceItem),
-    Order(Order),
-    OrderReturn(OrderReturn),
-    PaymentIntent(PaymentIntent),
-    Payout(Payout),
-    Plan(Plan),
-    Product(Product),
-    Refund(Refund),
-    Review(Review),
-    Sku(Sku),
-    Subscription(Subscription),
-    Transfer(Transfer),
-}
+impl Event {
+    /// List events, going back up to 30 days.
+    ///
+    /// Each event data is rendered according to Stripe API version at its creation time, specified in [event object](https://stripe.com/docs/api/events/object) `api_version` attribute (not according to your current Stripe API version or `Stripe-Version` header).
+    pub fn list<C: StripeClient>(client: &C, params: ListEvents<'_>) -> C::Response<List<Event>> {
+        List::get_query(client, "/events", &params)
+    }
+
+    /// Retrieves the details of an event.
+    ///
+    /// Supply the unique identifier of the event, which you might have received in a webhook.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &EventId,
+        expand: &[&str],
+    ) -> C::Response<Event> {
+        client.get_query(&format!("/events/{}", id), &Expand { expand })
+    }
 
-#[cfg(feature = "webhooks")]
-pub struct Webhook {
-    current_timestamp: i64,
+    /// Returns the events created after the event `checkpoint` (which matches the filters
+    /// of `params`), from the oldest to the newest.
+    ///
+    /// This is useful to catch up on the events which were missed while a webhook endpoint
+    /// was unavailable.  Each page of events is only fetched once the events before it have
+    /// been consumed.  The cursors in `params` are ignored.
+    ///
+    /// ```rust,no_run
+    /// # let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET");
+    /// let checkpoint = "evt_123".parse().unwrap();
+    /// let params = stripe::ListEvents::new();
+    /// for event in stripe::Event::list_since(&client, &checkpoint, params).unwrap() {
+    ///     let event = event.unwrap();
+    ///     // Handle the event, then store `event.id` as the new checkpoint
+    /// }
+    /// ```
+    pub fn list_since<C: StripeClient>(
+        client: &C,
+        checkpoint: &EventId,
+        params: ListEvents<'_>,
+    ) -> Result<Paginator<Event, C>, Error> {
+        let query = serde_qs::to_string(&params).map_err(Error::serialize)?;
+        let url = format!("/v1/events?{}", query);
+        Ok(Paginator::before(client, url, checkpoint.to_string()))
+    }
 }
 
-#[cfg(feature = "webhooks")]
-impl Webhook {
-    pub fn construct_event(
-        payload: String,
-        sig: String,
-        secret: String,
-    ) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+impl Object for Event {
+    type Id = EventId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "event"
     }
+}
 
-    fn do_construct_event(
-        self,
-        payload: String,
-        sig: String,
-        secret: String,
-    ) -> Result<Event, WebhookError> {
-        // Get Stripe signature from header
-        let signature = Signature::parse(&sig)?;
-        let signed_payload = format!("{}{}{}", signature.t, ".", payload);
-
-        // Compute HMAC with the SHA256 hash function, using endpoing secret as key
-        // and signed_payload string as the message.
-        let mut mac =
-            Hmac::<Sha256>::new_varkey(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
-        mac.input(signed_payload.as_bytes());
-        let mac_result = mac.result();
-        let hex = Self::to_hex(mac_result.code().as_slice());
-        if hex != signature.v1 {
-            return Err(WebhookError::BadSignature);
-        }
+/// Builds an `Event` (or the json payload of one), e.g. to test a webhook handler offline.
+///
+/// ```rust
+/// use stripe::{EventBuilder, EventObject, EventType};
+///
+/// let balance = serde_json::json!({
+///     "object": "balance",
+///     "available": [{"amount": 1000, "currency": "usd"}],
+///     "livemode": false,
+///     "pending": [],
+/// });
+/// let payload = EventBuilder::new(EventType::BalanceAvailable, EventObject::Unknown(balance))
+///     .id("evt_123".parse().unwrap())
+///     .payload();
+///
+/// let event: stripe::Event = serde_json::from_str(&payload).unwrap();
+/// assert!(matches!(event.data.object, EventObject::Balance(_)));
+/// ```
+///
+/// N.B. An object given as json (with `EventObject::Unknown`) is parsed as its typed variant
+/// when the payload is parsed, so it must include its `object` tag.
+#[derive(Clone, Debug)]
+pub struct EventBuilder {
+    event: Event,
+}
 
-        // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
-            return Err(WebhookError::BadTimestamp(signature.t));
+impl EventBuilder {
+    /// Creates a builder for an event of type `event_type` about `object`, with a random id,
+    /// created now and rendered with `API_VERSION` in test mode.
+    pub fn new(event_type: EventType, object: EventObject) -> EventBuilder {
+        let id = format!("{}{}", EventId::prefix(), uuid::Uuid::new_v4().to_simple());
+        EventBuilder {
+            event: Event {
+                id: id.parse().expect("a generated event id has the `evt_` prefix"),
+                account: None,
+                api_version: Some(API_VERSION.as_str().to_string()),
+                created: chrono::Utc::now().timestamp(),
+                data: EventData { object, previous_attributes: None },
+                livemode: false,
+                pending_webhooks: 1,
+                request: None,
+                event_type,
+            },
         }
+    }
+
+    pub fn id(mut self, id: EventId) -> EventBuilder {
+        self.event.id = id;
+        self
+    }
 
-        serde_json::from_str(&payload).map_err(WebhookError::BadParse)
+    /// Sets the connected account which the event occurred on.
+    pub fn account(mut self, account: AccountId) -> EventBuilder {
+        self.event.account = Some(account);
+        self
     }
 
-    const CHARS: &'static [u8] = b"0123456789abcdef";
+    /// Sets the API version which the event was rendered with.
+    pub fn api_version(mut self, api_version: impl Into<String>) -> EventBuilder {
+        self.event.api_version = Some(api_version.into());
+        self
+    }
 
-    fn to_hex(bytes: &[u8]) -> String {
-        let mut v = Vec::with_capacity(bytes.len() * 2);
-        for &byte in bytes {
-            v.push(Self::CHARS[(byte >> 4) as usize]);
-            v.push(Self::CHARS[(byte & 0xf) as usize]);
-        }
+    pub fn created(mut self, created: Timestamp) -> EventBuilder {
+        self.event.created = created;
+        self
+    }
+
+    pub fn livemode(mut self, livemode: bool) -> EventBuilder {
+        self.event.livemode = livemode;
+        self
+    }
+
+    /// Sets the values of the attributes which were changed, for an `*.updated` event.
+    pub fn previous_attributes(
+        mut self,
+        previous_attributes: serde_json::Map<String, serde_json::Value>,
+    ) -> EventBuilder {
+        self.event.data.previous_attributes = Some(previous_attributes);
+        self
+    }
+
+    /// Sets the API request which caused the event.
+    pub fn request(mut self, request: NotificationEventRequest) -> EventBuilder {
+        self.event.request = Some(request);
+        self
+    }
 
-        unsafe { String::from_utf8_unchecked(v) }
+    pub fn build(self) -> Event {
+        self.event
+    }
+
+    /// Returns the event as json, as it would be sent to a webhook endpoint.
+    pub fn payload(&self) -> String {
+        serde_json::to_string(&self.event).expect("an event can always be serialized")
     }
 }
 
-#[cfg(feature = "webhooks")]
-#[derive(Debug)]
-struct Signature<'r> {
-    t: i64,
-    v1: &'r str,
-    v0: Option<&'r str>,
+/// The parameters for `Event::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListEvents<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Filter events by whether all webhooks were successfully delivered.
+    ///
+    /// If false, events which are still pending or have failed all delivery attempts to a webhook endpoint will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_success: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<EventId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<EventId>,
+
+    /// A string containing a specific event name, or group of events using * as a wildcard.
+    ///
+    /// The list will be filtered to include only events with a matching event property.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<&'a str>,
+
+    /// An array of up to 20 strings containing specific event names.
+    ///
+    /// The list will be filtered to include only events with a matching event property.
+    /// You may pass either `type` or `types`, but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<EventType>>,
 }
 
-#[cfg(feature = "webhooks")]
-impl<'r> Signature<'r> {
-    fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
-        use std::collections::HashMap;
-        let headers: HashMap<&str, &str> = raw
-            .split(',')
-            .map(|header| {
-                let mut key_and_value = header.split('=');
-                let key = key_and_value.next();
-                let value = key_and_value.next();
-                (key, value)
-            })
-            .filter_map(|(key, value)| match (key, value) {
-                (Some(key), Some(value)) => Some((key, value)),
-                _ => None,
-            })
-            .collect();
-        let t = headers.get("t").ok_or(WebhookError::BadSignature)?;
-        let v1 = headers.get("v1").ok_or(WebhookError::BadSignature)?;
-        let v0 = headers.get("v0").map(|r| *r);
-        Ok(Signature { t: t.parse::<i64>().map_err(WebhookError::BadHeader)?, v1, v0 })
+impl<'a> ListEvents<'a> {
+    pub fn new() -> Self {
+        ListEvents {
+            created: Default::default(),
+            delivery_success: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            type_: Default::default(),
+            types: Default::default(),
+        }
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventData {
+    /// Object containing the API resource relevant to the event.
+    ///
+    /// For example, an `invoice.created` event will have a full [invoice object](https://stripe.com/docs/api#invoice_object) as the value of the object key.
+    pub object: EventObject,
+
+    /// Object containing the names of the attributes that have changed, and their previous values (sent along only with *.updated events).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_attributes: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotificationEventRequest {
+    /// ID of the API request that caused the event.
+    ///
+    /// If null, the event was automatic (e.g., Stripe's automatic subscription handling).
+    /// Request logs are available in the [dashboard](https://dashboard.stripe.com/logs), but currently not in the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The idempotency key transmitted during the request, if any.
+    ///
+    /// *Note: This property is populated only for events on or after May 23, 2017*.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+/// A type of object which an `Event` can be about, i.e. the type of a variant of `EventObject`.
+pub trait FromEventObject: Sized {
+    /// Returns the object if it's of this type.
+    fn from_event_object(object: &EventObject) -> Option<&Self>;
+}
+
+/// Defines `EventObject` with a variant for each type of object named.
+///
+/// N.B. It's invoked with the object of every event type by the generated `event_types.rs`.
+macro_rules! def_event_objects {
+    ($($variant:ident($object:ty) = $name:literal,)*) => {
+        /// The object which an `Event` is about, tagged by its `object` property.
+        #[derive(Clone, Debug)]
+        pub enum EventObject {
+            $($variant($object),)*
+
+            /// An object not yet supported by the library, e.g. one introduced
+            /// by a newer API version, or an object which doesn't parse as its type
+            /// (e.g. one rendered with another API version).  The json of the object
+            /// is preserved.
+            Unknown(serde_json::Value),
+        }
+
+        impl EventObject {
+            /// The object's type, as in its `object` property.
+            pub fn object(&self) -> &str {
+                match self {
+                    $(EventObject::$variant(_) => $name,)*
+                    EventObject::Unknown(value) => {
+                        value.get("object").and_then(|name| name.as_str()).unwrap_or_default()
+                    }
+                }
+            }
+        }
+
+        $(impl FromEventObject for $object {
+            fn from_event_object(object: &EventObject) -> Option<&Self> {
+                match object {
+                    EventObject::$variant(object) => Some(object),
+                    _ => None,
+                }
+            }
+        })*
+
+        impl serde::Serialize for EventObject {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::Error;
+
+                let mut value = match self {
+                    $(EventObject::$variant(object) => {
+                        serde_json::to_value(object).map_err(S::Error::custom)?
+                    })*
+                    EventObject::Unknown(value) => return value.serialize(serializer),
+                };
+                if let serde_json::Value::Object(map) = &mut value {
+                    map.insert("object".into(), self.object().into());
+                }
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for EventObject {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+                let name = value.get("object").and_then(|name| name.as_str()).unwrap_or_default();
+                // N.B. An object which fails to parse as its type is kept as json, rather
+                //      than failing to parse the whole event.
+                let parsed = match name {
+                    $($name => {
+                        <$object as serde::Deserialize>::deserialize(&value).map(EventObject::$variant)
+                    })*
+                    _ => return Ok(EventObject::Unknown(value)),
+                };
+                match parsed {
+                    Ok(object) => Ok(object),
+                    Err(err) => {
+                        log::warn!("failed to parse the {} object of an event: {}", name, err);
+                        Ok(EventObject::Unknown(value))
+                    }
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "webhooks")]
     #[test]
-    fn test_signature_parse() {
-        use super::Signature;
-
-        let raw_signature =
-            "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd";
-        let signature = Signature::parse(raw_signature).unwrap();
-        assert_eq!(signature.t, 1492774577);
-        assert_eq!(
-            signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
-        );
-        assert_eq!(signature.v0, None);
-
-        let raw_signature_with_test_mode = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
-        let signature = Signature::parse(raw_signature_with_test_mode).unwrap();
-        assert_eq!(signature.t, 1492774577);
-        assert_eq!(
-            signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
-        );
-        assert_eq!(
-            signature.v0,
-            Some("6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39")
-        );
-    }
-
-    #[cfg(feature = "webhooks")]
-    #[test]
-    fn test_webhook_construct_event() {
+    fn test_event_previous_attributes() {
         let payload = r#"{
-  "id": "evt_123",
+  "id": "evt_456",
   "object": "event",
-  "account": "acct_123",
-  "api_version": "2017-05-25",
+  "api_version": "2019-03-14",
   "created": 1533204620,
   "data": {
     "object": {
       "id": "ii_123",
       "object": "invoiceitem",
-      "amount": 1000,
+      "amount": 2000,
       "currency": "usd",
       "customer": "cus_123",
       "date": 1533204620,
-      "description": "Test Invoice Item",
       "discountable": false,
-      "invoice": "in_123",
       "livemode": false,
       "metadata": {},
       "period": {
         "start": 1533204620,
         "end": 1533204620
       },
-      "plan": null,
-      "proration": false,
-      "quantity": null,
-      "subscription": null
+      "proration": false
+    },
+    "previous_attributes": {
+      "amount": 1000
     }
   },
   "livemode": false,
-  "pending_webhooks": 1,
+  "pending_webhooks": 0,
   "request": {
-    "id": "req_123",
-    "idempotency_key": "idempotency-key-123"
+    "id": null,
+    "idempotency_key": null
   },
-  "type": "invoiceitem.created"
+  "type": "invoiceitem.updated"
 }
 "#;
-        let event_timestamp = 1533204620;
-        let secret = "webhook_secret".to_string();
-        let signature = format!("t={},v1=f0bdba6d4eacbd8ad8a3bbadd7248e633ec1477f7899c124c51b39405fa36613,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
+        let event: super::Event = serde_json::from_str(payload).unwrap();
+        assert_eq!(event.event_type, super::EventType::InvoiceItemUpdated);
+        assert!(!event.has_api_version_mismatch());
+        assert!(event.request.unwrap().id.is_none());
+        let previous = event.data.previous_attributes.expect("expected previous attributes");
+        assert_eq!(previous["amount"], 1000);
+    }
 
-        let webhook = super::Webhook { current_timestamp: event_timestamp };
+    #[test]
+    fn test_unknown_event() {
+        use super::{Event, EventObject, EventType};
 
-        let event = webhook
-            .do_construct_event(payload.to_string(), signature, secret)
-            .expect("Failed to construct event");
+        let payload = r#"{
+  "id": "evt_789",
+  "object": "event",
+  "api_version": "2019-03-14",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "tmr_123",
+      "object": "terminal.reader",
+      "label": "Front desk"
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "request": null,
+  "type": "terminal.reader.action_succeeded"
+}
+"#;
+        let event: Event = serde_json::from_str(payload).unwrap();
+        assert_eq!(event.event_type, EventType::Unknown("terminal.reader.action_succeeded".into()));
+        assert_eq!(event.event_type.as_str(), "terminal.reader.action_succeeded");
+        assert_eq!(event.data.object.object(), "terminal.reader");
+        match &event.data.object {
+            EventObject::Unknown(object) => assert_eq!(object["label"], "Front desk"),
+            other => panic!("expected an unknown object, got {:?}", other),
+        }
 
-        assert_eq!(event.event_type, super::EventType::InvoiceItemCreated);
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "terminal.reader.action_succeeded");
+        assert_eq!(json["data"]["object"]["id"], "tmr_123");
+        assert_eq!(EventType::from("invoiceitem.created"), EventType::InvoiceItemCreated);
     }
 }
@@ -0,0 +1,192 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::resources::*;
+
+def_event_types! {
+    AccountApplicationAuthorized = "account.application.authorized",
+    AccountApplicationDeauthorized = "account.application.deauthorized",
+    AccountExternalAccountCreated = "account.external_account.created",
+    AccountExternalAccountDeleted = "account.external_account.deleted",
+    AccountExternalAccountUpdated = "account.external_account.updated",
+    AccountUpdated = "account.updated",
+    ApplicationFeeCreated = "application_fee.created",
+    ApplicationFeeRefundUpdated = "application_fee.refund.updated",
+    ApplicationFeeRefunded = "application_fee.refunded",
+    BalanceAvailable = "balance.available",
+    ChargeCaptured = "charge.captured",
+    ChargeDisputeClosed = "charge.dispute.closed",
+    ChargeDisputeCreated = "charge.dispute.created",
+    ChargeDisputeFundsReinstated = "charge.dispute.funds_reinstated",
+    ChargeDisputeFundsWithdrawn = "charge.dispute.funds_withdrawn",
+    ChargeDisputeUpdated = "charge.dispute.updated",
+    ChargeExpired = "charge.expired",
+    ChargeFailed = "charge.failed",
+    ChargePending = "charge.pending",
+    ChargeRefundUpdated = "charge.refund.updated",
+    ChargeRefunded = "charge.refunded",
+    ChargeSucceeded = "charge.succeeded",
+    ChargeUpdated = "charge.updated",
+    CheckoutSessionCompleted = "checkout.session.completed",
+    CouponCreated = "coupon.created",
+    CouponDeleted = "coupon.deleted",
+    CouponUpdated = "coupon.updated",
+    CreditNoteCreated = "credit_note.created",
+    CreditNoteUpdated = "credit_note.updated",
+    CreditNoteVoided = "credit_note.voided",
+    CustomerCreated = "customer.created",
+    CustomerDeleted = "customer.deleted",
+    CustomerDiscountCreated = "customer.discount.created",
+    CustomerDiscountDeleted = "customer.discount.deleted",
+    CustomerDiscountUpdated = "customer.discount.updated",
+    CustomerSourceCreated = "customer.source.created",
+    CustomerSourceDeleted = "customer.source.deleted",
+    CustomerSourceExpiring = "customer.source.expiring",
+    CustomerSourceUpdated = "customer.source.updated",
+    CustomerSubscriptionCreated = "customer.subscription.created",
+    CustomerSubscriptionDeleted = "customer.subscription.deleted",
+    CustomerSubscriptionTrialWillEnd = "customer.subscription.trial_will_end",
+    CustomerSubscriptionUpdated = "customer.subscription.updated",
+    CustomerTaxIdCreated = "customer.tax_id.created",
+    CustomerTaxIdDeleted = "customer.tax_id.deleted",
+    CustomerTaxIdUpdated = "customer.tax_id.updated",
+    CustomerUpdated = "customer.updated",
+    FileCreated = "file.created",
+    InvoiceCreated = "invoice.created",
+    InvoiceDeleted = "invoice.deleted",
+    InvoiceFinalized = "invoice.finalized",
+    InvoiceMarkedUncollectible = "invoice.marked_uncollectible",
+    InvoicePaymentActionRequired = "invoice.payment_action_required",
+    InvoicePaymentFailed = "invoice.payment_failed",
+    InvoicePaymentSucceeded = "invoice.payment_succeeded",
+    InvoiceSent = "invoice.sent",
+    InvoiceUpcoming = "invoice.upcoming",
+    InvoiceUpdated = "invoice.updated",
+    InvoiceVoided = "invoice.voided",
+    InvoiceItemCreated = "invoiceitem.created",
+    InvoiceItemDeleted = "invoiceitem.deleted",
+    InvoiceItemUpdated = "invoiceitem.updated",
+    IssuingAuthorizationCreated = "issuing_authorization.created",
+    IssuingAuthorizationRequest = "issuing_authorization.request",
+    IssuingAuthorizationUpdated = "issuing_authorization.updated",
+    IssuingCardCreated = "issuing_card.created",
+    IssuingCardUpdated = "issuing_card.updated",
+    IssuingCardholderCreated = "issuing_cardholder.created",
+    IssuingCardholderUpdated = "issuing_cardholder.updated",
+    IssuingDisputeCreated = "issuing_dispute.created",
+    IssuingDisputeUpdated = "issuing_dispute.updated",
+    IssuingSettlementCreated = "issuing_settlement.created",
+    IssuingSettlementUpdated = "issuing_settlement.updated",
+    IssuingTransactionCreated = "issuing_transaction.created",
+    IssuingTransactionUpdated = "issuing_transaction.updated",
+    OrderCreated = "order.created",
+    OrderPaymentFailed = "order.payment_failed",
+    OrderPaymentSucceeded = "order.payment_succeeded",
+    OrderUpdated = "order.updated",
+    OrderReturnCreated = "order_return.created",
+    OrderReturnUpdated = "order_return.updated",
+    PaymentIntentAmountCapturableUpdated = "payment_intent.amount_capturable_updated",
+    PaymentIntentCreated = "payment_intent.created",
+    PaymentIntentPaymentFailed = "payment_intent.payment_failed",
+    PaymentIntentRequiresCapture = "payment_intent.requires_capture",
+    PaymentIntentSucceeded = "payment_intent.succeeded",
+    PaymentMethodAttached = "payment_method.attached",
+    PaymentMethodCardAutomaticallyUpdated = "payment_method.card_automatically_updated",
+    PaymentMethodDetached = "payment_method.detached",
+    PaymentMethodUpdated = "payment_method.updated",
+    PayoutCanceled = "payout.canceled",
+    PayoutCreated = "payout.created",
+    PayoutFailed = "payout.failed",
+    PayoutPaid = "payout.paid",
+    PayoutUpdated = "payout.updated",
+    PersonCreated = "person.created",
+    PersonDeleted = "person.deleted",
+    PersonUpdated = "person.updated",
+    PlanCreated = "plan.created",
+    PlanDeleted = "plan.deleted",
+    PlanUpdated = "plan.updated",
+    ProductCreated = "product.created",
+    ProductDeleted = "product.deleted",
+    ProductUpdated = "product.updated",
+    RecipientCreated = "recipient.created",
+    RecipientDeleted = "recipient.deleted",
+    RecipientUpdated = "recipient.updated",
+    ReportingReportRunFailed = "reporting.report_run.failed",
+    ReportingReportRunSucceeded = "reporting.report_run.succeeded",
+    ReportingReportTypeUpdated = "reporting.report_type.updated",
+    ReviewClosed = "review.closed",
+    ReviewOpened = "review.opened",
+    SigmaScheduledQueryRunCreated = "sigma.scheduled_query_run.created",
+    SkuCreated = "sku.created",
+    SkuDeleted = "sku.deleted",
+    SkuUpdated = "sku.updated",
+    SourceCanceled = "source.canceled",
+    SourceChargeable = "source.chargeable",
+    SourceFailed = "source.failed",
+    SourceMandateNotification = "source.mandate_notification",
+    SourceRefundAttributesRequired = "source.refund_attributes_required",
+    SourceTransactionCreated = "source.transaction.created",
+    SourceTransactionUpdated = "source.transaction.updated",
+    TaxRateCreated = "tax_rate.created",
+    TaxRateUpdated = "tax_rate.updated",
+    TopupCanceled = "topup.canceled",
+    TopupCreated = "topup.created",
+    TopupFailed = "topup.failed",
+    TopupReversed = "topup.reversed",
+    TopupSucceeded = "topup.succeeded",
+    TransferCreated = "transfer.created",
+    TransferFailed = "transfer.failed",
+    TransferPaid = "transfer.paid",
+    TransferReversed = "transfer.reversed",
+    TransferUpdated = "transfer.updated",
+}
+
+def_event_objects! {
+    Account(Account) = "account",
+    Application(Application) = "application",
+    ApplicationFee(ApplicationFee) = "application_fee",
+    Balance(Balance) = "balance",
+    BankAccount(BankAccount) = "bank_account",
+    Card(Card) = "card",
+    Charge(Charge) = "charge",
+    CheckoutSession(CheckoutSession) = "checkout.session",
+    Coupon(Coupon) = "coupon",
+    CreditNote(CreditNote) = "credit_note",
+    Customer(Customer) = "customer",
+    Discount(Discount) = "discount",
+    Dispute(Dispute) = "dispute",
+    ApplicationFeeRefund(ApplicationFeeRefund) = "fee_refund",
+    File(File) = "file",
+    Invoice(Invoice) = "invoice",
+    InvoiceItem(InvoiceItem) = "invoiceitem",
+    IssuingAuthorization(IssuingAuthorization) = "issuing.authorization",
+    IssuingCard(IssuingCard) = "issuing.card",
+    IssuingCardholder(IssuingCardholder) = "issuing.cardholder",
+    IssuingDispute(IssuingDispute) = "issuing.dispute",
+    IssuingSettlement(IssuingSettlement) = "issuing.settlement",
+    IssuingTransaction(IssuingTransaction) = "issuing.transaction",
+    Order(Order) = "order",
+    OrderReturn(OrderReturn) = "order_return",
+    PaymentIntent(PaymentIntent) = "payment_intent",
+    PaymentMethod(PaymentMethod) = "payment_method",
+    Payout(Payout) = "payout",
+    Person(Person) = "person",
+    Plan(Plan) = "plan",
+    Product(Product) = "product",
+    Recipient(Recipient) = "recipient",
+    Refund(Refund) = "refund",
+    ReportingReportRun(ReportingReportRun) = "reporting.report_run",
+    ReportingReportType(ReportingReportType) = "reporting.report_type",
+    Review(Review) = "review",
+    ScheduledQueryRun(ScheduledQueryRun) = "scheduled_query_run",
+    Sku(Sku) = "sku",
+    Source(Source) = "source",
+    SourceMandateNotification(SourceMandateNotification) = "source_mandate_notification",
+    SourceTransaction(SourceTransaction) = "source_transaction",
+    Subscription(Subscription) = "subscription",
+    TaxId(TaxId) = "tax_id",
+    TaxRate(TaxRate) = "tax_rate",
+    Topup(Topup) = "topup",
+    Transfer(Transfer) = "transfer",
+}
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::FileId;
 use crate::params::{Expand, List, Object, RangeQuery, Timestamp};
 use crate::resources::FileLink;
@@ -54,14 +54,18 @@ impl File {
     /// Returns a list of the files that your account has access to.
     ///
     /// The files are returned sorted by creation date, with the most recently created files appearing first.
-    pub fn list(client: &Client, params: ListFiles<'_>) -> Response<List<File>> {
-        client.get_query("/files", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListFiles<'_>) -> C::Response<List<File>> {
+        List::get_query(client, "/files", &params)
     }
 
     /// Retrieves the details of an existing file object.
     ///
     /// Supply the unique file ID from a file, and Stripe will return the corresponding file object.
-    pub fn retrieve(client: &Client, id: &FileId, expand: &[&str]) -> Response<File> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &FileId,
+        expand: &[&str],
+    ) -> C::Response<File> {
         client.get_query(&format!("/files/{}", id), &Expand { expand })
     }
 }
@@ -127,8 +131,7 @@ impl<'a> ListFiles<'a> {
 }
 
 /// An enum representing the possible values of an `File`'s `purpose` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FilePurpose {
     BusinessIcon,
     BusinessLogo,
@@ -139,10 +142,12 @@ pub enum FilePurpose {
     PciDocument,
     SigmaScheduledQuery,
     TaxDocumentUserUpload,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl FilePurpose {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             FilePurpose::BusinessIcon => "business_icon",
             FilePurpose::BusinessLogo => "business_logo",
@@ -153,6 +158,24 @@ impl FilePurpose {
             FilePurpose::PciDocument => "pci_document",
             FilePurpose::SigmaScheduledQuery => "sigma_scheduled_query",
             FilePurpose::TaxDocumentUserUpload => "tax_document_user_upload",
+            FilePurpose::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for FilePurpose {
+    fn from(value: &str) -> Self {
+        match value {
+            "business_icon" => FilePurpose::BusinessIcon,
+            "business_logo" => FilePurpose::BusinessLogo,
+            "customer_signature" => FilePurpose::CustomerSignature,
+            "dispute_evidence" => FilePurpose::DisputeEvidence,
+            "finance_report_run" => FilePurpose::FinanceReportRun,
+            "identity_document" => FilePurpose::IdentityDocument,
+            "pci_document" => FilePurpose::PciDocument,
+            "sigma_scheduled_query" => FilePurpose::SigmaScheduledQuery,
+            "tax_document_user_upload" => FilePurpose::TaxDocumentUserUpload,
+            _ => FilePurpose::Unknown(value.to_string()),
         }
     }
 }
@@ -168,3 +191,5 @@ impl std::fmt::Display for FilePurpose {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(FilePurpose);
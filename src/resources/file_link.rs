@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{FileId, FileLinkId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{File, Scheduled};
@@ -46,28 +46,38 @@ pub struct FileLink {
 
 impl FileLink {
     /// Returns a list of file links.
-    pub fn list(client: &Client, params: ListFileLinks<'_>) -> Response<List<FileLink>> {
-        client.get_query("/file_links", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListFileLinks<'_>,
+    ) -> C::Response<List<FileLink>> {
+        List::get_query(client, "/file_links", &params)
     }
 
     /// Creates a new file link object.
-    pub fn create(client: &Client, params: CreateFileLink<'_>) -> Response<FileLink> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateFileLink<'_>,
+    ) -> C::Response<FileLink> {
         client.post_form("/file_links", &params)
     }
 
     /// Retrieves the file link with the given ID.
-    pub fn retrieve(client: &Client, id: &FileLinkId, expand: &[&str]) -> Response<FileLink> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &FileLinkId,
+        expand: &[&str],
+    ) -> C::Response<FileLink> {
         client.get_query(&format!("/file_links/{}", id), &Expand { expand })
     }
 
     /// Updates an existing file link object.
     ///
     /// Expired links can no longer be updated.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &FileLinkId,
         params: UpdateFileLink<'_>,
-    ) -> Response<FileLink> {
+    ) -> C::Response<FileLink> {
         client.post_form(&format!("/file_links/{}", id), &params)
     }
 }
@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -343,19 +343,26 @@ impl Invoice {
     /// You can list all invoices, or list the invoices for a specific customer.
     ///
     /// The invoices are returned sorted by creation date, with the most recently created invoices appearing first.
-    pub fn list(client: &Client, params: ListInvoices<'_>) -> Response<List<Invoice>> {
-        client.get_query("/invoices", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListInvoices<'_>,
+    ) -> C::Response<List<Invoice>> {
+        List::get_query(client, "/invoices", &params)
     }
 
     /// This endpoint creates a draft invoice for a given customer.
     ///
     /// The draft invoice created pulls in all pending invoice items on that customer, including prorations.
-    pub fn create(client: &Client, params: CreateInvoice<'_>) -> Response<Invoice> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateInvoice<'_>) -> C::Response<Invoice> {
         client.post_form("/invoices", &params)
     }
 
     /// Retrieves the invoice with the given ID.
-    pub fn retrieve(client: &Client, id: &InvoiceId, expand: &[&str]) -> Response<Invoice> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &InvoiceId,
+        expand: &[&str],
+    ) -> C::Response<Invoice> {
         client.get_query(&format!("/invoices/{}", id), &Expand { expand })
     }
 }
@@ -625,18 +632,30 @@ impl<'a> ListInvoices<'a> {
 }
 
 /// An enum representing the possible values of an `Invoice`'s `billing` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoiceBilling {
     ChargeAutomatically,
     SendInvoice,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl InvoiceBilling {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoiceBilling::ChargeAutomatically => "charge_automatically",
             InvoiceBilling::SendInvoice => "send_invoice",
+            InvoiceBilling::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoiceBilling {
+    fn from(value: &str) -> Self {
+        match value {
+            "charge_automatically" => InvoiceBilling::ChargeAutomatically,
+            "send_invoice" => InvoiceBilling::SendInvoice,
+            _ => InvoiceBilling::Unknown(value.to_string()),
         }
     }
 }
@@ -653,9 +672,10 @@ impl std::fmt::Display for InvoiceBilling {
     }
 }
 
+serde_string_enum!(InvoiceBilling);
+
 /// An enum representing the possible values of an `Invoice`'s `billing_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoiceBillingReason {
     AutomaticPendingInvoiceItemInvoice,
     Manual,
@@ -665,10 +685,12 @@ pub enum InvoiceBillingReason {
     SubscriptionThreshold,
     SubscriptionUpdate,
     Upcoming,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl InvoiceBillingReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoiceBillingReason::AutomaticPendingInvoiceItemInvoice => {
                 "automatic_pending_invoice_item_invoice"
@@ -680,6 +702,25 @@ impl InvoiceBillingReason {
             InvoiceBillingReason::SubscriptionThreshold => "subscription_threshold",
             InvoiceBillingReason::SubscriptionUpdate => "subscription_update",
             InvoiceBillingReason::Upcoming => "upcoming",
+            InvoiceBillingReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoiceBillingReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "automatic_pending_invoice_item_invoice" => {
+                InvoiceBillingReason::AutomaticPendingInvoiceItemInvoice
+            }
+            "manual" => InvoiceBillingReason::Manual,
+            "subscription" => InvoiceBillingReason::Subscription,
+            "subscription_create" => InvoiceBillingReason::SubscriptionCreate,
+            "subscription_cycle" => InvoiceBillingReason::SubscriptionCycle,
+            "subscription_threshold" => InvoiceBillingReason::SubscriptionThreshold,
+            "subscription_update" => InvoiceBillingReason::SubscriptionUpdate,
+            "upcoming" => InvoiceBillingReason::Upcoming,
+            _ => InvoiceBillingReason::Unknown(value.to_string()),
         }
     }
 }
@@ -696,21 +737,36 @@ impl std::fmt::Display for InvoiceBillingReason {
     }
 }
 
+serde_string_enum!(InvoiceBillingReason);
+
 /// An enum representing the possible values of an `Invoice`'s `customer_tax_exempt` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoiceCustomerTaxExempt {
     Exempt,
     None,
     Reverse,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl InvoiceCustomerTaxExempt {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoiceCustomerTaxExempt::Exempt => "exempt",
             InvoiceCustomerTaxExempt::None => "none",
             InvoiceCustomerTaxExempt::Reverse => "reverse",
+            InvoiceCustomerTaxExempt::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoiceCustomerTaxExempt {
+    fn from(value: &str) -> Self {
+        match value {
+            "exempt" => InvoiceCustomerTaxExempt::Exempt,
+            "none" => InvoiceCustomerTaxExempt::None,
+            "reverse" => InvoiceCustomerTaxExempt::Reverse,
+            _ => InvoiceCustomerTaxExempt::Unknown(value.to_string()),
         }
     }
 }
@@ -727,9 +783,10 @@ impl std::fmt::Display for InvoiceCustomerTaxExempt {
     }
 }
 
+serde_string_enum!(InvoiceCustomerTaxExempt);
+
 /// An enum representing the possible values of an `Invoice`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoiceStatus {
     Deleted,
     Draft,
@@ -737,10 +794,12 @@ pub enum InvoiceStatus {
     Paid,
     Uncollectible,
     Void,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl InvoiceStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoiceStatus::Deleted => "deleted",
             InvoiceStatus::Draft => "draft",
@@ -748,6 +807,21 @@ impl InvoiceStatus {
             InvoiceStatus::Paid => "paid",
             InvoiceStatus::Uncollectible => "uncollectible",
             InvoiceStatus::Void => "void",
+            InvoiceStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoiceStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "deleted" => InvoiceStatus::Deleted,
+            "draft" => InvoiceStatus::Draft,
+            "open" => InvoiceStatus::Open,
+            "paid" => InvoiceStatus::Paid,
+            "uncollectible" => InvoiceStatus::Uncollectible,
+            "void" => InvoiceStatus::Void,
+            _ => InvoiceStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -764,23 +838,39 @@ impl std::fmt::Display for InvoiceStatus {
     }
 }
 
+serde_string_enum!(InvoiceStatus);
+
 /// An enum representing the possible values of an `InvoicesResourceInvoiceTaxId`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoicesResourceInvoiceTaxIdType {
     AuAbn,
     EuVat,
     NzGst,
     Unknown,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Other(String),
 }
 
 impl InvoicesResourceInvoiceTaxIdType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoicesResourceInvoiceTaxIdType::AuAbn => "au_abn",
             InvoicesResourceInvoiceTaxIdType::EuVat => "eu_vat",
             InvoicesResourceInvoiceTaxIdType::NzGst => "nz_gst",
             InvoicesResourceInvoiceTaxIdType::Unknown => "unknown",
+            InvoicesResourceInvoiceTaxIdType::Other(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoicesResourceInvoiceTaxIdType {
+    fn from(value: &str) -> Self {
+        match value {
+            "au_abn" => InvoicesResourceInvoiceTaxIdType::AuAbn,
+            "eu_vat" => InvoicesResourceInvoiceTaxIdType::EuVat,
+            "nz_gst" => InvoicesResourceInvoiceTaxIdType::NzGst,
+            "unknown" => InvoicesResourceInvoiceTaxIdType::Unknown,
+            _ => InvoicesResourceInvoiceTaxIdType::Other(value.to_string()),
         }
     }
 }
@@ -796,3 +886,5 @@ impl std::fmt::Display for InvoicesResourceInvoiceTaxIdType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(InvoicesResourceInvoiceTaxIdType);
@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
 use crate::params::{Metadata, Timestamp};
 use crate::resources::Invoice;
@@ -8,14 +8,17 @@ impl Invoice {
     /// Retrieves the details of an upcoming invoice_id
     ///
     /// For more details see https://stripe.com/docs/api#upcoming_invoice
-    pub fn upcoming(client: &Client, params: RetrieveUpcomingInvoice) -> Response<Invoice> {
+    pub fn upcoming<C: StripeClient>(
+        client: &C,
+        params: RetrieveUpcomingInvoice,
+    ) -> C::Response<Invoice> {
         client.get_query("/invoices/upcoming", &params)
     }
 
     /// Pays an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
-    pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
+    pub fn pay<C: StripeClient>(client: &C, invoice_id: &InvoiceId) -> C::Response<Invoice> {
         client.post(&format!("/invoices/{}/pay", invoice_id))
     }
 }
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, InvoiceId, InvoiceItemId, SubscriptionId};
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{Currency, Customer, Invoice, Period, Plan, Subscription, TaxRate};
@@ -104,37 +104,50 @@ impl InvoiceItem {
     /// Returns a list of your invoice items.
     ///
     /// Invoice items are returned sorted by creation date, with the most recently created invoice items appearing first.
-    pub fn list(client: &Client, params: ListInvoiceItems<'_>) -> Response<List<InvoiceItem>> {
-        client.get_query("/invoiceitems", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListInvoiceItems<'_>,
+    ) -> C::Response<List<InvoiceItem>> {
+        List::get_query(client, "/invoiceitems", &params)
     }
 
     /// Creates an item to be added to a draft invoice.
     ///
     /// If no invoice is specified, the item will be on the next invoice created for the customer specified.
-    pub fn create(client: &Client, params: CreateInvoiceItem<'_>) -> Response<InvoiceItem> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateInvoiceItem<'_>,
+    ) -> C::Response<InvoiceItem> {
         client.post_form("/invoiceitems", &params)
     }
 
     /// Retrieves the invoice item with the given ID.
-    pub fn retrieve(client: &Client, id: &InvoiceItemId, expand: &[&str]) -> Response<InvoiceItem> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &InvoiceItemId,
+        expand: &[&str],
+    ) -> C::Response<InvoiceItem> {
         client.get_query(&format!("/invoiceitems/{}", id), &Expand { expand })
     }
 
     /// Updates the amount or description of an invoice item on an upcoming invoice.
     ///
     /// Updating an invoice item is only possible before the invoice it’s attached to is closed.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &InvoiceItemId,
         params: UpdateInvoiceItem<'_>,
-    ) -> Response<InvoiceItem> {
+    ) -> C::Response<InvoiceItem> {
         client.post_form(&format!("/invoiceitems/{}", id), &params)
     }
 
     /// Deletes an invoice item, removing it from an invoice.
     ///
     /// Deleting invoice items is only possible when they’re not attached to invoices, or if it’s attached to a draft invoice.
-    pub fn delete(client: &Client, id: &InvoiceItemId) -> Response<Deleted<InvoiceItemId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &InvoiceItemId,
+    ) -> C::Response<Deleted<InvoiceItemId>> {
         client.delete(&format!("/invoiceitems/{}", id))
     }
 }
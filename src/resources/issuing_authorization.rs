@@ -150,20 +150,33 @@ pub struct IssuingAuthorizationVerificationData {
 }
 
 /// An enum representing the possible values of an `IssuingAuthorization`'s `wallet_provider` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingAuthorizationWalletProvider {
     ApplePay,
     GooglePay,
     SamsungPay,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingAuthorizationWalletProvider {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingAuthorizationWalletProvider::ApplePay => "apple_pay",
             IssuingAuthorizationWalletProvider::GooglePay => "google_pay",
             IssuingAuthorizationWalletProvider::SamsungPay => "samsung_pay",
+            IssuingAuthorizationWalletProvider::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingAuthorizationWalletProvider {
+    fn from(value: &str) -> Self {
+        match value {
+            "apple_pay" => IssuingAuthorizationWalletProvider::ApplePay,
+            "google_pay" => IssuingAuthorizationWalletProvider::GooglePay,
+            "samsung_pay" => IssuingAuthorizationWalletProvider::SamsungPay,
+            _ => IssuingAuthorizationWalletProvider::Unknown(value.to_string()),
         }
     }
 }
@@ -179,3 +192,5 @@ impl std::fmt::Display for IssuingAuthorizationWalletProvider {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(IssuingAuthorizationWalletProvider);
@@ -156,22 +156,36 @@ pub struct IssuingCardShipping {
 }
 
 /// An enum representing the possible values of an `IssuingCard`'s `replacement_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardReplacementReason {
     Damage,
     Expiration,
     Loss,
     Theft,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardReplacementReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardReplacementReason::Damage => "damage",
             IssuingCardReplacementReason::Expiration => "expiration",
             IssuingCardReplacementReason::Loss => "loss",
             IssuingCardReplacementReason::Theft => "theft",
+            IssuingCardReplacementReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardReplacementReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "damage" => IssuingCardReplacementReason::Damage,
+            "expiration" => IssuingCardReplacementReason::Expiration,
+            "loss" => IssuingCardReplacementReason::Loss,
+            "theft" => IssuingCardReplacementReason::Theft,
+            _ => IssuingCardReplacementReason::Unknown(value.to_string()),
         }
     }
 }
@@ -188,9 +202,10 @@ impl std::fmt::Display for IssuingCardReplacementReason {
     }
 }
 
+serde_string_enum!(IssuingCardReplacementReason);
+
 /// An enum representing the possible values of an `IssuingCardShipping`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardShippingStatus {
     Canceled,
     Delivered,
@@ -198,10 +213,12 @@ pub enum IssuingCardShippingStatus {
     Pending,
     Returned,
     Shipped,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardShippingStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardShippingStatus::Canceled => "canceled",
             IssuingCardShippingStatus::Delivered => "delivered",
@@ -209,6 +226,21 @@ impl IssuingCardShippingStatus {
             IssuingCardShippingStatus::Pending => "pending",
             IssuingCardShippingStatus::Returned => "returned",
             IssuingCardShippingStatus::Shipped => "shipped",
+            IssuingCardShippingStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardShippingStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "canceled" => IssuingCardShippingStatus::Canceled,
+            "delivered" => IssuingCardShippingStatus::Delivered,
+            "failure" => IssuingCardShippingStatus::Failure,
+            "pending" => IssuingCardShippingStatus::Pending,
+            "returned" => IssuingCardShippingStatus::Returned,
+            "shipped" => IssuingCardShippingStatus::Shipped,
+            _ => IssuingCardShippingStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -225,19 +257,33 @@ impl std::fmt::Display for IssuingCardShippingStatus {
     }
 }
 
+serde_string_enum!(IssuingCardShippingStatus);
+
 /// An enum representing the possible values of an `IssuingCardShipping`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardShippingType {
     Bulk,
     Individual,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardShippingType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardShippingType::Bulk => "bulk",
             IssuingCardShippingType::Individual => "individual",
+            IssuingCardShippingType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardShippingType {
+    fn from(value: &str) -> Self {
+        match value {
+            "bulk" => IssuingCardShippingType::Bulk,
+            "individual" => IssuingCardShippingType::Individual,
+            _ => IssuingCardShippingType::Unknown(value.to_string()),
         }
     }
 }
@@ -254,9 +300,10 @@ impl std::fmt::Display for IssuingCardShippingType {
     }
 }
 
+serde_string_enum!(IssuingCardShippingType);
+
 /// An enum representing the possible values of an `IssuingCard`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardStatus {
     Active,
     Canceled,
@@ -264,10 +311,12 @@ pub enum IssuingCardStatus {
     Lost,
     Pending,
     Stolen,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardStatus::Active => "active",
             IssuingCardStatus::Canceled => "canceled",
@@ -275,6 +324,21 @@ impl IssuingCardStatus {
             IssuingCardStatus::Lost => "lost",
             IssuingCardStatus::Pending => "pending",
             IssuingCardStatus::Stolen => "stolen",
+            IssuingCardStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "active" => IssuingCardStatus::Active,
+            "canceled" => IssuingCardStatus::Canceled,
+            "inactive" => IssuingCardStatus::Inactive,
+            "lost" => IssuingCardStatus::Lost,
+            "pending" => IssuingCardStatus::Pending,
+            "stolen" => IssuingCardStatus::Stolen,
+            _ => IssuingCardStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -291,19 +355,33 @@ impl std::fmt::Display for IssuingCardStatus {
     }
 }
 
+serde_string_enum!(IssuingCardStatus);
+
 /// An enum representing the possible values of an `IssuingCard`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardType {
     Physical,
     Virtual,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardType::Physical => "physical",
             IssuingCardType::Virtual => "virtual",
+            IssuingCardType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardType {
+    fn from(value: &str) -> Self {
+        match value {
+            "physical" => IssuingCardType::Physical,
+            "virtual" => IssuingCardType::Virtual,
+            _ => IssuingCardType::Unknown(value.to_string()),
         }
     }
 }
@@ -319,3 +397,5 @@ impl std::fmt::Display for IssuingCardType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(IssuingCardType);
@@ -89,22 +89,36 @@ pub struct IssuingCardholderAuthorizationControls {
 }
 
 /// An enum representing the possible values of an `IssuingCardholder`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardholderStatus {
     Active,
     Blocked,
     Inactive,
     Pending,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardholderStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardholderStatus::Active => "active",
             IssuingCardholderStatus::Blocked => "blocked",
             IssuingCardholderStatus::Inactive => "inactive",
             IssuingCardholderStatus::Pending => "pending",
+            IssuingCardholderStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardholderStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "active" => IssuingCardholderStatus::Active,
+            "blocked" => IssuingCardholderStatus::Blocked,
+            "inactive" => IssuingCardholderStatus::Inactive,
+            "pending" => IssuingCardholderStatus::Pending,
+            _ => IssuingCardholderStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -121,19 +135,33 @@ impl std::fmt::Display for IssuingCardholderStatus {
     }
 }
 
+serde_string_enum!(IssuingCardholderStatus);
+
 /// An enum representing the possible values of an `IssuingCardholder`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingCardholderType {
     BusinessEntity,
     Individual,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingCardholderType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingCardholderType::BusinessEntity => "business_entity",
             IssuingCardholderType::Individual => "individual",
+            IssuingCardholderType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingCardholderType {
+    fn from(value: &str) -> Self {
+        match value {
+            "business_entity" => IssuingCardholderType::BusinessEntity,
+            "individual" => IssuingCardholderType::Individual,
+            _ => IssuingCardholderType::Unknown(value.to_string()),
         }
     }
 }
@@ -149,3 +177,5 @@ impl std::fmt::Display for IssuingCardholderType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(IssuingCardholderType);
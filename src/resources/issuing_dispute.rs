@@ -99,18 +99,30 @@ pub struct IssuingDisputeOtherEvidence {
 }
 
 /// An enum representing the possible values of an `IssuingDispute`'s `reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingDisputeReason {
     Fraudulent,
     Other,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingDisputeReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingDisputeReason::Fraudulent => "fraudulent",
             IssuingDisputeReason::Other => "other",
+            IssuingDisputeReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingDisputeReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "fraudulent" => IssuingDisputeReason::Fraudulent,
+            "other" => IssuingDisputeReason::Other,
+            _ => IssuingDisputeReason::Unknown(value.to_string()),
         }
     }
 }
@@ -127,23 +139,39 @@ impl std::fmt::Display for IssuingDisputeReason {
     }
 }
 
+serde_string_enum!(IssuingDisputeReason);
+
 /// An enum representing the possible values of an `IssuingDispute`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingDisputeStatus {
     Lost,
     UnderReview,
     Unsubmitted,
     Won,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingDisputeStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingDisputeStatus::Lost => "lost",
             IssuingDisputeStatus::UnderReview => "under_review",
             IssuingDisputeStatus::Unsubmitted => "unsubmitted",
             IssuingDisputeStatus::Won => "won",
+            IssuingDisputeStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingDisputeStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "lost" => IssuingDisputeStatus::Lost,
+            "under_review" => IssuingDisputeStatus::UnderReview,
+            "unsubmitted" => IssuingDisputeStatus::Unsubmitted,
+            "won" => IssuingDisputeStatus::Won,
+            _ => IssuingDisputeStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -159,3 +187,5 @@ impl std::fmt::Display for IssuingDisputeStatus {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(IssuingDisputeStatus);
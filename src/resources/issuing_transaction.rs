@@ -71,8 +71,7 @@ impl Object for IssuingTransaction {
 }
 
 /// An enum representing the possible values of an `IssuingTransaction`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssuingTransactionType {
     Capture,
     CashWithdrawal,
@@ -80,10 +79,12 @@ pub enum IssuingTransactionType {
     DisputeLoss,
     Refund,
     RefundReversal,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl IssuingTransactionType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             IssuingTransactionType::Capture => "capture",
             IssuingTransactionType::CashWithdrawal => "cash_withdrawal",
@@ -91,6 +92,21 @@ impl IssuingTransactionType {
             IssuingTransactionType::DisputeLoss => "dispute_loss",
             IssuingTransactionType::Refund => "refund",
             IssuingTransactionType::RefundReversal => "refund_reversal",
+            IssuingTransactionType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IssuingTransactionType {
+    fn from(value: &str) -> Self {
+        match value {
+            "capture" => IssuingTransactionType::Capture,
+            "cash_withdrawal" => IssuingTransactionType::CashWithdrawal,
+            "dispute" => IssuingTransactionType::Dispute,
+            "dispute_loss" => IssuingTransactionType::DisputeLoss,
+            "refund" => IssuingTransactionType::Refund,
+            "refund_reversal" => IssuingTransactionType::RefundReversal,
+            _ => IssuingTransactionType::Unknown(value.to_string()),
         }
     }
 }
@@ -106,3 +122,5 @@ impl std::fmt::Display for IssuingTransactionType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(IssuingTransactionType);
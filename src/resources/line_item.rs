@@ -103,19 +103,30 @@ pub struct TaxAmount {
 }
 
 /// An enum representing the possible values of an `InvoiceLineItem`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvoiceLineItemType {
-    #[serde(rename = "invoiceitem")]
     InvoiceItem,
     Subscription,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl InvoiceLineItemType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             InvoiceLineItemType::InvoiceItem => "invoiceitem",
             InvoiceLineItemType::Subscription => "subscription",
+            InvoiceLineItemType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for InvoiceLineItemType {
+    fn from(value: &str) -> Self {
+        match value {
+            "invoiceitem" => InvoiceLineItemType::InvoiceItem,
+            "subscription" => InvoiceLineItemType::Subscription,
+            _ => InvoiceLineItemType::Unknown(value.to_string()),
         }
     }
 }
@@ -131,3 +142,5 @@ impl std::fmt::Display for InvoiceLineItemType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(InvoiceLineItemType);
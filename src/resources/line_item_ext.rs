@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, InvoiceId};
 use crate::resources::{Currency, InvoiceLineItem};
 use serde_derive::{Deserialize, Serialize};
@@ -7,7 +7,10 @@ impl InvoiceLineItem {
     /// Creates an invoice line item.
     ///
     /// For more details see https://stripe.com/docs/api#invoice_line_item_object
-    pub fn create(client: &Client, params: CreateInvoiceLineItem<'_>) -> Response<InvoiceLineItem> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateInvoiceLineItem<'_>,
+    ) -> C::Response<InvoiceLineItem> {
         client.post_form("/invoiceitems", &params)
     }
 }
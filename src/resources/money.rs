@@ -0,0 +1,283 @@
+use crate::resources::Currency;
+
+/// An amount of money in a currency, given in the currency's smallest unit (e.g. cents
+/// for USD), which is how Stripe represents amounts.
+///
+/// ```rust
+/// use stripe::{Currency, Money};
+///
+/// let price = Money::from_decimal("19.99", Currency::USD).unwrap();
+/// assert_eq!(price.amount, 1999);
+///
+/// let shipping = Money::new(300, Currency::USD);
+/// let total = price.clone().checked_mul(3).and_then(|total| total.checked_add(shipping)).unwrap();
+/// assert_eq!(total.to_string(), "62.97 USD");
+///
+/// // JPY has no minor unit, and amounts in different currencies can't be added
+/// let yen: Money = "500 JPY".parse().unwrap();
+/// assert_eq!(yen.amount, 500);
+/// assert!(price.clone().checked_add(yen).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Money {
+    /// The amount in the currency's smallest unit.
+    pub amount: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of money from an `amount` in the currency's smallest unit.
+    pub fn new(amount: i64, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Money {
+        Money::new(0, currency)
+    }
+
+    /// Parses an amount in the currency's main unit with at most `Currency::exponent`
+    /// decimals, e.g. `"19.99"` (or `"-19.99"`) for USD.
+    ///
+    /// Fails for a `Currency::Unknown`, whose exponent isn't known.
+    pub fn from_decimal(decimal: &str, currency: Currency) -> Result<Money, ParseMoneyError> {
+        if let Currency::Unknown(_) = currency {
+            return Err(ParseMoneyError(()));
+        }
+        let exponent = currency.exponent();
+        let (negative, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, decimal),
+        };
+        let is_number =
+            |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+        let (units, fraction) = match digits.find('.') {
+            Some(point) if is_number(&digits[point + 1..]) => {
+                (&digits[..point], &digits[point + 1..])
+            }
+            Some(_) => return Err(ParseMoneyError(())),
+            None => (digits, ""),
+        };
+        if !is_number(units) || fraction.len() > exponent as usize {
+            return Err(ParseMoneyError(()));
+        }
+
+        let fraction = format!("{:0<width$}", fraction, width = exponent as usize);
+        let amount = units
+            .parse::<i64>()
+            .ok()
+            .and_then(|units| units.checked_mul(10i64.pow(exponent)))
+            .and_then(|amount| amount.checked_add(fraction.parse().unwrap_or(0)))
+            .ok_or(ParseMoneyError(()))?;
+        Ok(Money::new(if negative { -amount } else { amount }, currency))
+    }
+
+    /// Formats the amount in the currency's main unit, with `Currency::exponent` decimals
+    /// (e.g. `"19.99"` for 1999 USD, or `"500"` for 500 JPY).
+    ///
+    /// N.B. An amount in a `Currency::Unknown` is formatted with 2 decimals.
+    pub fn to_decimal(&self) -> String {
+        let exponent = self.currency.exponent();
+        if exponent == 0 {
+            return self.amount.to_string();
+        }
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let amount = self.amount.unsigned_abs();
+        let scale = 10u64.pow(exponent);
+        format!("{}{}.{:0width$}", sign, amount / scale, amount % scale, width = exponent as usize)
+    }
+
+    /// Adds `other`, which must be in the same currency.
+    pub fn checked_add(self, other: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self.amount.checked_add(other.amount).ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Subtracts `other`, which must be in the same currency.
+    pub fn checked_sub(self, other: Money) -> Result<Money, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self.amount.checked_sub(other.amount).ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Multiplies the amount by `factor`, e.g. a quantity.
+    pub fn checked_mul(self, factor: i64) -> Result<Money, MoneyError> {
+        let amount = self.amount.checked_mul(factor).ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Returns `true` if the amount is at least the currency's `minimum_charge_amount`
+    /// (or is positive, for a currency without a minimum).
+    pub fn is_chargeable(&self) -> bool {
+        self.amount >= self.currency.minimum_charge_amount().unwrap_or(1)
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(
+                self.currency.clone(),
+                other.currency.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.currency.as_str().to_uppercase())
+    }
+}
+
+impl std::str::FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Parses an amount in the currency's main unit followed by its currency code,
+    /// as formatted by `Display` (e.g. `"19.99 USD"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(decimal), Some(currency), None) => {
+                let currency = currency.parse().map_err(|_| ParseMoneyError(()))?;
+                Money::from_decimal(decimal, currency)
+            }
+            _ => Err(ParseMoneyError(())),
+        }
+    }
+}
+
+/// An error in arithmetic with amounts of `Money`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoneyError {
+    /// The amounts are in different currencies.
+    CurrencyMismatch(Currency, Currency),
+    /// The result doesn't fit in an `i64`.
+    Overflow,
+}
+
+impl std::fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoneyError::CurrencyMismatch(a, b) => {
+                write!(f, "mismatched currencies: {} and {}", a, b)
+            }
+            MoneyError::Overflow => f.write_str("amount overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+#[derive(Debug)]
+pub struct ParseMoneyError(/* private */ ());
+
+impl std::fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid amount of money")
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Money, MoneyError};
+    use crate::resources::Currency;
+
+    #[test]
+    fn test_decimal_uses_currency_exponent() {
+        let cases = [
+            ("19.99", Currency::USD, 1999, "19.99"),
+            ("19.9", Currency::USD, 1990, "19.90"),
+            ("19", Currency::USD, 1900, "19.00"),
+            ("0.05", Currency::EUR, 5, "0.05"),
+            ("-0.05", Currency::EUR, -5, "-0.05"),
+            ("500", Currency::JPY, 500, "500"),
+            ("1000", Currency::KRW, 1000, "1000"),
+            ("1.234", Currency::KWD, 1234, "1.234"),
+            ("1.5", Currency::BHD, 1500, "1.500"),
+        ];
+        for (decimal, currency, amount, formatted) in cases {
+            let money = Money::from_decimal(decimal, currency.clone()).unwrap();
+            assert_eq!(money, Money::new(amount, currency.clone()), "{} {}", decimal, currency);
+            assert_eq!(money.to_decimal(), formatted);
+        }
+
+        for (decimal, currency) in [
+            ("19.999", Currency::USD),
+            ("1.5", Currency::JPY),
+            ("19.", Currency::USD),
+            (".5", Currency::USD),
+            ("", Currency::USD),
+            ("-", Currency::USD),
+            ("1,000", Currency::USD),
+            ("+1", Currency::USD),
+            ("99999999999999999999", Currency::USD),
+            ("19.99", Currency::Unknown("xts".into())),
+            ("500", Currency::Unknown("xts".into())),
+        ] {
+            assert!(
+                Money::from_decimal(decimal, currency.clone()).is_err(),
+                "{} {}",
+                decimal,
+                currency
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        assert_eq!(Money::new(1999, Currency::USD).to_string(), "19.99 USD");
+        assert_eq!(Money::new(500, Currency::JPY).to_string(), "500 JPY");
+        assert_eq!(
+            Money::new(std::i64::MIN, Currency::USD).to_string(),
+            "-92233720368547758.08 USD"
+        );
+        assert_eq!("19.99 usd".parse::<Money>().unwrap(), Money::new(1999, Currency::USD));
+        assert_eq!(" 500 JPY ".parse::<Money>().unwrap(), Money::new(500, Currency::JPY));
+        assert!("19.99".parse::<Money>().is_err());
+        assert!("19.99 XYZ".parse::<Money>().is_err());
+        assert!("19.99 USD extra".parse::<Money>().is_err());
+
+        // An unknown currency's exponent is assumed to be 2
+        let unknown = Money::new(1999, Currency::Unknown("xts".into()));
+        assert_eq!(unknown.to_string(), "19.99 XTS");
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let usd = Money::new(1000, Currency::USD);
+        assert_eq!(
+            usd.clone().checked_add(Money::new(1, Currency::USD)),
+            Ok(Money::new(1001, Currency::USD))
+        );
+        assert_eq!(
+            usd.clone().checked_sub(Money::new(1001, Currency::USD)),
+            Ok(Money::new(-1, Currency::USD))
+        );
+        assert_eq!(usd.clone().checked_mul(3), Ok(Money::new(3000, Currency::USD)));
+        assert_eq!(
+            usd.clone().checked_add(Money::new(1000, Currency::JPY)),
+            Err(MoneyError::CurrencyMismatch(Currency::USD, Currency::JPY))
+        );
+        assert_eq!(
+            usd.clone().checked_sub(Money::new(1000, Currency::EUR)),
+            Err(MoneyError::CurrencyMismatch(Currency::USD, Currency::EUR))
+        );
+        assert_eq!(
+            Money::new(std::i64::MAX, Currency::USD).checked_add(Money::new(1, Currency::USD)),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(usd.checked_mul(std::i64::MAX), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_minimum_charge_amount() {
+        assert!(Money::new(50, Currency::USD).is_chargeable());
+        assert!(!Money::new(49, Currency::USD).is_chargeable());
+        assert!(Money::new(30, Currency::GBP).is_chargeable());
+        assert!(!Money::new(49, Currency::JPY).is_chargeable());
+        assert!(Money::new(1, Currency::KRW).is_chargeable());
+        assert!(!Money::new(0, Currency::KRW).is_chargeable());
+    }
+}
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CouponId, CustomerId, OrderId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -113,26 +113,34 @@ impl Order {
     /// Returns a list of your orders.
     ///
     /// The orders are returned sorted by creation date, with the most recently created orders appearing first.
-    pub fn list(client: &Client, params: ListOrders<'_>) -> Response<List<Order>> {
-        client.get_query("/orders", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListOrders<'_>) -> C::Response<List<Order>> {
+        List::get_query(client, "/orders", &params)
     }
 
     /// Creates a new order object.
-    pub fn create(client: &Client, params: CreateOrder<'_>) -> Response<Order> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateOrder<'_>) -> C::Response<Order> {
         client.post_form("/orders", &params)
     }
 
     /// Retrieves the details of an existing order.
     ///
     /// Supply the unique order ID from either an order creation request or the order list, and Stripe will return the corresponding order information.
-    pub fn retrieve(client: &Client, id: &OrderId, expand: &[&str]) -> Response<Order> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &OrderId,
+        expand: &[&str],
+    ) -> C::Response<Order> {
         client.get_query(&format!("/orders/{}", id), &Expand { expand })
     }
 
     /// Updates the specific order by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
-    pub fn update(client: &Client, id: &OrderId, params: UpdateOrder<'_>) -> Response<Order> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &OrderId,
+        params: UpdateOrder<'_>,
+    ) -> C::Response<Order> {
         client.post_form(&format!("/orders/{}", id), &params)
     }
 }
@@ -433,22 +441,36 @@ pub struct OrderItemParams {
 }
 
 /// An enum representing the possible values of an `OrderItemParams`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum OrderItemParamsType {
     Discount,
     Shipping,
     Sku,
     Tax,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl OrderItemParamsType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             OrderItemParamsType::Discount => "discount",
             OrderItemParamsType::Shipping => "shipping",
             OrderItemParamsType::Sku => "sku",
             OrderItemParamsType::Tax => "tax",
+            OrderItemParamsType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for OrderItemParamsType {
+    fn from(value: &str) -> Self {
+        match value {
+            "discount" => OrderItemParamsType::Discount,
+            "shipping" => OrderItemParamsType::Shipping,
+            "sku" => OrderItemParamsType::Sku,
+            "tax" => OrderItemParamsType::Tax,
+            _ => OrderItemParamsType::Unknown(value.to_string()),
         }
     }
 }
@@ -465,25 +487,42 @@ impl std::fmt::Display for OrderItemParamsType {
     }
 }
 
+serde_string_enum!(OrderItemParamsType);
+
 /// An enum representing the possible values of an `Order`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum OrderStatus {
     Canceled,
     Created,
     Fulfilled,
     Paid,
     Returned,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl OrderStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             OrderStatus::Canceled => "canceled",
             OrderStatus::Created => "created",
             OrderStatus::Fulfilled => "fulfilled",
             OrderStatus::Paid => "paid",
             OrderStatus::Returned => "returned",
+            OrderStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for OrderStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "canceled" => OrderStatus::Canceled,
+            "created" => OrderStatus::Created,
+            "fulfilled" => OrderStatus::Fulfilled,
+            "paid" => OrderStatus::Paid,
+            "returned" => OrderStatus::Returned,
+            _ => OrderStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -500,23 +539,39 @@ impl std::fmt::Display for OrderStatus {
     }
 }
 
+serde_string_enum!(OrderStatus);
+
 /// An enum representing the possible values of an `ListOrders`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum OrderStatusFilter {
     Created,
     Fulfilled,
     Paid,
     Refunded,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl OrderStatusFilter {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             OrderStatusFilter::Created => "created",
             OrderStatusFilter::Fulfilled => "fulfilled",
             OrderStatusFilter::Paid => "paid",
             OrderStatusFilter::Refunded => "refunded",
+            OrderStatusFilter::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for OrderStatusFilter {
+    fn from(value: &str) -> Self {
+        match value {
+            "created" => OrderStatusFilter::Created,
+            "fulfilled" => OrderStatusFilter::Fulfilled,
+            "paid" => OrderStatusFilter::Paid,
+            "refunded" => OrderStatusFilter::Refunded,
+            _ => OrderStatusFilter::Unknown(value.to_string()),
         }
     }
 }
@@ -532,3 +587,5 @@ impl std::fmt::Display for OrderStatusFilter {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(OrderStatusFilter);
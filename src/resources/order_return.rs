@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{OrderId, OrderReturnId};
 use crate::params::{Expand, Expandable, List, Object, RangeQuery, Timestamp};
 use crate::resources::{Currency, Order, OrderItem, Refund};
@@ -46,14 +46,21 @@ impl OrderReturn {
     /// Returns a list of your order returns.
     ///
     /// The returns are returned sorted by creation date, with the most recently created return appearing first.
-    pub fn list(client: &Client, params: ListOrderReturns<'_>) -> Response<List<OrderReturn>> {
-        client.get_query("/order_returns", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListOrderReturns<'_>,
+    ) -> C::Response<List<OrderReturn>> {
+        List::get_query(client, "/order_returns", &params)
     }
 
     /// Retrieves the details of an existing order return.
     ///
     /// Supply the unique order ID from either an order return creation request or the order return list, and Stripe will return the corresponding order information.
-    pub fn retrieve(client: &Client, id: &OrderReturnId, expand: &[&str]) -> Response<OrderReturn> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &OrderReturnId,
+        expand: &[&str],
+    ) -> C::Response<OrderReturn> {
         client.get_query(&format!("/order_returns/{}", id), &Expand { expand })
     }
 }
@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -172,69 +172,75 @@ impl PaymentIntent {
     /// Creates a new payment_intent.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/create](https://stripe.com/docs/api/payment_intents/create).
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: PaymentIntentCreateParams,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form("/payment_intents", params)
     }
 
     /// Retrieves the details of a payment_intent.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/retrieve](https://stripe.com/docs/api/payment_intents/retrieve).
-    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Response<PaymentIntent> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        payment_intent_id: &str,
+    ) -> C::Response<PaymentIntent> {
         client.get(&format!("/payment_intents/{}", payment_intent_id))
     }
 
     /// Updates a payment_intent's properties.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/update](https://stripe.com/docs/api/payment_intents/update).
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: PaymentIntentUpdateParams,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}", payment_intent_id), params)
     }
 
     /// Confirm that customer intends to pay with current or provided source. Upon confirmation, the PaymentIntent will attempt to initiate a payment.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm).
-    pub fn confirm(
-        client: &Client,
+    pub fn confirm<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: PaymentIntentConfirmParams,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
     }
 
     /// Capture the funds of an existing uncaptured PaymentIntent where required_action="requires_capture".
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/capture](https://stripe.com/docs/api/payment_intents/capture).
-    pub fn capture(
-        client: &Client,
+    pub fn capture<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: PaymentIntentCaptureParams,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/capture", payment_intent_id), params)
     }
 
     /// A PaymentIntent object can be canceled when it is in one of these statuses: requires_source, requires_capture, requires_confirmation, requires_source_action.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/cancel](https://stripe.com/docs/api/payment_intents/cancel).
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: PaymentIntentCancelParams,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
     }
 
     /// List all payment_intents.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/list](https://stripe.com/docs/api/payment_intents/list).
-    pub fn list(client: &Client, params: PaymentIntentListParams) -> Response<List<PaymentIntent>> {
-        client.get_query("/payment_intents", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: PaymentIntentListParams,
+    ) -> C::Response<List<PaymentIntent>> {
+        List::get_query(client, "/payment_intents", &params)
     }
 }
 
@@ -2,10 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, PaymentMethodId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
-use crate::resources::{Address, BillingDetails, Customer, PaymentMethodDetails};
+use crate::resources::{
+    Address, BillingDetails, Customer, PaymentMethodDetails, PaymentMethodType, WalletDetailsType,
+};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "PaymentMethod".
@@ -53,37 +55,42 @@ pub struct PaymentMethod {
 
 impl PaymentMethod {
     /// Returns a list of PaymentMethods for a given Customer.
-    pub fn list(client: &Client, params: ListPaymentMethods<'_>) -> Response<List<PaymentMethod>> {
-        client.get_query("/payment_methods", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListPaymentMethods<'_>,
+    ) -> C::Response<List<PaymentMethod>> {
+        List::get_query(client, "/payment_methods", &params)
     }
 
     /// Creates a PaymentMethod object.
     ///
     /// Read the [Stripe.js reference](https://stripe.com/docs/stripe-js/reference#stripe-create-payment-method) to learn how to create PaymentMethods via Stripe.js.
-    pub fn create(client: &Client, params: CreatePaymentMethod<'_>) -> Response<PaymentMethod> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentMethod<'_>,
+    ) -> C::Response<PaymentMethod> {
         client.post_form("/payment_methods", &params)
     }
 
     /// Retrieves a PaymentMethod object.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &PaymentMethodId,
         expand: &[&str],
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.get_query(&format!("/payment_methods/{}", id), &Expand { expand })
     }
 
     /// Updates a PaymentMethod object.
     ///
     /// A PaymentMethod must be attached a customer to be updated.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &PaymentMethodId,
         params: UpdatePaymentMethod<'_>,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.post_form(&format!("/payment_methods/{}", id), &params)
     }
-
 }
 
 impl Object for PaymentMethod {
@@ -410,69 +417,3 @@ impl<'a> UpdatePaymentMethod<'a> {
         }
     }
 }
-
-/// An enum representing the possible values of an `PaymentMethod`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum PaymentMethodType {
-    Card,
-    CardPresent,
-}
-
-impl PaymentMethodType {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            PaymentMethodType::Card => "card",
-            PaymentMethodType::CardPresent => "card_present",
-        }
-    }
-}
-
-impl AsRef<str> for PaymentMethodType {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for PaymentMethodType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-
-/// An enum representing the possible values of an `WalletDetails`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum WalletDetailsType {
-    AmexExpressCheckout,
-    ApplePay,
-    GooglePay,
-    Masterpass,
-    SamsungPay,
-    VisaCheckout,
-}
-
-impl WalletDetailsType {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            WalletDetailsType::AmexExpressCheckout => "amex_express_checkout",
-            WalletDetailsType::ApplePay => "apple_pay",
-            WalletDetailsType::GooglePay => "google_pay",
-            WalletDetailsType::Masterpass => "masterpass",
-            WalletDetailsType::SamsungPay => "samsung_pay",
-            WalletDetailsType::VisaCheckout => "visa_checkout",
-        }
-    }
-}
-
-impl AsRef<str> for WalletDetailsType {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for WalletDetailsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
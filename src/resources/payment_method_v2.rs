@@ -1,5 +1,5 @@
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use serde_derive::{Deserialize, Serialize};
 // use crate::ids::{SourceId, TokenId};
 // use crate::params::Identifiable;
@@ -13,7 +13,6 @@ use crate::resources::{
     CardPresent,
     CardParamsShort,
     ThreeDSecureUsage,
-    Wallet,
 };
 use crate::resources::{Address, BillingDetails, Customer};
 use crate::ids::{PaymentMethodId};
@@ -75,17 +74,29 @@ impl Object for PaymentMethod {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PaymentMethodType {
     Card,
-    CardPresent
+    CardPresent,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 impl PaymentMethodType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PaymentMethodType::Card => "card",
             PaymentMethodType::CardPresent => "card_present",
+            PaymentMethodType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PaymentMethodType {
+    fn from(value: &str) -> Self {
+        match value {
+            "card" => PaymentMethodType::Card,
+            "card_present" => PaymentMethodType::CardPresent,
+            _ => PaymentMethodType::Unknown(value.to_string()),
         }
     }
 }
@@ -102,6 +113,7 @@ impl std::fmt::Display for PaymentMethodType {
     }
 }
 
+serde_string_enum!(PaymentMethodType);
 
 impl PaymentMethod {
 
@@ -118,10 +130,10 @@ impl PaymentMethod {
     /// -d billing_details[email]=andrew.yang@yanggang.com
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn create(
-        client: &Client,
-        params: PaymentMethodCreateParams
-    ) -> Response<PaymentMethod> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: PaymentMethodCreateParams,
+    ) -> C::Response<PaymentMethod> {
         // println!("create(): stripe-rs params: {:?}", params);
         client.post_form("/payment_methods", params)
     }
@@ -131,10 +143,10 @@ impl PaymentMethod {
     /// curl https://api.stripe.com/v1/payment_methods/pm_1EeVl72eZvKYlo2CBjFfYbm8 \
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         params: PaymentMethodRetrieveParams,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         // println!("retrieve(): stripe-rs id: {:?}", params.payment_method_id);
         client.get(&format!("/payment_methods/{}", params.payment_method_id))
     }
@@ -146,11 +158,11 @@ impl PaymentMethod {
     ///   -d metadata[order_id]=6735
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         payment_method_id: String,
-        params: PaymentMethodUpdateParams
-    ) -> Response<PaymentMethod> {
+        params: PaymentMethodUpdateParams,
+    ) -> C::Response<PaymentMethod> {
         // println!("update(): stripe-rs params: {:?}", params);
         client.post_form(
             &format!("/payment_methods/{}", payment_method_id),
@@ -165,11 +177,11 @@ impl PaymentMethod {
     ///   -G
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn list_payment_methods(
-        client: &Client,
+    pub fn list_payment_methods<C: StripeClient>(
+        client: &C,
         params: PaymentMethodsListParams,
-    ) -> Response<List<PaymentMethod>> {
-        client.get_query("/payment_methods", &params)
+    ) -> C::Response<List<PaymentMethod>> {
+        List::get_query(client, "/payment_methods", &params)
     }
 
     /// Attaches a new PaymentMethod to a Customer
@@ -180,11 +192,11 @@ impl PaymentMethod {
     ///   -d customer=cus_F8nLAuoRpovfMk
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn attach_payment_method(
-        client: &Client,
+    pub fn attach_payment_method<C: StripeClient>(
+        client: &C,
         payment_method_id: String,
         params: PaymentMethodAttachParams,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.post_form(
             &format!("/payment_methods/{}/attach", payment_method_id),
             &params
@@ -198,10 +210,10 @@ impl PaymentMethod {
     ///   -X POST
     ///
     /// For more details see [https://stripe.com/docs/payments/payment-methods/saving](https://stripe.com/docs/payments/payment-methods/saving).
-    pub fn detach_payment_method(
-        client: &Client,
+    pub fn detach_payment_method<C: StripeClient>(
+        client: &C,
         payment_method_id: String,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.post(&format!("/payment_methods/{}/detach", payment_method_id))
     }
 }
@@ -391,8 +403,7 @@ pub struct WalletVisaCheckout {
 }
 
 /// An enum representing the possible values of an `WalletDetails`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WalletDetailsType {
     AmexExpressCheckout,
     ApplePay,
@@ -400,10 +411,12 @@ pub enum WalletDetailsType {
     Masterpass,
     SamsungPay,
     VisaCheckout,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl WalletDetailsType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             WalletDetailsType::AmexExpressCheckout => "amex_express_checkout",
             WalletDetailsType::ApplePay => "apple_pay",
@@ -411,6 +424,21 @@ impl WalletDetailsType {
             WalletDetailsType::Masterpass => "masterpass",
             WalletDetailsType::SamsungPay => "samsung_pay",
             WalletDetailsType::VisaCheckout => "visa_checkout",
+            WalletDetailsType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for WalletDetailsType {
+    fn from(value: &str) -> Self {
+        match value {
+            "amex_express_checkout" => WalletDetailsType::AmexExpressCheckout,
+            "apple_pay" => WalletDetailsType::ApplePay,
+            "google_pay" => WalletDetailsType::GooglePay,
+            "masterpass" => WalletDetailsType::Masterpass,
+            "samsung_pay" => WalletDetailsType::SamsungPay,
+            "visa_checkout" => WalletDetailsType::VisaCheckout,
+            _ => WalletDetailsType::Unknown(value.to_string()),
         }
     }
 }
@@ -426,3 +454,5 @@ impl std::fmt::Display for WalletDetailsType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(WalletDetailsType);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::PayoutId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, BankAccount, Card, Currency};
@@ -104,22 +104,26 @@ impl Payout {
     /// Returns a list of existing payouts sent to third-party bank accounts or that Stripe has sent you.
     ///
     /// The payouts are returned in sorted order, with the most recently created payouts appearing first.
-    pub fn list(client: &Client, params: ListPayouts<'_>) -> Response<List<Payout>> {
-        client.get_query("/payouts", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListPayouts<'_>) -> C::Response<List<Payout>> {
+        List::get_query(client, "/payouts", &params)
     }
 
     /// To send funds to your own bank account, you create a new payout object.
     ///
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must be able to cover the payout amount, or you’ll receive an “Insufficient Funds” error.  If your API key is in test mode, money won’t actually be sent, though everything else will occur as if in live mode.  If you are creating a manual payout on a Stripe account that uses multiple payment source types, you’ll need to specify the source type balance that the payout should draw from.
     /// The [balance object](https://stripe.com/docs/api#balance_object) details available and pending amounts by source type.
-    pub fn create(client: &Client, params: CreatePayout<'_>) -> Response<Payout> {
+    pub fn create<C: StripeClient>(client: &C, params: CreatePayout<'_>) -> C::Response<Payout> {
         client.post_form("/payouts", &params)
     }
 
     /// Retrieves the details of an existing payout.
     ///
     /// Supply the unique payout ID from either a payout creation request or the payout list, and Stripe will return the corresponding payout information.
-    pub fn retrieve(client: &Client, id: &PayoutId, expand: &[&str]) -> Response<Payout> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PayoutId,
+        expand: &[&str],
+    ) -> C::Response<Payout> {
         client.get_query(&format!("/payouts/{}", id), &Expand { expand })
     }
 
@@ -127,7 +131,11 @@ impl Payout {
     ///
     /// Any parameters not provided will be left unchanged.
     /// This request accepts only the metadata as arguments.
-    pub fn update(client: &Client, id: &PayoutId, params: UpdatePayout<'_>) -> Response<Payout> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PayoutId,
+        params: UpdatePayout<'_>,
+    ) -> C::Response<Payout> {
         client.post_form(&format!("/payouts/{}", id), &params)
     }
 }
@@ -289,18 +297,30 @@ pub enum PayoutDestination {
 }
 
 /// An enum representing the possible values of an `CreatePayout`'s `method` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PayoutMethod {
     Instant,
     Standard,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PayoutMethod {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PayoutMethod::Instant => "instant",
             PayoutMethod::Standard => "standard",
+            PayoutMethod::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PayoutMethod {
+    fn from(value: &str) -> Self {
+        match value {
+            "instant" => PayoutMethod::Instant,
+            "standard" => PayoutMethod::Standard,
+            _ => PayoutMethod::Unknown(value.to_string()),
         }
     }
 }
@@ -317,19 +337,33 @@ impl std::fmt::Display for PayoutMethod {
     }
 }
 
+serde_string_enum!(PayoutMethod);
+
 /// An enum representing the possible values of an `CreatePayout`'s `source_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PayoutSourceType {
     BankAccount,
     Card,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PayoutSourceType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PayoutSourceType::BankAccount => "bank_account",
             PayoutSourceType::Card => "card",
+            PayoutSourceType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PayoutSourceType {
+    fn from(value: &str) -> Self {
+        match value {
+            "bank_account" => PayoutSourceType::BankAccount,
+            "card" => PayoutSourceType::Card,
+            _ => PayoutSourceType::Unknown(value.to_string()),
         }
     }
 }
@@ -346,19 +380,33 @@ impl std::fmt::Display for PayoutSourceType {
     }
 }
 
+serde_string_enum!(PayoutSourceType);
+
 /// An enum representing the possible values of an `Payout`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PayoutType {
     BankAccount,
     Card,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PayoutType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PayoutType::BankAccount => "bank_account",
             PayoutType::Card => "card",
+            PayoutType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PayoutType {
+    fn from(value: &str) -> Self {
+        match value {
+            "bank_account" => PayoutType::BankAccount,
+            "card" => PayoutType::Card,
+            _ => PayoutType::Unknown(value.to_string()),
         }
     }
 }
@@ -374,3 +422,5 @@ impl std::fmt::Display for PayoutType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(PayoutType);
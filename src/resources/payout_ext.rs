@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{PayoutDestinationId, PayoutId};
 use crate::params::Object;
 use crate::resources::{Payout, PayoutDestination};
@@ -7,7 +7,7 @@ impl Payout {
     /// Cancels the payout.
     ///
     /// For more details see [https://stripe.com/docs/api/payouts/cancel](https://stripe.com/docs/api/payouts/cancel).
-    pub fn cancel(client: &Client, id: &PayoutId) -> Response<Payout> {
+    pub fn cancel<C: StripeClient>(client: &C, id: &PayoutId) -> C::Response<Payout> {
         client.post(&format!("/payouts/{}/cancel", id))
     }
 }
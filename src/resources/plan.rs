@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::PlanId;
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{Currency, Product, UpTo};
@@ -118,17 +118,21 @@ pub struct Plan {
 
 impl Plan {
     /// Returns a list of your plans.
-    pub fn list(client: &Client, params: ListPlans<'_>) -> Response<List<Plan>> {
-        client.get_query("/plans", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListPlans<'_>) -> C::Response<List<Plan>> {
+        List::get_query(client, "/plans", &params)
     }
 
     /// You can create plans using the API, or in the Stripe [Dashboard](https://dashboard.stripe.com/subscriptions/products).
-    pub fn create(client: &Client, params: CreatePlan<'_>) -> Response<Plan> {
+    pub fn create<C: StripeClient>(client: &C, params: CreatePlan<'_>) -> C::Response<Plan> {
         client.post_form("/plans", &params)
     }
 
     /// Retrieves the plan with the given ID.
-    pub fn retrieve(client: &Client, id: &PlanId, expand: &[&str]) -> Response<Plan> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PlanId,
+        expand: &[&str],
+    ) -> C::Response<Plan> {
         client.get_query(&format!("/plans/{}", id), &Expand { expand })
     }
 
@@ -136,14 +140,18 @@ impl Plan {
     ///
     /// Any parameters not provided are left unchanged.
     /// By design, you cannot change a plan’s ID, amount, currency, or billing cycle.
-    pub fn update(client: &Client, id: &PlanId, params: UpdatePlan<'_>) -> Response<Plan> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PlanId,
+        params: UpdatePlan<'_>,
+    ) -> C::Response<Plan> {
         client.post_form(&format!("/plans/{}", id), &params)
     }
 
     /// Deleting plans means new subscribers can’t be added.
     ///
     /// Existing subscribers aren’t affected.
-    pub fn delete(client: &Client, id: &PlanId) -> Response<Deleted<PlanId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &PlanId) -> C::Response<Deleted<PlanId>> {
         client.delete(&format!("/plans/{}", id))
     }
 }
@@ -411,18 +419,30 @@ pub struct CreatePlanTransformUsage {
 }
 
 /// An enum representing the possible values of an `CreatePlanTransformUsage`'s `round` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CreatePlanTransformUsageRound {
     Down,
     Up,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl CreatePlanTransformUsageRound {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             CreatePlanTransformUsageRound::Down => "down",
             CreatePlanTransformUsageRound::Up => "up",
+            CreatePlanTransformUsageRound::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for CreatePlanTransformUsageRound {
+    fn from(value: &str) -> Self {
+        match value {
+            "down" => CreatePlanTransformUsageRound::Down,
+            "up" => CreatePlanTransformUsageRound::Up,
+            _ => CreatePlanTransformUsageRound::Unknown(value.to_string()),
         }
     }
 }
@@ -439,23 +459,39 @@ impl std::fmt::Display for CreatePlanTransformUsageRound {
     }
 }
 
+serde_string_enum!(CreatePlanTransformUsageRound);
+
 /// An enum representing the possible values of an `Plan`'s `aggregate_usage` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PlanAggregateUsage {
     LastDuringPeriod,
     LastEver,
     Max,
     Sum,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PlanAggregateUsage {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PlanAggregateUsage::LastDuringPeriod => "last_during_period",
             PlanAggregateUsage::LastEver => "last_ever",
             PlanAggregateUsage::Max => "max",
             PlanAggregateUsage::Sum => "sum",
+            PlanAggregateUsage::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PlanAggregateUsage {
+    fn from(value: &str) -> Self {
+        match value {
+            "last_during_period" => PlanAggregateUsage::LastDuringPeriod,
+            "last_ever" => PlanAggregateUsage::LastEver,
+            "max" => PlanAggregateUsage::Max,
+            "sum" => PlanAggregateUsage::Sum,
+            _ => PlanAggregateUsage::Unknown(value.to_string()),
         }
     }
 }
@@ -472,19 +508,33 @@ impl std::fmt::Display for PlanAggregateUsage {
     }
 }
 
+serde_string_enum!(PlanAggregateUsage);
+
 /// An enum representing the possible values of an `Plan`'s `billing_scheme` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PlanBillingScheme {
     PerUnit,
     Tiered,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PlanBillingScheme {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PlanBillingScheme::PerUnit => "per_unit",
             PlanBillingScheme::Tiered => "tiered",
+            PlanBillingScheme::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PlanBillingScheme {
+    fn from(value: &str) -> Self {
+        match value {
+            "per_unit" => PlanBillingScheme::PerUnit,
+            "tiered" => PlanBillingScheme::Tiered,
+            _ => PlanBillingScheme::Unknown(value.to_string()),
         }
     }
 }
@@ -501,23 +551,39 @@ impl std::fmt::Display for PlanBillingScheme {
     }
 }
 
+serde_string_enum!(PlanBillingScheme);
+
 /// An enum representing the possible values of an `Plan`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PlanInterval {
     Day,
     Month,
     Week,
     Year,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PlanInterval {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PlanInterval::Day => "day",
             PlanInterval::Month => "month",
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
+            PlanInterval::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PlanInterval {
+    fn from(value: &str) -> Self {
+        match value {
+            "day" => PlanInterval::Day,
+            "month" => PlanInterval::Month,
+            "week" => PlanInterval::Week,
+            "year" => PlanInterval::Year,
+            _ => PlanInterval::Unknown(value.to_string()),
         }
     }
 }
@@ -534,19 +600,33 @@ impl std::fmt::Display for PlanInterval {
     }
 }
 
+serde_string_enum!(PlanInterval);
+
 /// An enum representing the possible values of an `Plan`'s `tiers_mode` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PlanTiersMode {
     Graduated,
     Volume,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PlanTiersMode {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PlanTiersMode::Graduated => "graduated",
             PlanTiersMode::Volume => "volume",
+            PlanTiersMode::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PlanTiersMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "graduated" => PlanTiersMode::Graduated,
+            "volume" => PlanTiersMode::Volume,
+            _ => PlanTiersMode::Unknown(value.to_string()),
         }
     }
 }
@@ -563,19 +643,33 @@ impl std::fmt::Display for PlanTiersMode {
     }
 }
 
+serde_string_enum!(PlanTiersMode);
+
 /// An enum representing the possible values of an `Plan`'s `usage_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PlanUsageType {
     Licensed,
     Metered,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl PlanUsageType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             PlanUsageType::Licensed => "licensed",
             PlanUsageType::Metered => "metered",
+            PlanUsageType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for PlanUsageType {
+    fn from(value: &str) -> Self {
+        match value {
+            "licensed" => PlanUsageType::Licensed,
+            "metered" => PlanUsageType::Metered,
+            _ => PlanUsageType::Unknown(value.to_string()),
         }
     }
 }
@@ -592,19 +686,33 @@ impl std::fmt::Display for PlanUsageType {
     }
 }
 
+serde_string_enum!(PlanUsageType);
+
 /// An enum representing the possible values of an `TransformUsage`'s `round` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransformUsageRound {
     Down,
     Up,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TransformUsageRound {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TransformUsageRound::Down => "down",
             TransformUsageRound::Up => "up",
+            TransformUsageRound::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TransformUsageRound {
+    fn from(value: &str) -> Self {
+        match value {
+            "down" => TransformUsageRound::Down,
+            "up" => TransformUsageRound::Up,
+            _ => TransformUsageRound::Unknown(value.to_string()),
         }
     }
 }
@@ -620,3 +728,5 @@ impl std::fmt::Display for TransformUsageRound {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TransformUsageRound);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::ProductId;
 use crate::params::{Deleted, Expand, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::PackageDimensions;
@@ -123,21 +123,28 @@ impl Product {
     /// Returns a list of your products.
     ///
     /// The products are returned sorted by creation date, with the most recently created products appearing first.
-    pub fn list(client: &Client, params: ListProducts<'_>) -> Response<List<Product>> {
-        client.get_query("/products", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListProducts<'_>,
+    ) -> C::Response<List<Product>> {
+        List::get_query(client, "/products", &params)
     }
 
     /// Creates a new product object.
     ///
     /// To create a product for use with subscriptions, see [Subscriptions Products](https://stripe.com/docs/api#create_service_product).
-    pub fn create(client: &Client, params: CreateProduct<'_>) -> Response<Product> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateProduct<'_>) -> C::Response<Product> {
         client.post_form("/products", &params)
     }
 
     /// Retrieves the details of an existing product.
     ///
     /// Supply the unique product ID from either a product creation request or the product list, and Stripe will return the corresponding product information.
-    pub fn retrieve(client: &Client, id: &ProductId, expand: &[&str]) -> Response<Product> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ProductId,
+        expand: &[&str],
+    ) -> C::Response<Product> {
         client.get_query(&format!("/products/{}", id), &Expand { expand })
     }
 
@@ -145,7 +152,11 @@ impl Product {
     ///
     /// Any parameters not provided will be left unchanged.  Note that a product’s `attributes` are not editable.
     /// Instead, you would need to deactivate the existing product and create a new one with the new attribute values.
-    pub fn update(client: &Client, id: &ProductId, params: UpdateProduct<'_>) -> Response<Product> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &ProductId,
+        params: UpdateProduct<'_>,
+    ) -> C::Response<Product> {
         client.post_form(&format!("/products/{}", id), &params)
     }
 
@@ -153,7 +164,7 @@ impl Product {
     ///
     /// Deleting a product with type=`good` is only possible if it has no SKUs associated with it.
     /// Deleting a product with type=`service` is only possible if it has no plans associated with it.
-    pub fn delete(client: &Client, id: &ProductId) -> Response<Deleted<ProductId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &ProductId) -> C::Response<Deleted<ProductId>> {
         client.delete(&format!("/products/{}", id))
     }
 }
@@ -469,18 +480,30 @@ impl<'a> UpdateProduct<'a> {
 }
 
 /// An enum representing the possible values of an `Product`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProductType {
     Good,
     Service,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl ProductType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             ProductType::Good => "good",
             ProductType::Service => "service",
+            ProductType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ProductType {
+    fn from(value: &str) -> Self {
+        match value {
+            "good" => ProductType::Good,
+            "service" => ProductType::Service,
+            _ => ProductType::Unknown(value.to_string()),
         }
     }
 }
@@ -496,3 +519,5 @@ impl std::fmt::Display for ProductType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(ProductType);
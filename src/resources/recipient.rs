@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::RecipientId;
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{Account, BankAccount, Card};
@@ -77,20 +77,30 @@ impl Recipient {
     /// Returns a list of your recipients.
     ///
     /// The recipients are returned sorted by creation date, with the most recently created recipients appearing first.
-    pub fn list(client: &Client, params: ListRecipients<'_>) -> Response<List<Recipient>> {
-        client.get_query("/recipients", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListRecipients<'_>,
+    ) -> C::Response<List<Recipient>> {
+        List::get_query(client, "/recipients", &params)
     }
 
     /// Creates a new `Recipient` object and verifies the recipient’s identity.
     /// Also verifies the recipient’s bank account information or debit card, if either is provided.
-    pub fn create(client: &Client, params: CreateRecipient<'_>) -> Response<Recipient> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateRecipient<'_>,
+    ) -> C::Response<Recipient> {
         client.post_form("/recipients", &params)
     }
 
     /// Retrieves the details of an existing recipient.
     ///
     /// You need only supply the unique recipient identifier that was returned upon recipient creation.
-    pub fn retrieve(client: &Client, id: &RecipientId, expand: &[&str]) -> Response<Recipient> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &RecipientId,
+        expand: &[&str],
+    ) -> C::Response<Recipient> {
         client.get_query(&format!("/recipients/{}", id), &Expand { expand })
     }
 
@@ -99,18 +109,21 @@ impl Recipient {
     ///
     /// If you update the name or tax ID, the identity verification will automatically be rerun.
     /// If you update the bank account, the bank account validation will automatically be rerun.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &RecipientId,
         params: UpdateRecipient<'_>,
-    ) -> Response<Recipient> {
+    ) -> C::Response<Recipient> {
         client.post_form(&format!("/recipients/{}", id), &params)
     }
 
     /// Permanently deletes a recipient.
     ///
     /// It cannot be undone.
-    pub fn delete(client: &Client, id: &RecipientId) -> Response<Deleted<RecipientId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &RecipientId,
+    ) -> C::Response<Deleted<RecipientId>> {
         client.delete(&format!("/recipients/{}", id))
     }
 }
@@ -296,18 +309,30 @@ impl<'a> UpdateRecipient<'a> {
 }
 
 /// An enum representing the possible values of an `Recipient`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RecipientType {
     Corporation,
     Individual,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl RecipientType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             RecipientType::Corporation => "corporation",
             RecipientType::Individual => "individual",
+            RecipientType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for RecipientType {
+    fn from(value: &str) -> Self {
+        match value {
+            "corporation" => RecipientType::Corporation,
+            "individual" => RecipientType::Individual,
+            _ => RecipientType::Unknown(value.to_string()),
         }
     }
 }
@@ -323,3 +348,5 @@ impl std::fmt::Display for RecipientType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(RecipientType);
@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{ChargeId, RefundId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Charge, Currency, TransferReversal};
@@ -92,24 +92,32 @@ impl Refund {
     ///
     /// The refunds are returned in sorted order, with the most recent refunds appearing first.
     /// For convenience, the 10 most recent refunds are always available by default on the charge object.
-    pub fn list(client: &Client, params: ListRefunds<'_>) -> Response<List<Refund>> {
-        client.get_query("/refunds", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListRefunds<'_>) -> C::Response<List<Refund>> {
+        List::get_query(client, "/refunds", &params)
     }
 
     /// Create a refund.
-    pub fn create(client: &Client, params: CreateRefund<'_>) -> Response<Refund> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateRefund<'_>) -> C::Response<Refund> {
         client.post_form("/refunds", &params)
     }
 
     /// Retrieves the details of an existing refund.
-    pub fn retrieve(client: &Client, id: &RefundId, expand: &[&str]) -> Response<Refund> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &RefundId,
+        expand: &[&str],
+    ) -> C::Response<Refund> {
         client.get_query(&format!("/refunds/{}", id), &Expand { expand })
     }
 
     /// Updates the specified refund by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.  This request only accepts `metadata` as an argument.
-    pub fn update(client: &Client, id: &RefundId, params: UpdateRefund<'_>) -> Response<Refund> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &RefundId,
+        params: UpdateRefund<'_>,
+    ) -> C::Response<Refund> {
         client.post_form(&format!("/refunds/{}", id), &params)
     }
 }
@@ -239,20 +247,33 @@ impl<'a> UpdateRefund<'a> {
 }
 
 /// An enum representing the possible values of an `CreateRefund`'s `reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RefundReason {
     Duplicate,
     Fraudulent,
     RequestedByCustomer,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl RefundReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             RefundReason::Duplicate => "duplicate",
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
+            RefundReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for RefundReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "duplicate" => RefundReason::Duplicate,
+            "fraudulent" => RefundReason::Fraudulent,
+            "requested_by_customer" => RefundReason::RequestedByCustomer,
+            _ => RefundReason::Unknown(value.to_string()),
         }
     }
 }
@@ -268,3 +289,5 @@ impl std::fmt::Display for RefundReason {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(RefundReason);
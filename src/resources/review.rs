@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::ReviewId;
 use crate::params::{Expand, Expandable, List, Object, RangeQuery, Timestamp};
 use crate::resources::{Charge, PaymentIntent};
@@ -72,12 +72,16 @@ impl Review {
     /// Returns a list of `Review` objects that have `open` set to `true`.
     ///
     /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
-    pub fn list(client: &Client, params: ListReviews<'_>) -> Response<List<Review>> {
-        client.get_query("/reviews", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListReviews<'_>) -> C::Response<List<Review>> {
+        List::get_query(client, "/reviews", &params)
     }
 
     /// Retrieves a `Review` object.
-    pub fn retrieve(client: &Client, id: &ReviewId, expand: &[&str]) -> Response<Review> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ReviewId,
+        expand: &[&str],
+    ) -> C::Response<Review> {
         client.get_query(&format!("/reviews/{}", id), &Expand { expand })
     }
 }
@@ -178,22 +182,36 @@ impl<'a> ListReviews<'a> {
 }
 
 /// An enum representing the possible values of an `Review`'s `closed_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReviewClosedReason {
     Approved,
     Disputed,
     Refunded,
     RefundedAsFraud,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl ReviewClosedReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             ReviewClosedReason::Approved => "approved",
             ReviewClosedReason::Disputed => "disputed",
             ReviewClosedReason::Refunded => "refunded",
             ReviewClosedReason::RefundedAsFraud => "refunded_as_fraud",
+            ReviewClosedReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ReviewClosedReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "approved" => ReviewClosedReason::Approved,
+            "disputed" => ReviewClosedReason::Disputed,
+            "refunded" => ReviewClosedReason::Refunded,
+            "refunded_as_fraud" => ReviewClosedReason::RefundedAsFraud,
+            _ => ReviewClosedReason::Unknown(value.to_string()),
         }
     }
 }
@@ -210,19 +228,33 @@ impl std::fmt::Display for ReviewClosedReason {
     }
 }
 
+serde_string_enum!(ReviewClosedReason);
+
 /// An enum representing the possible values of an `Review`'s `opened_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReviewOpenedReason {
     Manual,
     Rule,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl ReviewOpenedReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             ReviewOpenedReason::Manual => "manual",
             ReviewOpenedReason::Rule => "rule",
+            ReviewOpenedReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ReviewOpenedReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "manual" => ReviewOpenedReason::Manual,
+            "rule" => ReviewOpenedReason::Rule,
+            _ => ReviewOpenedReason::Unknown(value.to_string()),
         }
     }
 }
@@ -239,9 +271,10 @@ impl std::fmt::Display for ReviewOpenedReason {
     }
 }
 
+serde_string_enum!(ReviewOpenedReason);
+
 /// An enum representing the possible values of an `Review`'s `reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReviewReason {
     Approved,
     Disputed,
@@ -249,10 +282,12 @@ pub enum ReviewReason {
     Refunded,
     RefundedAsFraud,
     Rule,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl ReviewReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             ReviewReason::Approved => "approved",
             ReviewReason::Disputed => "disputed",
@@ -260,6 +295,21 @@ impl ReviewReason {
             ReviewReason::Refunded => "refunded",
             ReviewReason::RefundedAsFraud => "refunded_as_fraud",
             ReviewReason::Rule => "rule",
+            ReviewReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ReviewReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "approved" => ReviewReason::Approved,
+            "disputed" => ReviewReason::Disputed,
+            "manual" => ReviewReason::Manual,
+            "refunded" => ReviewReason::Refunded,
+            "refunded_as_fraud" => ReviewReason::RefundedAsFraud,
+            "rule" => ReviewReason::Rule,
+            _ => ReviewReason::Unknown(value.to_string()),
         }
     }
 }
@@ -275,3 +325,5 @@ impl std::fmt::Display for ReviewReason {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(ReviewReason);
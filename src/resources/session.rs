@@ -1,7 +1,7 @@
 use serde_derive::{Deserialize, Serialize};
 use crate::params::{Metadata};
 use crate::resources::{Address, PaymentIntent};
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct SessionParams {
@@ -38,11 +38,11 @@ pub struct Session {
 // curl https://api.stripe.com/v1/checkout/sessions
 
 impl Session {
-    pub fn create(client: &Client, params: SessionParams) -> Response<Session> {
+    pub fn create<C: StripeClient>(client: &C, params: SessionParams) -> C::Response<Session> {
         client.post_form("/checkout/sessions", params)
     }
 
-    pub fn retrieve<'a>(client: &Client, session_id: &'a str) -> Response<Session> {
+    pub fn retrieve<C: StripeClient>(client: &C, session_id: &str) -> C::Response<Session> {
         client.post_form("/checkout/sessions", session_id)
     }
 }
@@ -1,6 +1,6 @@
 
 use serde_derive::{Deserialize, Serialize};
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -42,47 +42,50 @@ pub struct SetupIntent {
 impl SetupIntent {
 
     /// Creates a new setup_intent.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: SetupIntentCreateParams,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form("/setup_intents", params)
     }
 
     /// Retrieves the details of a setup_intent.
     /// https://stripe.com/docs/api/setup_intents/retrieve
-    pub fn retrieve(client: &Client, setup_intent_id: &str) -> Response<SetupIntent> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        setup_intent_id: &str,
+    ) -> C::Response<SetupIntent> {
         client.get(&format!("/setup_intents/{}", setup_intent_id))
     }
 
     /// Updates a setup_intent's properties.
     /// https://stripe.com/docs/api/setup_intents/update
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         setup_intent_id: &str,
         params: SetupIntentUpdateParams,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}", setup_intent_id), params)
     }
 
     /// Confirm that customer intends to pay with current or provided source. Upon confirmation, the SetupIntent will attempt to initiate a payment.
     /// https://stripe.com/docs/api/setup_intents/confirm
-    pub fn confirm(
-        client: &Client,
+    pub fn confirm<C: StripeClient>(
+        client: &C,
         setup_intent_id: &str,
         params: SetupIntentConfirmParams,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}/confirm", setup_intent_id), params)
     }
 
 
     /// A SetupIntent object can be canceled when it is in one of these statuses:
     /// https://stripe.com/docs/api/setup_intents/cancel
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         setup_intent_id: &str,
         params: SetupIntentCancelParams,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}/cancel", setup_intent_id), params)
     }
 
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::SkuId;
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, Timestamp};
 use crate::resources::{Currency, PackageDimensions, Product};
@@ -81,19 +81,19 @@ impl Sku {
     /// Returns a list of your SKUs.
     ///
     /// The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.
-    pub fn list(client: &Client, params: ListSkus<'_>) -> Response<List<Sku>> {
-        client.get_query("/skus", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListSkus<'_>) -> C::Response<List<Sku>> {
+        List::get_query(client, "/skus", &params)
     }
 
     /// Creates a new SKU associated with a product.
-    pub fn create(client: &Client, params: CreateSku<'_>) -> Response<Sku> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateSku<'_>) -> C::Response<Sku> {
         client.post_form("/skus", &params)
     }
 
     /// Retrieves the details of an existing SKU.
     ///
     /// Supply the unique SKU identifier from either a SKU creation request or from the product, and Stripe will return the corresponding SKU information.
-    pub fn retrieve(client: &Client, id: &SkuId, expand: &[&str]) -> Response<Sku> {
+    pub fn retrieve<C: StripeClient>(client: &C, id: &SkuId, expand: &[&str]) -> C::Response<Sku> {
         client.get_query(&format!("/skus/{}", id), &Expand { expand })
     }
 
@@ -101,14 +101,18 @@ impl Sku {
     ///
     /// Any parameters not provided will be left unchanged.  Note that a SKU’s `attributes` are not editable.
     /// Instead, you would need to deactivate the existing SKU and create a new one with the new attribute values.
-    pub fn update(client: &Client, id: &SkuId, params: UpdateSku<'_>) -> Response<Sku> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &SkuId,
+        params: UpdateSku<'_>,
+    ) -> C::Response<Sku> {
         client.post_form(&format!("/skus/{}", id), &params)
     }
 
     /// Delete a SKU.
     ///
     /// Deleting a SKU is only possible until it has been used in an order.
-    pub fn delete(client: &Client, id: &SkuId) -> Response<Deleted<SkuId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &SkuId) -> C::Response<Deleted<SkuId>> {
         client.delete(&format!("/skus/{}", id))
     }
 }
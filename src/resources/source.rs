@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, SourceId, TokenId};
 use crate::params::{Expand, Metadata, Object, Timestamp};
 use crate::resources::{Address, BillingDetails, Currency};
@@ -148,14 +148,18 @@ pub struct Source {
 
 impl Source {
     /// Creates a new source object.
-    pub fn create(client: &Client, params: CreateSource<'_>) -> Response<Source> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateSource<'_>) -> C::Response<Source> {
         client.post_form("/sources", &params)
     }
 
     /// Retrieves an existing source object.
     ///
     /// Supply the unique source ID from a source creation request and Stripe will return the corresponding up-to-date source object information.
-    pub fn retrieve(client: &Client, id: &SourceId, expand: &[&str]) -> Response<Source> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &SourceId,
+        expand: &[&str],
+    ) -> C::Response<Source> {
         client.get_query(&format!("/sources/{}", id), &Expand { expand })
     }
 
@@ -164,7 +168,11 @@ impl Source {
     /// Any parameters not provided will be left unchanged.  This request accepts the `metadata` and `owner` as arguments.
     /// It is also possible to update type specific information for selected payment methods.
     /// Please refer to our [payment method guides](https://stripe.com/docs/sources) for more detail.
-    pub fn update(client: &Client, id: &SourceId, params: UpdateSource<'_>) -> Response<Source> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &SourceId,
+        params: UpdateSource<'_>,
+    ) -> C::Response<Source> {
         client.post_form(&format!("/sources/{}", id), &params)
     }
 }
@@ -887,22 +895,36 @@ pub struct SourceAcceptanceOnlineParams {
 }
 
 /// An enum representing the possible values of an `SourceAcceptanceParams`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceAcceptanceParamsStatus {
     Accepted,
     Pending,
     Refused,
     Revoked,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceAcceptanceParamsStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceAcceptanceParamsStatus::Accepted => "accepted",
             SourceAcceptanceParamsStatus::Pending => "pending",
             SourceAcceptanceParamsStatus::Refused => "refused",
             SourceAcceptanceParamsStatus::Revoked => "revoked",
+            SourceAcceptanceParamsStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceAcceptanceParamsStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "accepted" => SourceAcceptanceParamsStatus::Accepted,
+            "pending" => SourceAcceptanceParamsStatus::Pending,
+            "refused" => SourceAcceptanceParamsStatus::Refused,
+            "revoked" => SourceAcceptanceParamsStatus::Revoked,
+            _ => SourceAcceptanceParamsStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -919,19 +941,33 @@ impl std::fmt::Display for SourceAcceptanceParamsStatus {
     }
 }
 
+serde_string_enum!(SourceAcceptanceParamsStatus);
+
 /// An enum representing the possible values of an `SourceAcceptanceParams`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceAcceptanceParamsType {
     Offline,
     Online,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceAcceptanceParamsType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceAcceptanceParamsType::Offline => "offline",
             SourceAcceptanceParamsType::Online => "online",
+            SourceAcceptanceParamsType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceAcceptanceParamsType {
+    fn from(value: &str) -> Self {
+        match value {
+            "offline" => SourceAcceptanceParamsType::Offline,
+            "online" => SourceAcceptanceParamsType::Online,
+            _ => SourceAcceptanceParamsType::Unknown(value.to_string()),
         }
     }
 }
@@ -948,23 +984,39 @@ impl std::fmt::Display for SourceAcceptanceParamsType {
     }
 }
 
+serde_string_enum!(SourceAcceptanceParamsType);
+
 /// An enum representing the possible values of an `Source`'s `flow` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceFlow {
     CodeVerification,
     None,
     Receiver,
     Redirect,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceFlow {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceFlow::CodeVerification => "code_verification",
             SourceFlow::None => "none",
             SourceFlow::Receiver => "receiver",
             SourceFlow::Redirect => "redirect",
+            SourceFlow::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceFlow {
+    fn from(value: &str) -> Self {
+        match value {
+            "code_verification" => SourceFlow::CodeVerification,
+            "none" => SourceFlow::None,
+            "receiver" => SourceFlow::Receiver,
+            "redirect" => SourceFlow::Redirect,
+            _ => SourceFlow::Unknown(value.to_string()),
         }
     }
 }
@@ -981,21 +1033,36 @@ impl std::fmt::Display for SourceFlow {
     }
 }
 
+serde_string_enum!(SourceFlow);
+
 /// An enum representing the possible values of an `SourceMandateParams`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceMandateInterval {
     OneTime,
     Scheduled,
     Variable,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceMandateInterval {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceMandateInterval::OneTime => "one_time",
             SourceMandateInterval::Scheduled => "scheduled",
             SourceMandateInterval::Variable => "variable",
+            SourceMandateInterval::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceMandateInterval {
+    fn from(value: &str) -> Self {
+        match value {
+            "one_time" => SourceMandateInterval::OneTime,
+            "scheduled" => SourceMandateInterval::Scheduled,
+            "variable" => SourceMandateInterval::Variable,
+            _ => SourceMandateInterval::Unknown(value.to_string()),
         }
     }
 }
@@ -1012,25 +1079,42 @@ impl std::fmt::Display for SourceMandateInterval {
     }
 }
 
+serde_string_enum!(SourceMandateInterval);
+
 /// An enum representing the possible values of an `SourceMandateParams`'s `notification_method` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceMandateNotificationMethod {
     DeprecatedNone,
     Email,
     Manual,
     None,
     StripeEmail,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceMandateNotificationMethod {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceMandateNotificationMethod::DeprecatedNone => "deprecated_none",
             SourceMandateNotificationMethod::Email => "email",
             SourceMandateNotificationMethod::Manual => "manual",
             SourceMandateNotificationMethod::None => "none",
             SourceMandateNotificationMethod::StripeEmail => "stripe_email",
+            SourceMandateNotificationMethod::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceMandateNotificationMethod {
+    fn from(value: &str) -> Self {
+        match value {
+            "deprecated_none" => SourceMandateNotificationMethod::DeprecatedNone,
+            "email" => SourceMandateNotificationMethod::Email,
+            "manual" => SourceMandateNotificationMethod::Manual,
+            "none" => SourceMandateNotificationMethod::None,
+            "stripe_email" => SourceMandateNotificationMethod::StripeEmail,
+            _ => SourceMandateNotificationMethod::Unknown(value.to_string()),
         }
     }
 }
@@ -1047,21 +1131,36 @@ impl std::fmt::Display for SourceMandateNotificationMethod {
     }
 }
 
+serde_string_enum!(SourceMandateNotificationMethod);
+
 /// An enum representing the possible values of an `SourceRedirectFlow`'s `failure_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceRedirectFlowFailureReason {
     Declined,
     ProcessingError,
     UserAbort,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceRedirectFlowFailureReason {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceRedirectFlowFailureReason::Declined => "declined",
             SourceRedirectFlowFailureReason::ProcessingError => "processing_error",
             SourceRedirectFlowFailureReason::UserAbort => "user_abort",
+            SourceRedirectFlowFailureReason::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceRedirectFlowFailureReason {
+    fn from(value: &str) -> Self {
+        match value {
+            "declined" => SourceRedirectFlowFailureReason::Declined,
+            "processing_error" => SourceRedirectFlowFailureReason::ProcessingError,
+            "user_abort" => SourceRedirectFlowFailureReason::UserAbort,
+            _ => SourceRedirectFlowFailureReason::Unknown(value.to_string()),
         }
     }
 }
@@ -1078,23 +1177,39 @@ impl std::fmt::Display for SourceRedirectFlowFailureReason {
     }
 }
 
+serde_string_enum!(SourceRedirectFlowFailureReason);
+
 /// An enum representing the possible values of an `SourceRedirectFlow`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceRedirectFlowStatus {
     Failed,
     NotRequired,
     Pending,
     Succeeded,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceRedirectFlowStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceRedirectFlowStatus::Failed => "failed",
             SourceRedirectFlowStatus::NotRequired => "not_required",
             SourceRedirectFlowStatus::Pending => "pending",
             SourceRedirectFlowStatus::Succeeded => "succeeded",
+            SourceRedirectFlowStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceRedirectFlowStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "failed" => SourceRedirectFlowStatus::Failed,
+            "not_required" => SourceRedirectFlowStatus::NotRequired,
+            "pending" => SourceRedirectFlowStatus::Pending,
+            "succeeded" => SourceRedirectFlowStatus::Succeeded,
+            _ => SourceRedirectFlowStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -1111,21 +1226,36 @@ impl std::fmt::Display for SourceRedirectFlowStatus {
     }
 }
 
+serde_string_enum!(SourceRedirectFlowStatus);
+
 /// An enum representing the possible values of an `CreateSourceReceiver`'s `refund_attributes_method` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceRefundNotificationMethod {
     Email,
     Manual,
     None,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceRefundNotificationMethod {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceRefundNotificationMethod::Email => "email",
             SourceRefundNotificationMethod::Manual => "manual",
             SourceRefundNotificationMethod::None => "none",
+            SourceRefundNotificationMethod::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceRefundNotificationMethod {
+    fn from(value: &str) -> Self {
+        match value {
+            "email" => SourceRefundNotificationMethod::Email,
+            "manual" => SourceRefundNotificationMethod::Manual,
+            "none" => SourceRefundNotificationMethod::None,
+            _ => SourceRefundNotificationMethod::Unknown(value.to_string()),
         }
     }
 }
@@ -1142,25 +1272,42 @@ impl std::fmt::Display for SourceRefundNotificationMethod {
     }
 }
 
+serde_string_enum!(SourceRefundNotificationMethod);
+
 /// An enum representing the possible values of an `Source`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceStatus {
     Canceled,
     Chargeable,
     Consumed,
     Failed,
     Pending,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceStatus::Canceled => "canceled",
             SourceStatus::Chargeable => "chargeable",
             SourceStatus::Consumed => "consumed",
             SourceStatus::Failed => "failed",
             SourceStatus::Pending => "pending",
+            SourceStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "canceled" => SourceStatus::Canceled,
+            "chargeable" => SourceStatus::Chargeable,
+            "consumed" => SourceStatus::Consumed,
+            "failed" => SourceStatus::Failed,
+            "pending" => SourceStatus::Pending,
+            _ => SourceStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -1177,9 +1324,10 @@ impl std::fmt::Display for SourceStatus {
     }
 }
 
+serde_string_enum!(SourceStatus);
+
 /// An enum representing the possible values of an `Source`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceType {
     AchCreditTransfer,
     AchDebit,
@@ -1196,10 +1344,12 @@ pub enum SourceType {
     Sofort,
     ThreeDSecure,
     Wechat,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceType::AchCreditTransfer => "ach_credit_transfer",
             SourceType::AchDebit => "ach_debit",
@@ -1216,6 +1366,30 @@ impl SourceType {
             SourceType::Sofort => "sofort",
             SourceType::ThreeDSecure => "three_d_secure",
             SourceType::Wechat => "wechat",
+            SourceType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceType {
+    fn from(value: &str) -> Self {
+        match value {
+            "ach_credit_transfer" => SourceType::AchCreditTransfer,
+            "ach_debit" => SourceType::AchDebit,
+            "alipay" => SourceType::Alipay,
+            "bancontact" => SourceType::Bancontact,
+            "card" => SourceType::Card,
+            "card_present" => SourceType::CardPresent,
+            "eps" => SourceType::Eps,
+            "giropay" => SourceType::Giropay,
+            "ideal" => SourceType::Ideal,
+            "multibanco" => SourceType::Multibanco,
+            "p24" => SourceType::P24,
+            "sepa_debit" => SourceType::SepaDebit,
+            "sofort" => SourceType::Sofort,
+            "three_d_secure" => SourceType::ThreeDSecure,
+            "wechat" => SourceType::Wechat,
+            _ => SourceType::Unknown(value.to_string()),
         }
     }
 }
@@ -1232,19 +1406,33 @@ impl std::fmt::Display for SourceType {
     }
 }
 
+serde_string_enum!(SourceType);
+
 /// An enum representing the possible values of an `Source`'s `usage` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SourceUsage {
     Reusable,
     SingleUse,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SourceUsage {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SourceUsage::Reusable => "reusable",
             SourceUsage::SingleUse => "single_use",
+            SourceUsage::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceUsage {
+    fn from(value: &str) -> Self {
+        match value {
+            "reusable" => SourceUsage::Reusable,
+            "single_use" => SourceUsage::SingleUse,
+            _ => SourceUsage::Unknown(value.to_string()),
         }
     }
 }
@@ -1260,3 +1448,5 @@ impl std::fmt::Display for SourceUsage {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(SourceUsage);
@@ -0,0 +1,259 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::ids::SourceTransactionId;
+use crate::params::{Object, Timestamp};
+use crate::resources::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "SourceTransaction".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransaction {
+    /// Unique identifier for the object.
+    pub id: SourceTransactionId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_credit_transfer: Option<SourceTransactionAchCreditTransferData>,
+
+    /// A positive integer in the smallest currency unit (that is, 100 cents for $1.00, or 1 for ¥1, Japanese Yen being a zero-decimal currency) representing the amount your customer has pushed to the receiver.
+    pub amount: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chf_credit_transfer: Option<SourceTransactionChfCreditTransferData>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_check: Option<SourceTransactionPaperCheckData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_credit_transfer: Option<SourceTransactionSepaCreditTransferData>,
+
+    /// The ID of the source this transaction is attached to.
+    pub source: String,
+
+    /// The status of the transaction, one of `succeeded`, `pending`, or `failed`.
+    pub status: SourceTransactionStatus,
+
+    /// The type of source this transaction is attached to.
+    #[serde(rename = "type")]
+    pub type_: SourceTransactionType,
+}
+
+impl Object for SourceTransaction {
+    type Id = SourceTransactionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "source_transaction"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionAchCreditTransferData {
+    /// Customer data associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_data: Option<String>,
+
+    /// Bank account fingerprint associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// Last 4 digits of the account number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Routing number associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionChfCreditTransferData {
+    /// Reference associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Sender's country address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address_country: Option<String>,
+
+    /// Sender's line 1 address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address_line1: Option<String>,
+
+    /// Sender's bank account IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_iban: Option<String>,
+
+    /// Sender's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionPaperCheckData {
+    /// String unix time for the available date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_at: Option<String>,
+
+    /// Invoice ID associated with the paper check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoices: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceTransactionSepaCreditTransferData {
+    /// Reference associated with the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Sender's bank account IBAN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_iban: Option<String>,
+
+    /// Sender's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+}
+
+/// An enum representing the possible values of an `SourceTransaction`'s `status` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SourceTransactionStatus {
+    Failed,
+    Pending,
+    Succeeded,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl SourceTransactionStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SourceTransactionStatus::Failed => "failed",
+            SourceTransactionStatus::Pending => "pending",
+            SourceTransactionStatus::Succeeded => "succeeded",
+            SourceTransactionStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceTransactionStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "failed" => SourceTransactionStatus::Failed,
+            "pending" => SourceTransactionStatus::Pending,
+            "succeeded" => SourceTransactionStatus::Succeeded,
+            _ => SourceTransactionStatus::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for SourceTransactionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SourceTransactionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(SourceTransactionStatus);
+
+/// An enum representing the possible values of an `SourceTransaction`'s `type` field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SourceTransactionType {
+    AchCreditTransfer,
+    AchDebit,
+    Alipay,
+    Bancontact,
+    Card,
+    CardPresent,
+    Eps,
+    Giropay,
+    Ideal,
+    Multibanco,
+    P24,
+    SepaDebit,
+    Sofort,
+    ThreeDSecure,
+    Wechat,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
+}
+
+impl SourceTransactionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SourceTransactionType::AchCreditTransfer => "ach_credit_transfer",
+            SourceTransactionType::AchDebit => "ach_debit",
+            SourceTransactionType::Alipay => "alipay",
+            SourceTransactionType::Bancontact => "bancontact",
+            SourceTransactionType::Card => "card",
+            SourceTransactionType::CardPresent => "card_present",
+            SourceTransactionType::Eps => "eps",
+            SourceTransactionType::Giropay => "giropay",
+            SourceTransactionType::Ideal => "ideal",
+            SourceTransactionType::Multibanco => "multibanco",
+            SourceTransactionType::P24 => "p24",
+            SourceTransactionType::SepaDebit => "sepa_debit",
+            SourceTransactionType::Sofort => "sofort",
+            SourceTransactionType::ThreeDSecure => "three_d_secure",
+            SourceTransactionType::Wechat => "wechat",
+            SourceTransactionType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SourceTransactionType {
+    fn from(value: &str) -> Self {
+        match value {
+            "ach_credit_transfer" => SourceTransactionType::AchCreditTransfer,
+            "ach_debit" => SourceTransactionType::AchDebit,
+            "alipay" => SourceTransactionType::Alipay,
+            "bancontact" => SourceTransactionType::Bancontact,
+            "card" => SourceTransactionType::Card,
+            "card_present" => SourceTransactionType::CardPresent,
+            "eps" => SourceTransactionType::Eps,
+            "giropay" => SourceTransactionType::Giropay,
+            "ideal" => SourceTransactionType::Ideal,
+            "multibanco" => SourceTransactionType::Multibanco,
+            "p24" => SourceTransactionType::P24,
+            "sepa_debit" => SourceTransactionType::SepaDebit,
+            "sofort" => SourceTransactionType::Sofort,
+            "three_d_secure" => SourceTransactionType::ThreeDSecure,
+            "wechat" => SourceTransactionType::Wechat,
+            _ => SourceTransactionType::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl AsRef<str> for SourceTransactionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SourceTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+serde_string_enum!(SourceTransactionType);
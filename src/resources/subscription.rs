@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CouponId, CustomerId, PlanId, SubscriptionId};
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
@@ -172,21 +172,27 @@ impl Subscription {
     /// By default, returns a list of subscriptions that have not been canceled.
     ///
     /// In order to list canceled subscriptions, specify `status=canceled`.
-    pub fn list(client: &Client, params: ListSubscriptions<'_>) -> Response<List<Subscription>> {
-        client.get_query("/subscriptions", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListSubscriptions<'_>,
+    ) -> C::Response<List<Subscription>> {
+        List::get_query(client, "/subscriptions", &params)
     }
 
     /// Creates a new subscription on an existing customer.
-    pub fn create(client: &Client, params: CreateSubscription<'_>) -> Response<Subscription> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateSubscription<'_>,
+    ) -> C::Response<Subscription> {
         client.post_form("/subscriptions", &params)
     }
 
     /// Retrieves the subscription with the given ID.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &SubscriptionId,
         expand: &[&str],
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.get_query(&format!("/subscriptions/{}", id), &Expand { expand })
     }
 
@@ -194,11 +200,11 @@ impl Subscription {
     ///
     /// When changing plans or quantities, we will optionally prorate the price we charge next month to make up for any price changes.
     /// To preview how the proration will be calculated, use the [upcoming invoice](https://stripe.com/docs/api#upcoming_invoice) endpoint.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SubscriptionId,
         params: UpdateSubscription<'_>,
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.post_form(&format!("/subscriptions/{}", id), &params)
     }
 
@@ -210,7 +216,10 @@ impl Subscription {
     /// This is intended to prevent unexpected payment attempts after the customer has canceled a subscription.
     /// However, you can resume automatic collection of the invoices manually after subscription cancellation to have us proceed.
     /// Or, you could check for unpaid invoices before allowing the customer to cancel the subscription at all.
-    pub fn delete(client: &Client, id: &SubscriptionId) -> Response<Deleted<SubscriptionId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &SubscriptionId,
+    ) -> C::Response<Deleted<SubscriptionId>> {
         client.delete(&format!("/subscriptions/{}", id))
     }
 }
@@ -643,18 +652,30 @@ pub struct UpdateSubscriptionItems {
 }
 
 /// An enum representing the possible values of an `Subscription`'s `billing` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SubscriptionBilling {
     ChargeAutomatically,
     SendInvoice,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SubscriptionBilling {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SubscriptionBilling::ChargeAutomatically => "charge_automatically",
             SubscriptionBilling::SendInvoice => "send_invoice",
+            SubscriptionBilling::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SubscriptionBilling {
+    fn from(value: &str) -> Self {
+        match value {
+            "charge_automatically" => SubscriptionBilling::ChargeAutomatically,
+            "send_invoice" => SubscriptionBilling::SendInvoice,
+            _ => SubscriptionBilling::Unknown(value.to_string()),
         }
     }
 }
@@ -671,19 +692,33 @@ impl std::fmt::Display for SubscriptionBilling {
     }
 }
 
+serde_string_enum!(SubscriptionBilling);
+
 /// An enum representing the possible values of an `UpdateSubscription`'s `billing_cycle_anchor` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SubscriptionBillingCycleAnchor {
     Now,
     Unchanged,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SubscriptionBillingCycleAnchor {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SubscriptionBillingCycleAnchor::Now => "now",
             SubscriptionBillingCycleAnchor::Unchanged => "unchanged",
+            SubscriptionBillingCycleAnchor::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SubscriptionBillingCycleAnchor {
+    fn from(value: &str) -> Self {
+        match value {
+            "now" => SubscriptionBillingCycleAnchor::Now,
+            "unchanged" => SubscriptionBillingCycleAnchor::Unchanged,
+            _ => SubscriptionBillingCycleAnchor::Unknown(value.to_string()),
         }
     }
 }
@@ -700,9 +735,10 @@ impl std::fmt::Display for SubscriptionBillingCycleAnchor {
     }
 }
 
+serde_string_enum!(SubscriptionBillingCycleAnchor);
+
 /// An enum representing the possible values of an `Subscription`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SubscriptionStatus {
     Active,
     Canceled,
@@ -711,10 +747,12 @@ pub enum SubscriptionStatus {
     PastDue,
     Trialing,
     Unpaid,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SubscriptionStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SubscriptionStatus::Active => "active",
             SubscriptionStatus::Canceled => "canceled",
@@ -723,6 +761,22 @@ impl SubscriptionStatus {
             SubscriptionStatus::PastDue => "past_due",
             SubscriptionStatus::Trialing => "trialing",
             SubscriptionStatus::Unpaid => "unpaid",
+            SubscriptionStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SubscriptionStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "active" => SubscriptionStatus::Active,
+            "canceled" => SubscriptionStatus::Canceled,
+            "incomplete" => SubscriptionStatus::Incomplete,
+            "incomplete_expired" => SubscriptionStatus::IncompleteExpired,
+            "past_due" => SubscriptionStatus::PastDue,
+            "trialing" => SubscriptionStatus::Trialing,
+            "unpaid" => SubscriptionStatus::Unpaid,
+            _ => SubscriptionStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -739,9 +793,10 @@ impl std::fmt::Display for SubscriptionStatus {
     }
 }
 
+serde_string_enum!(SubscriptionStatus);
+
 /// An enum representing the possible values of an `ListSubscriptions`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SubscriptionStatusFilter {
     Active,
     All,
@@ -752,10 +807,12 @@ pub enum SubscriptionStatusFilter {
     PastDue,
     Trialing,
     Unpaid,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl SubscriptionStatusFilter {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             SubscriptionStatusFilter::Active => "active",
             SubscriptionStatusFilter::All => "all",
@@ -766,6 +823,24 @@ impl SubscriptionStatusFilter {
             SubscriptionStatusFilter::PastDue => "past_due",
             SubscriptionStatusFilter::Trialing => "trialing",
             SubscriptionStatusFilter::Unpaid => "unpaid",
+            SubscriptionStatusFilter::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for SubscriptionStatusFilter {
+    fn from(value: &str) -> Self {
+        match value {
+            "active" => SubscriptionStatusFilter::Active,
+            "all" => SubscriptionStatusFilter::All,
+            "canceled" => SubscriptionStatusFilter::Canceled,
+            "ended" => SubscriptionStatusFilter::Ended,
+            "incomplete" => SubscriptionStatusFilter::Incomplete,
+            "incomplete_expired" => SubscriptionStatusFilter::IncompleteExpired,
+            "past_due" => SubscriptionStatusFilter::PastDue,
+            "trialing" => SubscriptionStatusFilter::Trialing,
+            "unpaid" => SubscriptionStatusFilter::Unpaid,
+            _ => SubscriptionStatusFilter::Unknown(value.to_string()),
         }
     }
 }
@@ -781,3 +856,5 @@ impl std::fmt::Display for SubscriptionStatusFilter {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(SubscriptionStatusFilter);
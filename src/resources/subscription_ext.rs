@@ -1,4 +1,4 @@
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::SubscriptionId;
 use crate::resources::Subscription;
 use serde_derive::Serialize;
@@ -19,11 +19,11 @@ impl Subscription {
     /// Cancels a subscription.
     ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         subscription_id: &SubscriptionId,
         params: CancelSubscription,
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
     }
 }
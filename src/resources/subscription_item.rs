@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{PlanId, SubscriptionId, SubscriptionItemId};
 use crate::params::{Deleted, Expand, List, Metadata, Object, Timestamp};
 use crate::resources::{Plan, SubscriptionItemBillingThresholds, TaxRate};
@@ -56,38 +56,38 @@ pub struct SubscriptionItem {
 
 impl SubscriptionItem {
     /// Returns a list of your subscription items for a given subscription.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: ListSubscriptionItems<'_>,
-    ) -> Response<List<SubscriptionItem>> {
-        client.get_query("/subscription_items", &params)
+    ) -> C::Response<List<SubscriptionItem>> {
+        List::get_query(client, "/subscription_items", &params)
     }
 
     /// Retrieves the invoice item with the given ID.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
         expand: &[&str],
-    ) -> Response<SubscriptionItem> {
+    ) -> C::Response<SubscriptionItem> {
         client.get_query(&format!("/subscription_items/{}", id), &Expand { expand })
     }
 
     /// Updates the plan or quantity of an item on a current subscription.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
         params: UpdateSubscriptionItem<'_>,
-    ) -> Response<SubscriptionItem> {
+    ) -> C::Response<SubscriptionItem> {
         client.post_form(&format!("/subscription_items/{}", id), &params)
     }
 
     /// Deletes an item from the subscription.
     ///
     /// Removing a subscription item from a subscription will not cancel the subscription.
-    pub fn delete(
-        client: &Client,
+    pub fn delete<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
-    ) -> Response<Deleted<SubscriptionItemId>> {
+    ) -> C::Response<Deleted<SubscriptionItemId>> {
         client.delete(&format!("/subscription_items/{}", id))
     }
 }
@@ -73,22 +73,36 @@ pub struct TaxIdVerification {
 }
 
 /// An enum representing the possible values of an `TaxId`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaxIdType {
     AuAbn,
     EuVat,
     NzGst,
     Unknown,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Other(String),
 }
 
 impl TaxIdType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdType::AuAbn => "au_abn",
             TaxIdType::EuVat => "eu_vat",
             TaxIdType::NzGst => "nz_gst",
             TaxIdType::Unknown => "unknown",
+            TaxIdType::Other(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TaxIdType {
+    fn from(value: &str) -> Self {
+        match value {
+            "au_abn" => TaxIdType::AuAbn,
+            "eu_vat" => TaxIdType::EuVat,
+            "nz_gst" => TaxIdType::NzGst,
+            "unknown" => TaxIdType::Unknown,
+            _ => TaxIdType::Other(value.to_string()),
         }
     }
 }
@@ -105,23 +119,39 @@ impl std::fmt::Display for TaxIdType {
     }
 }
 
+serde_string_enum!(TaxIdType);
+
 /// An enum representing the possible values of an `TaxIdVerification`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaxIdVerificationStatus {
     Pending,
     Unavailable,
     Unverified,
     Verified,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TaxIdVerificationStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdVerificationStatus::Pending => "pending",
             TaxIdVerificationStatus::Unavailable => "unavailable",
             TaxIdVerificationStatus::Unverified => "unverified",
             TaxIdVerificationStatus::Verified => "verified",
+            TaxIdVerificationStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TaxIdVerificationStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "pending" => TaxIdVerificationStatus::Pending,
+            "unavailable" => TaxIdVerificationStatus::Unavailable,
+            "unverified" => TaxIdVerificationStatus::Unverified,
+            "verified" => TaxIdVerificationStatus::Verified,
+            _ => TaxIdVerificationStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -137,3 +167,5 @@ impl std::fmt::Display for TaxIdVerificationStatus {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TaxIdVerificationStatus);
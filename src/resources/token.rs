@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{CustomerId, TokenId};
 use crate::params::{Expand, Object, Timestamp};
 use crate::resources::{BankAccount, BusinessType, Card, CompanyParams, PersonParams};
@@ -47,12 +47,16 @@ impl Token {
     /// This token can be used with any API method in place of a bank account dictionary.
     ///
     /// This token can be used only once, by attaching it to a [Custom account](https://stripe.com/docs/api#accounts).
-    pub fn create(client: &Client, params: CreateToken<'_>) -> Response<Token> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateToken<'_>) -> C::Response<Token> {
         client.post_form("/tokens", &params)
     }
 
     /// Retrieves the token with the given ID.
-    pub fn retrieve(client: &Client, id: &TokenId, expand: &[&str]) -> Response<Token> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TokenId,
+        expand: &[&str],
+    ) -> C::Response<Token> {
         client.get_query(&format!("/tokens/{}", id), &Expand { expand })
     }
 }
@@ -124,22 +128,36 @@ pub struct CreateTokenPii {
 }
 
 /// An enum representing the possible values of an `Token`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TokenType {
     Account,
     BankAccount,
     Card,
     Pii,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TokenType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TokenType::Account => "account",
             TokenType::BankAccount => "bank_account",
             TokenType::Card => "card",
             TokenType::Pii => "pii",
+            TokenType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TokenType {
+    fn from(value: &str) -> Self {
+        match value {
+            "account" => TokenType::Account,
+            "bank_account" => TokenType::BankAccount,
+            "card" => TokenType::Card,
+            "pii" => TokenType::Pii,
+            _ => TokenType::Unknown(value.to_string()),
         }
     }
 }
@@ -155,3 +173,5 @@ impl std::fmt::Display for TokenType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TokenType);
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::TopupId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Currency, Source};
@@ -83,21 +83,29 @@ pub struct Topup {
 
 impl Topup {
     /// Returns a list of top-ups.
-    pub fn list(client: &Client, params: ListTopups<'_>) -> Response<List<Topup>> {
-        client.get_query("/topups", &params)
+    pub fn list<C: StripeClient>(client: &C, params: ListTopups<'_>) -> C::Response<List<Topup>> {
+        List::get_query(client, "/topups", &params)
     }
 
     /// Retrieves the details of a top-up that has previously been created.
     ///
     /// Supply the unique top-up ID that was returned from your previous request, and Stripe will return the corresponding top-up information.
-    pub fn retrieve(client: &Client, id: &TopupId, expand: &[&str]) -> Response<Topup> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TopupId,
+        expand: &[&str],
+    ) -> C::Response<Topup> {
         client.get_query(&format!("/topups/{}", id), &Expand { expand })
     }
 
     /// Updates the metadata of a top-up.
     ///
     /// Other top-up details are not editable by design.
-    pub fn update(client: &Client, id: &TopupId, params: UpdateTopup<'_>) -> Response<Topup> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &TopupId,
+        params: UpdateTopup<'_>,
+    ) -> C::Response<Topup> {
         client.post_form(&format!("/topups/{}", id), &params)
     }
 }
@@ -201,24 +209,39 @@ impl<'a> UpdateTopup<'a> {
 }
 
 /// An enum representing the possible values of an `Topup`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TopupStatus {
     Canceled,
     Failed,
     Pending,
     Reversed,
     Succeeded,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TopupStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TopupStatus::Canceled => "canceled",
             TopupStatus::Failed => "failed",
             TopupStatus::Pending => "pending",
             TopupStatus::Reversed => "reversed",
             TopupStatus::Succeeded => "succeeded",
+            TopupStatus::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TopupStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "canceled" => TopupStatus::Canceled,
+            "failed" => TopupStatus::Failed,
+            "pending" => TopupStatus::Pending,
+            "reversed" => TopupStatus::Reversed,
+            "succeeded" => TopupStatus::Succeeded,
+            _ => TopupStatus::Unknown(value.to_string()),
         }
     }
 }
@@ -235,23 +258,39 @@ impl std::fmt::Display for TopupStatus {
     }
 }
 
+serde_string_enum!(TopupStatus);
+
 /// An enum representing the possible values of an `ListTopups`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TopupStatusFilter {
     Canceled,
     Failed,
     Pending,
     Succeeded,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TopupStatusFilter {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TopupStatusFilter::Canceled => "canceled",
             TopupStatusFilter::Failed => "failed",
             TopupStatusFilter::Pending => "pending",
             TopupStatusFilter::Succeeded => "succeeded",
+            TopupStatusFilter::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TopupStatusFilter {
+    fn from(value: &str) -> Self {
+        match value {
+            "canceled" => TopupStatusFilter::Canceled,
+            "failed" => TopupStatusFilter::Failed,
+            "pending" => TopupStatusFilter::Pending,
+            "succeeded" => TopupStatusFilter::Succeeded,
+            _ => TopupStatusFilter::Unknown(value.to_string()),
         }
     }
 }
@@ -267,3 +306,5 @@ impl std::fmt::Display for TopupStatusFilter {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TopupStatusFilter);
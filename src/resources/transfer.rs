@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::config::{Client, Response};
+use crate::config::StripeClient;
 use crate::ids::{ChargeId, TransferId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{Account, BalanceTransaction, Charge, Currency, TransferReversal};
@@ -87,32 +87,42 @@ impl Transfer {
     /// Returns a list of existing transfers sent to connected accounts.
     ///
     /// The transfers are returned in sorted order, with the most recently created transfers appearing first.
-    pub fn list(client: &Client, params: ListTransfers<'_>) -> Response<List<Transfer>> {
-        client.get_query("/transfers", &params)
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: ListTransfers<'_>,
+    ) -> C::Response<List<Transfer>> {
+        List::get_query(client, "/transfers", &params)
     }
 
     /// To send funds from your Stripe account to a connected account, you create a new transfer object.
     ///
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.
-    pub fn create(client: &Client, params: CreateTransfer<'_>) -> Response<Transfer> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTransfer<'_>,
+    ) -> C::Response<Transfer> {
         client.post_form("/transfers", &params)
     }
 
     /// Retrieves the details of an existing transfer.
     ///
     /// Supply the unique transfer ID from either a transfer creation request or the transfer list, and Stripe will return the corresponding transfer information.
-    pub fn retrieve(client: &Client, id: &TransferId, expand: &[&str]) -> Response<Transfer> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TransferId,
+        expand: &[&str],
+    ) -> C::Response<Transfer> {
         client.get_query(&format!("/transfers/{}", id), &Expand { expand })
     }
 
     /// Updates the specified transfer by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.  This request accepts only metadata as an argument.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &TransferId,
         params: UpdateTransfer<'_>,
-    ) -> Response<Transfer> {
+    ) -> C::Response<Transfer> {
         client.post_form(&format!("/transfers/{}", id), &params)
     }
 }
@@ -271,18 +281,30 @@ impl<'a> UpdateTransfer<'a> {
 }
 
 /// An enum representing the possible values of an `Transfer`'s `source_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransferSourceType {
     BankAccount,
     Card,
+    /// A value not yet supported by the library, e.g. one added by a newer API version.
+    Unknown(String),
 }
 
 impl TransferSourceType {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TransferSourceType::BankAccount => "bank_account",
             TransferSourceType::Card => "card",
+            TransferSourceType::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for TransferSourceType {
+    fn from(value: &str) -> Self {
+        match value {
+            "bank_account" => TransferSourceType::BankAccount,
+            "card" => TransferSourceType::Card,
+            _ => TransferSourceType::Unknown(value.to_string()),
         }
     }
 }
@@ -298,3 +320,5 @@ impl std::fmt::Display for TransferSourceType {
         self.as_str().fmt(f)
     }
 }
+
+serde_string_enum!(TransferSourceType);
@@ -0,0 +1,8 @@
+use crate::resources::ApiVersion;
+
+/// The version of the Stripe API which this crate's types were generated for
+/// (i.e. the version of `openapi/spec3.json`).
+///
+/// Clients send this version in the `Stripe-Version` header of every request,
+/// unless another version is set with `Client::with_api_version`.
+pub const API_VERSION: ApiVersion = ApiVersion::V2019_03_14;
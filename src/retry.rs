@@ -0,0 +1,212 @@
+use crate::error::RequestError;
+use reqwest::header::HeaderMap;
+use std::time::Duration;
+
+/// Controls if and how a client retries requests which failed for a transient reason.
+///
+/// A request is retried when the connection to Stripe failed, when Stripe responded
+/// with a `409 Conflict` (e.g. a lock timeout), a `429 Too Many Requests` rate limit
+/// error, or a `5xx` server error.  If Stripe sends a `Stripe-Should-Retry` header,
+/// its value takes precedence over those rules, and a `Retry-After` header is used
+/// as the delay before the next attempt.
+///
+/// The default policy makes a single attempt and never retries.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// let client = stripe::Client::new("sk_test_YOUR_STRIPE_SECRET").with_retry_policy(
+///     stripe::RetryPolicy {
+///         max_attempts: 3,
+///         max_delay: Duration::from_secs(5),
+///         ..Default::default()
+///     },
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts made for one request, including the first.
+    pub max_attempts: u32,
+    /// The delay before the first retry; it doubles for each subsequent retry.
+    pub base_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize each delay to between half and all of its computed value.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(2),
+            jitter: true,
+        }
+    }
+}
+
+/// The reason an attempt to make a request failed.
+pub(crate) enum Failure<'a> {
+    /// No response was received from Stripe.
+    Connection,
+    /// Stripe responded with an unsuccessful status.
+    Response { headers: &'a HeaderMap, error: &'a RequestError },
+}
+
+impl RetryPolicy {
+    /// A policy which retries a request up to `max_attempts` times in total
+    /// using the default delays.
+    pub fn exponential(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, ..Default::default() }
+    }
+
+    /// Returns how long to wait before making another attempt, or `None` if the
+    /// request should not be retried after its `attempt`-th (1-based) failure.
+    pub(crate) fn retry_delay(&self, attempt: u32, failure: &Failure<'_>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match *failure {
+            Failure::Connection => Some(self.backoff(attempt)),
+            Failure::Response { headers, error } => {
+                let should_retry = match header_str(headers, "stripe-should-retry") {
+                    Some("true") => true,
+                    Some("false") => false,
+                    _ => error.is_retryable(),
+                };
+                if !should_retry {
+                    return None;
+                }
+                let retry_after = header_str(headers, "retry-after")
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs);
+                match retry_after {
+                    Some(delay) => Some(std::cmp::min(delay, self.max_delay)),
+                    None => Some(self.backoff(attempt)),
+                }
+            }
+        }
+    }
+
+    /// The exponential delay after the `attempt`-th (1-based) failure.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let delay = self.base_delay.checked_mul(factor).unwrap_or(self.max_delay);
+        let delay = std::cmp::min(delay, self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Returns a pseudo-random number in `[0, 1)`.
+///
+/// N.B. This only needs to be unpredictable enough to spread out retries from
+///      different clients, so we borrow the randomly seeded std hasher instead
+///      of pulling in a dependency for it.
+fn random_fraction() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|t| t.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Failure, RetryPolicy};
+    use crate::error::{ErrorType, RequestError};
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use std::time::Duration;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: false,
+        }
+    }
+
+    fn error(http_status: u16) -> RequestError {
+        RequestError { http_status, ..Default::default() }
+    }
+
+    #[test]
+    fn backoff_is_exponential_and_capped() {
+        let policy = policy();
+        let delays: Vec<_> =
+            (1..5).map(|attempt| policy.retry_delay(attempt, &Failure::Connection)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_millis(100)),
+                Some(Duration::from_millis(200)),
+                Some(Duration::from_millis(300)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy { jitter: true, ..policy() };
+        for _ in 0..100 {
+            let delay = policy.retry_delay(2, &Failure::Connection).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn retries_transient_statuses() {
+        let policy = policy();
+        let headers = HeaderMap::new();
+        for &(status, expected) in
+            &[(400, false), (401, false), (402, false), (404, false), (409, true), (429, true), (500, true), (503, true)]
+        {
+            let error = error(status);
+            let failure = Failure::Response { headers: &headers, error: &error };
+            assert_eq!(policy.retry_delay(1, &failure).is_some(), expected, "status {}", status);
+        }
+
+        let error = RequestError { error_type: ErrorType::RateLimit, ..error(400) };
+        let failure = Failure::Response { headers: &headers, error: &error };
+        assert!(policy.retry_delay(1, &failure).is_some());
+    }
+
+    #[test]
+    fn honours_stripe_headers() {
+        let policy = policy();
+        let mut headers = HeaderMap::new();
+        headers.insert("stripe-should-retry", HeaderValue::from_static("false"));
+        let error = error(503);
+        let failure = Failure::Response { headers: &headers, error: &error };
+        assert_eq!(policy.retry_delay(1, &failure), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("stripe-should-retry", HeaderValue::from_static("true"));
+        headers.insert("retry-after", HeaderValue::from_static("0"));
+        let error = self::error(400);
+        let failure = Failure::Response { headers: &headers, error: &error };
+        assert_eq!(policy.retry_delay(1, &failure), Some(Duration::from_secs(0)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("60"));
+        let error = self::error(429);
+        let failure = Failure::Response { headers: &headers, error: &error };
+        assert_eq!(policy.retry_delay(1, &failure), Some(Duration::from_millis(300)));
+    }
+}
@@ -0,0 +1,395 @@
+use crate::error::{Error, ErrorResponse, RequestError};
+use crate::observer::{self, RequestLog, RequestObserver};
+use crate::params::{Headers, ResponseMeta, ResponseMetaSlot};
+use crate::resources::{ApiVersion, API_VERSION};
+use crate::retry::{Failure, RetryPolicy};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, Url};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// A request to be sent to Stripe by a `Transport`.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// The urlencoded form sent as the request body, if any.
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Returns the value of the header `name` as a string, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// A response received from Stripe by a `Transport`.
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Creates a response with a json `body`.
+    pub fn json(status: u16, body: impl Into<String>) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        HttpResponse { status, headers, body: body.into().into_bytes() }
+    }
+
+    /// Adds a header to the response, e.g. a `Request-Id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` or `value` are not valid in an http header.
+    pub fn with_header(mut self, name: &str, value: &str) -> HttpResponse {
+        let name = HeaderName::from_bytes(name.as_bytes()).expect("invalid header name");
+        self.headers.insert(name, HeaderValue::from_str(value).expect("invalid header value"));
+        self
+    }
+
+    /// Returns the value of the header `name` as a string, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Sends the requests of the blocking `Client`.
+///
+/// This is implemented for `reqwest::blocking::Client` (which the client uses by default),
+/// so a `reqwest` client configured with a custom proxy or TLS settings can be provided
+/// with `Client::with_transport`.  An error returned by a transport is treated as a
+/// connection failure, and so the request may be retried.
+#[cfg(feature = "blocking")]
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for reqwest::blocking::Client {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mut builder = self.request(request.method, request.url).headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send()?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.bytes()?.to_vec();
+        Ok(HttpResponse { status, headers, body })
+    }
+}
+
+/// A transport which records the requests it is sent and replies with canned responses,
+/// in the order they were added, so that code using a `Client` can be tested offline.
+///
+/// ```rust
+/// let transport = stripe::ReplayTransport::new();
+/// transport.push(stripe::HttpResponse::json(
+///     200,
+///     r#"{"id": "cus_123", "object": "customer", "deleted": true}"#,
+/// ));
+///
+/// let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+/// let id = "cus_123".parse().unwrap();
+/// let deleted = stripe::Customer::delete(&client, &id).unwrap();
+/// assert!(deleted.deleted);
+///
+/// let requests = transport.requests();
+/// assert_eq!(requests[0].url.path(), "/v1/customers/cus_123");
+/// ```
+///
+/// Once every response has been replayed, requests fail with `Error::Unexpected`.
+#[derive(Clone, Default)]
+pub struct ReplayTransport {
+    state: Arc<Mutex<ReplayState>>,
+}
+
+#[derive(Default)]
+struct ReplayState {
+    responses: VecDeque<HttpResponse>,
+    requests: Vec<HttpRequest>,
+}
+
+impl ReplayTransport {
+    pub fn new() -> ReplayTransport {
+        ReplayTransport::default()
+    }
+
+    /// Adds a response to reply to the next request with (after any added previously).
+    pub fn push(&self, response: HttpResponse) {
+        self.state.lock().unwrap().responses.push_back(response);
+    }
+
+    /// Returns the requests sent so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    fn reply(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mut state = self.state.lock().unwrap();
+        state.requests.push(request);
+        state.responses.pop_front().ok_or(Error::Unexpected("no response left to replay"))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        self.reply(request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::r#async::Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> crate::r#async::Response<HttpResponse> {
+        Box::pin(std::future::ready(self.reply(request)))
+    }
+}
+
+/// The `User-Agent` sent with every request, unless a `ClientBuilder` appends a suffix to it.
+pub(crate) const USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
+
+/// The settings shared by the blocking and the async `Client`, which build the requests
+/// they send and turn the responses they receive into results.
+#[derive(Clone)]
+pub(crate) struct ClientConfig {
+    pub(crate) secret_key: String,
+    pub(crate) headers: Headers,
+    pub(crate) host: String,
+    pub(crate) api_version: ApiVersion,
+    pub(crate) user_agent: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) idempotency_key: Option<String>,
+    pub(crate) response_meta: Option<ResponseMetaSlot>,
+    pub(crate) observer: Option<Arc<dyn RequestObserver>>,
+}
+
+impl ClientConfig {
+    pub(crate) fn new(scheme_host: String, secret_key: String) -> ClientConfig {
+        let host = if scheme_host.ends_with('/') {
+            format!("{}v1", scheme_host)
+        } else {
+            format!("{}/v1", scheme_host)
+        };
+        ClientConfig {
+            secret_key,
+            headers: Headers::default(),
+            host,
+            api_version: API_VERSION,
+            user_agent: USER_AGENT.into(),
+            retry_policy: RetryPolicy::default(),
+            idempotency_key: None,
+            response_meta: None,
+            observer: None,
+        }
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.host, &path[1..])
+    }
+
+    pub(crate) fn url_with_params<P: serde::Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Result<String, Error> {
+        let params = serde_qs::to_string(&params).map_err(Error::serialize)?;
+        Ok(format!("{}/{}?{}", self.host, &path[1..], params))
+    }
+
+    pub(crate) fn request(&self, method: Method, url: &str) -> Result<HttpRequest, Error> {
+        let url = Url::parse(url).map_err(Error::serialize)?;
+        let mut headers = self.headers()?;
+        if method == Method::POST {
+            // N.B. The same key is sent with every attempt so that Stripe
+            //      can recognize the retries as the same logical request.
+            if let Some(key) = self.idempotency_key() {
+                let key = HeaderValue::from_str(&key).map_err(Error::serialize)?;
+                headers.insert(HeaderName::from_static("idempotency-key"), key);
+            }
+        }
+        Ok(HttpRequest { method, url, headers, body: None })
+    }
+
+    /// Starts the attempts to send `request`.
+    pub(crate) fn exchange(&self, request: &HttpRequest) -> Exchange<'_> {
+        let log = self.observer.as_ref().map(|_| RequestLog {
+            method: request.method.to_string(),
+            path: observer::redact_path(request.url.path(), request.url.query()),
+            body: request.body.as_ref().map(|form| observer::redact(form)),
+            status: None,
+            latency: Default::default(),
+            request_id: None,
+            attempt: 0,
+        });
+        Exchange { config: self, attempt: 0, started: Instant::now(), log }
+    }
+
+    fn idempotency_key(&self) -> Option<String> {
+        match self.idempotency_key {
+            Some(ref key) => Some(key.clone()),
+            None if self.retry_policy.max_attempts > 1 => Some(Uuid::new_v4().to_string()),
+            None => None,
+        }
+    }
+
+    /// The headers sent with every request, which fail to build if the secret key, the api
+    /// version, the user agent or a header set with `with_headers` isn't a valid header value.
+    fn headers(&self) -> Result<HeaderMap, Error> {
+        let header = |value: &str| HeaderValue::from_str(value).map_err(Error::serialize);
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::USER_AGENT, header(&self.user_agent)?);
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            header(&format!("Bearer {}", self.secret_key))?,
+        );
+        let version = header(self.api_version.as_str())?;
+        headers.insert(HeaderName::from_static("stripe-version"), version);
+        if let Some(account) = &self.headers.stripe_account {
+            headers.insert(HeaderName::from_static("stripe-account"), header(account)?);
+        }
+        if let Some(client_id) = &self.headers.client_id {
+            headers.insert(HeaderName::from_static("client-id"), header(client_id)?);
+        }
+        Ok(headers)
+    }
+}
+
+/// The attempts made to send one request, reported to the client's observer.
+///
+/// A client calls `start` before sending each attempt with its transport, and `finish`
+/// with what the transport returned, until `finish` returns `Attempt::Done`.
+pub(crate) struct Exchange<'a> {
+    config: &'a ClientConfig,
+    attempt: u32,
+    started: Instant,
+    log: Option<RequestLog>,
+}
+
+/// What a client does after an attempt to send a request.
+pub(crate) enum Attempt<T> {
+    /// Return the result of the request.
+    Done(Result<T, Error>),
+    /// Wait for the delay, then send the request again.
+    Retry(Duration),
+}
+
+impl Exchange<'_> {
+    pub(crate) fn start(&mut self) {
+        self.attempt += 1;
+        self.started = Instant::now();
+    }
+
+    pub(crate) fn finish<T: DeserializeOwned>(
+        &mut self,
+        result: Result<HttpResponse, Error>,
+    ) -> Attempt<T> {
+        if let (Some(observer), Some(log)) = (&self.config.observer, &mut self.log) {
+            log.attempt = self.attempt;
+            log.latency = self.started.elapsed();
+            log.status = result.as_ref().ok().map(|response| response.status);
+            log.request_id = result
+                .as_ref()
+                .ok()
+                .and_then(|response| response.header("request-id").map(str::to_string));
+            observer.observe(log);
+        }
+        let retry_policy = &self.config.retry_policy;
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                return match retry_policy.retry_delay(self.attempt, &Failure::Connection) {
+                    Some(delay) => Attempt::Retry(delay),
+                    None => Attempt::Done(Err(err)),
+                }
+            }
+        };
+
+        let meta = ResponseMeta::new(response.status, &response.headers);
+        if !(200..300).contains(&response.status) {
+            let error = request_error(&response, meta.request_id);
+            let failure = Failure::Response { headers: &response.headers, error: &error };
+            return match retry_policy.retry_delay(self.attempt, &failure) {
+                Some(delay) => Attempt::Retry(delay),
+                None => Attempt::Done(Err(Error::from(error))),
+            };
+        }
+
+        let value = serde_json::from_slice(&response.body).map_err(Error::deserialize);
+        if let (Ok(_), Some(slot)) = (&value, &self.config.response_meta) {
+            if let Ok(mut last) = slot.lock() {
+                last.replace(meta);
+            }
+        }
+        Attempt::Done(value)
+    }
+}
+
+/// Parses the `RequestError` of an unsuccessful response.
+fn request_error(response: &HttpResponse, request_id: Option<String>) -> RequestError {
+    let mut err = serde_json::from_slice(&response.body).unwrap_or_else(|err| {
+        let mut req = ErrorResponse { error: RequestError::default() };
+        req.error.message = Some(format!("failed to deserialize error: {}", err));
+        req
+    });
+    err.error.http_status = response.status;
+    err.error.request_id = request_id;
+    err.error.should_retry = match response.header("stripe-should-retry") {
+        Some("true") => Some(true),
+        Some("false") => Some(false),
+        _ => None,
+    };
+    err.error
+}
+
+/// Serialize the form content using `serde_qs` instead of `serde_urlencoded`
+///
+/// See https://github.com/seanmonstar/reqwest/issues/274
+pub(crate) fn with_form_urlencoded<T: serde::Serialize>(
+    mut request: HttpRequest,
+    form: &T,
+) -> Result<HttpRequest, Error> {
+    let body = serde_qs::to_string(form).map_err(Error::serialize)?;
+    request.headers.insert(
+        reqwest::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/x-www-form-urlencoded"),
+    );
+    request.body = Some(body);
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{with_form_urlencoded, ClientConfig};
+    use crate::CreateCustomer;
+    use reqwest::Method;
+    use std::collections::HashMap;
+
+    #[test]
+    fn serialize_metadata() {
+        let mut metadata = HashMap::new();
+        metadata.insert("any".to_string(), "thing".to_string());
+        let mut form = CreateCustomer::new();
+        form.email = Some("jdoe@example.org");
+        form.metadata = Some(metadata);
+        let config = ClientConfig::new("https://api.stripe.com/".into(), "sk_test_123".into());
+        let result = config
+            .request(Method::POST, "https://example.example/v1/")
+            .and_then(|request| with_form_urlencoded(request, &form));
+        assert!(result.is_ok(), "Failed to build request: {:?}", result);
+        if let Ok(request) = result {
+            assert_eq!(
+                request.body.as_deref(),
+                Some("email=jdoe%40example.org&metadata[any]=thing")
+            );
+        }
+    }
+}
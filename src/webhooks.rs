@@ -0,0 +1,382 @@
+use crate::error::WebhookError;
+use crate::resources::{Event, API_VERSION};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "hyper")]
+mod hyper;
+mod router;
+
+#[cfg(feature = "axum")]
+pub use self::axum::StripeEvent;
+pub use self::router::{DedupState, EventDedup, EventRouter, InMemoryDedup, RouteError, Routed};
+
+/// The default tolerance of a `WebhookVerifier`, which is the tolerance recommended by Stripe.
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+pub struct Webhook;
+
+impl Webhook {
+    /// Verifies the `Stripe-Signature` header `sig` of `payload` with the endpoint secret
+    /// `secret`, and parses the payload as an `Event`.
+    ///
+    /// This is a shorthand for `WebhookVerifier::new(secret).construct_event(payload, sig)`.
+    pub fn construct_event(
+        payload: impl AsRef<[u8]>,
+        sig: &str,
+        secret: &str,
+    ) -> Result<Event, WebhookError> {
+        WebhookVerifier::new(secret).construct_event(payload, sig)
+    }
+
+    /// Generates the `Stripe-Signature` header which Stripe would send with `payload` at
+    /// `timestamp` to an endpoint with the secret `secret`.
+    ///
+    /// This is useful to test a webhook handler offline, e.g. with a payload from `EventBuilder`:
+    ///
+    /// ```rust
+    /// use stripe::{EventBuilder, EventObject, EventType, Webhook, WebhookVerifier};
+    ///
+    /// let object = serde_json::json!({"object": "balance", "available": [], "livemode": false, "pending": []});
+    /// let payload = EventBuilder::new(EventType::BalanceAvailable, EventObject::Unknown(object)).payload();
+    /// let header = Webhook::generate_test_header(&payload, "whsec_test", chrono::Utc::now().timestamp());
+    ///
+    /// let event = WebhookVerifier::new("whsec_test").construct_event(&payload, &header).unwrap();
+    /// assert_eq!(event.event_type, EventType::BalanceAvailable);
+    /// ```
+    pub fn generate_test_header(payload: impl AsRef<[u8]>, secret: &str, timestamp: i64) -> String {
+        let code = sign(secret, timestamp, payload.as_ref())
+            .expect("hmac accepts keys of any length")
+            .result()
+            .code();
+        let hex: String = code.iter().map(|byte| format!("{:02x}", byte)).collect();
+        format!("t={},v1={}", timestamp, hex)
+    }
+}
+
+/// Verifies that the webhook events received by an endpoint were sent by Stripe.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// # fn handle(payload: &[u8], stripe_signature: &str) -> Result<(), stripe::WebhookError> {
+/// let verifier = stripe::WebhookVerifier::new("whsec_current")
+///     // Also accept events signed with the previous secret while it's being rolled
+///     .secret("whsec_previous")
+///     .tolerance(Duration::from_secs(600));
+///
+/// let event = verifier.construct_event(payload, stripe_signature)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// An event is accepted if any `v1` signature in its `Stripe-Signature` header matches the
+/// payload signed with any of the secrets.  Signatures are compared in constant time.
+#[derive(Clone, Debug)]
+pub struct WebhookVerifier {
+    secrets: Vec<String>,
+    tolerance: Duration,
+}
+
+impl WebhookVerifier {
+    /// Creates a verifier for events signed with the endpoint secret `secret` (e.g. `whsec_...`).
+    pub fn new(secret: impl Into<String>) -> WebhookVerifier {
+        WebhookVerifier { secrets: vec![secret.into()], tolerance: DEFAULT_TOLERANCE }
+    }
+
+    /// Also accepts events signed with `secret`, e.g. while the endpoint's secret is rolled.
+    pub fn secret(mut self, secret: impl Into<String>) -> WebhookVerifier {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// Sets how old the timestamp of a signature may be, to prevent replay attacks.
+    /// Defaults to 5 minutes.
+    ///
+    /// Like Stripe's libraries, a timestamp in the future (e.g. because of clock skew)
+    /// is accepted.
+    pub fn tolerance(mut self, tolerance: Duration) -> WebhookVerifier {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Checks that `payload` was signed by Stripe with one of the secrets, where `signature`
+    /// is the value of the `Stripe-Signature` header sent with it.
+    pub fn verify(&self, payload: impl AsRef<[u8]>, signature: &str) -> Result<(), WebhookError> {
+        self.verify_at(payload.as_ref(), signature, Utc::now().timestamp())
+    }
+
+    /// Verifies the `signature` of `payload` and parses the payload as an `Event`.
+    pub fn construct_event(
+        &self,
+        payload: impl AsRef<[u8]>,
+        signature: &str,
+    ) -> Result<Event, WebhookError> {
+        self.construct_event_at(payload.as_ref(), signature, Utc::now().timestamp())
+    }
+
+    fn construct_event_at(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        current_timestamp: i64,
+    ) -> Result<Event, WebhookError> {
+        self.verify_at(payload, signature, current_timestamp)?;
+
+        let event: Event = serde_json::from_slice(payload).map_err(|err| {
+            log::warn!("failed to parse a webhook event signed by stripe: {}", err);
+            WebhookError::BadParse(err)
+        })?;
+        if event.has_api_version_mismatch() {
+            log::warn!(
+                "webhook event uses api version {} but stripe-rust expects {}; \
+                 set the api version of the webhook endpoint to match",
+                event.api_version.as_ref().map_or("", String::as_str),
+                API_VERSION.as_str(),
+            );
+        }
+        Ok(event)
+    }
+
+    fn verify_at(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        current_timestamp: i64,
+    ) -> Result<(), WebhookError> {
+        let signature = Signature::parse(signature)?;
+        let expected: Vec<Vec<u8>> = signature.v1.iter().filter_map(|v1| from_hex(v1)).collect();
+
+        // Compare the signature of the payload with each secret against every signature
+        // in the header, so that the time taken doesn't depend on which of them matched.
+        let mut verified = false;
+        for secret in &self.secrets {
+            let code = sign(secret, signature.t, payload)?.result();
+            for v1 in &expected {
+                verified |= code.is_equal(v1);
+            }
+        }
+        if !verified {
+            return Err(WebhookError::BadSignature);
+        }
+
+        let age = current_timestamp.saturating_sub(signature.t);
+        if age > 0 && age.unsigned_abs() > self.tolerance.as_secs() {
+            return Err(WebhookError::BadTimestamp(signature.t));
+        }
+        Ok(())
+    }
+}
+
+/// Computes the HMAC of `"{timestamp}.{payload}"` with SHA256, using the endpoint secret as the key.
+fn sign(secret: &str, timestamp: i64, payload: &[u8]) -> Result<Hmac<Sha256>, WebhookError> {
+    let mut mac =
+        Hmac::<Sha256>::new_varkey(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
+    mac.input(timestamp.to_string().as_bytes());
+    mac.input(b".");
+    mac.input(payload);
+    Ok(mac)
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+#[derive(Debug)]
+struct Signature<'r> {
+    t: i64,
+    v1: Vec<&'r str>,
+}
+
+impl<'r> Signature<'r> {
+    fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
+        let mut t = None;
+        let mut v1 = Vec::new();
+        for header in raw.split(',') {
+            let mut key_and_value = header.splitn(2, '=');
+            match (key_and_value.next(), key_and_value.next()) {
+                (Some("t"), Some(value)) => t = Some(value),
+                // N.B. Stripe sends a signature with each secret while a secret is being rolled
+                (Some("v1"), Some(value)) => v1.push(value),
+                _ => {}
+            }
+        }
+        let t = t.ok_or(WebhookError::BadSignature)?;
+        if v1.is_empty() {
+            return Err(WebhookError::BadSignature);
+        }
+        Ok(Signature { t: t.parse::<i64>().map_err(WebhookError::BadHeader)?, v1 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Signature, Webhook, WebhookVerifier};
+    use crate::error::WebhookError;
+    use std::time::Duration;
+
+    /// Returns the `v1` signature of `payload` signed with `secret`.
+    fn v1(secret: &str, timestamp: i64, payload: &str) -> String {
+        let header = Webhook::generate_test_header(payload, secret, timestamp);
+        header.splitn(2, ",v1=").nth(1).unwrap().to_string()
+    }
+
+    const PAYLOAD: &str = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "created": 1533204620,
+  "data": {"object": {"object": "balance", "available": [], "livemode": false, "pending": []}},
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "balance.available"
+}"#;
+
+    #[test]
+    fn test_signature_parse() {
+        let raw_signature =
+            "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd";
+        let signature = Signature::parse(raw_signature).unwrap();
+        assert_eq!(signature.t, 1492774577);
+        assert_eq!(
+            signature.v1,
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
+        );
+
+        let raw_signature_with_test_mode = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
+        let signature = Signature::parse(raw_signature_with_test_mode).unwrap();
+        assert_eq!(signature.t, 1492774577);
+        assert_eq!(
+            signature.v1,
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
+        );
+
+        let raw_signature_while_rolling = "t=1492774577,v1=5257a869,v1=6ffbb59b";
+        let signature = Signature::parse(raw_signature_while_rolling).unwrap();
+        assert_eq!(signature.v1, vec!["5257a869", "6ffbb59b"]);
+
+        assert!(Signature::parse("t=1492774577").is_err());
+    }
+
+    #[test]
+    fn test_webhook_construct_event() {
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "account": "acct_123",
+  "api_version": "2017-05-25",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "ii_123",
+      "object": "invoiceitem",
+      "amount": 1000,
+      "currency": "usd",
+      "customer": "cus_123",
+      "date": 1533204620,
+      "description": "Test Invoice Item",
+      "discountable": false,
+      "invoice": "in_123",
+      "livemode": false,
+      "metadata": {},
+      "period": {
+        "start": 1533204620,
+        "end": 1533204620
+      },
+      "plan": null,
+      "proration": false,
+      "quantity": null,
+      "subscription": null
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "request": {
+    "id": "req_123",
+    "idempotency_key": "idempotency-key-123"
+  },
+  "type": "invoiceitem.created"
+}
+"#;
+        let event_timestamp = 1533204620;
+        let secret = "webhook_secret".to_string();
+        let signature = format!("t={},v1=f0bdba6d4eacbd8ad8a3bbadd7248e633ec1477f7899c124c51b39405fa36613,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
+
+        let event = WebhookVerifier::new(secret)
+            .construct_event_at(payload.as_bytes(), &signature, event_timestamp)
+            .expect("Failed to construct event");
+
+        assert_eq!(event.event_type, crate::EventType::InvoiceItemCreated);
+        assert_eq!(event.data.object.object(), "invoiceitem");
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "invoiceitem.created");
+        assert_eq!(json["data"]["object"]["object"], "invoiceitem");
+        assert_eq!(event.api_version.as_deref(), Some("2017-05-25"));
+        assert!(event.has_api_version_mismatch());
+        assert_eq!(event.id.as_str(), "evt_123");
+        assert_eq!(event.account.as_ref().map(|id| id.as_str()), Some("acct_123"));
+        assert_eq!(event.created, 1533204620);
+        assert_eq!(event.pending_webhooks, 1);
+        let request = event.request.expect("expected a request");
+        assert_eq!(request.id.as_deref(), Some("req_123"));
+        assert_eq!(request.idempotency_key.as_deref(), Some("idempotency-key-123"));
+        assert!(event.data.previous_attributes.is_none());
+    }
+
+    #[test]
+    fn test_verify_with_rolled_secrets() {
+        let timestamp = 1533204620;
+        let signature = format!(
+            "t={},v1={},v1={}",
+            timestamp,
+            v1("whsec_new", timestamp, PAYLOAD),
+            v1("whsec_old", timestamp, PAYLOAD),
+        );
+
+        let verifier = WebhookVerifier::new("whsec_old");
+        assert!(verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp).is_ok());
+
+        let verifier = WebhookVerifier::new("whsec_other").secret("whsec_new");
+        assert!(verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp).is_ok());
+
+        let verifier = WebhookVerifier::new("whsec_other");
+        match verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp) {
+            Err(WebhookError::BadSignature) => {}
+            other => panic!("expected a bad signature, got {:?}", other),
+        }
+
+        let tampered = PAYLOAD.replace("evt_123", "evt_456");
+        let verifier = WebhookVerifier::new("whsec_old").secret("whsec_new");
+        match verifier.verify_at(tampered.as_bytes(), &signature, timestamp) {
+            Err(WebhookError::BadSignature) => {}
+            other => panic!("expected a bad signature, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_verify_tolerance() {
+        let timestamp = 1533204620;
+        let signature = Webhook::generate_test_header(PAYLOAD, "whsec_123", timestamp);
+
+        let verifier = WebhookVerifier::new("whsec_123");
+        assert!(verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp + 300).is_ok());
+        match verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp + 301) {
+            Err(WebhookError::BadTimestamp(t)) => assert_eq!(t, timestamp),
+            other => panic!("expected a bad timestamp, got {:?}", other),
+        }
+
+        // A timestamp in the future is accepted
+        assert!(verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp - 3600).is_ok());
+
+        let verifier = verifier.tolerance(Duration::from_secs(3600));
+        assert!(verifier.verify_at(PAYLOAD.as_bytes(), &signature, timestamp + 3600).is_ok());
+        let event = verifier.construct_event_at(PAYLOAD.as_bytes(), &signature, timestamp).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+    }
+}
@@ -0,0 +1,44 @@
+//! Receiving webhook events in an `axum` handler.
+
+use super::WebhookVerifier;
+use crate::resources::Event;
+use axum::extract::{FromRef, FromRequest};
+use axum::response::{IntoResponse, Response};
+use hyper::{Body, Request};
+
+/// An `Event` received by a webhook endpoint, which an `axum` handler can take as an argument
+/// to have the request verified with the `WebhookVerifier` of the router's state.
+///
+/// ```rust,no_run
+/// use axum::routing::post;
+/// use axum::Router;
+/// use stripe::{StripeEvent, WebhookVerifier};
+///
+/// async fn webhook(StripeEvent(event): StripeEvent) {
+///     println!("{}", event.event_type);
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/webhook", post(webhook))
+///     .with_state(WebhookVerifier::new("whsec_test"));
+/// ```
+///
+/// A request which fails to verify is rejected with `WebhookError::to_response`.
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+#[axum::async_trait]
+impl<S> FromRequest<S, Body> for StripeEvent
+where
+    WebhookVerifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+        match WebhookVerifier::from_ref(state).receive(request).await {
+            Ok(event) => Ok(StripeEvent(event)),
+            Err(err) => Err(err.to_response().into_response()),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Receiving webhook events with `hyper`, or with another framework built on the
+//! `http` crate's `Request` (e.g. `axum` or `warp`).
+
+use super::WebhookVerifier;
+use crate::error::WebhookError;
+use crate::resources::Event;
+use hyper::body::HttpBody;
+use hyper::header::CONTENT_LENGTH;
+use hyper::{Body, Request, Response};
+
+/// The largest request body read by `WebhookVerifier::receive` (1 MiB), far larger than any
+/// event sent by Stripe.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+impl WebhookVerifier {
+    /// Verifies the `Stripe-Signature` header of a request received by a webhook endpoint,
+    /// whose body has already been read, and parses the body as an `Event`.
+    ///
+    /// N.B. A request without a `Stripe-Signature` header fails with `WebhookError::BadSignature`.
+    pub fn verify_request<B: AsRef<[u8]>>(
+        &self,
+        request: &Request<B>,
+    ) -> Result<Event, WebhookError> {
+        let signature =
+            request.headers().get("Stripe-Signature").and_then(|value| value.to_str().ok());
+        self.construct_event(request.body(), signature.unwrap_or_default())
+    }
+
+    /// Reads the body of a request received by a webhook endpoint, verifies its signature
+    /// and parses it as an `Event`.
+    ///
+    /// A body larger than 1 MiB fails with `WebhookError::BadBody`, without being read further.
+    ///
+    /// ```rust
+    /// use hyper::{Body, Request, Response};
+    ///
+    /// async fn webhook(verifier: &stripe::WebhookVerifier, request: Request<Body>) -> Response<Body> {
+    ///     match verifier.receive(request).await {
+    ///         Ok(event) => {
+    ///             // Handle the event
+    ///             Response::new(Body::empty())
+    ///         }
+    ///         Err(err) => err.to_response(),
+    ///     }
+    /// }
+    /// ```
+    pub async fn receive(&self, request: Request<Body>) -> Result<Event, WebhookError> {
+        let (parts, mut body) = request.into_parts();
+        let too_large = || WebhookError::BadBody("request body is larger than 1 MiB".into());
+
+        let content_length = parts.headers.get(CONTENT_LENGTH);
+        let content_length = content_length.and_then(|value| value.to_str().ok()?.parse().ok());
+        if content_length.is_some_and(|length: u64| length > MAX_BODY_SIZE as u64) {
+            return Err(too_large());
+        }
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk.map_err(|err| WebhookError::BadBody(Box::new(err)))?;
+            if bytes.len() + chunk.len() > MAX_BODY_SIZE {
+                return Err(too_large());
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        self.verify_request(&Request::from_parts(parts, bytes))
+    }
+}
+
+impl WebhookError {
+    /// Returns the response a webhook endpoint should send for this error, with the
+    /// `status_code` of the error and a plain text description of it.
+    pub fn to_response(&self) -> Response<Body> {
+        Response::builder()
+            .status(self.status_code())
+            .body(Body::from(self.to_string()))
+            .expect("the status of a webhook error is valid")
+    }
+}
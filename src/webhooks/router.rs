@@ -0,0 +1,318 @@
+use crate::ids::EventId;
+use crate::resources::{Event, EventObject, EventType, FromEventObject};
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+type Handler<E> = Box<dyn Fn(&Event) -> Result<(), E> + Send + Sync>;
+
+/// The events a handler is called with: those of its `event_type` (or of any type if `None`)
+/// whose object is accepted by `object`.
+struct Route {
+    event_type: Option<EventType>,
+    object: fn(&EventObject) -> bool,
+}
+
+impl Route {
+    fn matches(&self, event: &Event) -> bool {
+        match &self.event_type {
+            Some(event_type) if *event_type != event.event_type => false,
+            _ => (self.object)(&event.data.object),
+        }
+    }
+
+    /// Returns the route of the events (of `event_type`, if any) whose object is a `T`.
+    fn object<T: FromEventObject>(event_type: Option<EventType>) -> Route {
+        Route { event_type, object: |object| T::from_event_object(object).is_some() }
+    }
+}
+
+/// Boxes a handler of the events whose object is a `T`, for a route returned by `Route::object`.
+fn object_handler<T, E, F>(handler: F) -> Handler<E>
+where
+    T: FromEventObject,
+    F: Fn(&Event, &T) -> Result<(), E> + Send + Sync + 'static,
+{
+    Box::new(move |event| match T::from_event_object(&event.data.object) {
+        Some(object) => handler(event, object),
+        None => Ok(()),
+    })
+}
+
+/// Passes each event to the handlers registered for its type or for the type of its object,
+/// instead of matching on `event.event_type`.
+///
+/// ```rust
+/// use stripe::{Customer, Event, EventRouter, EventType, InMemoryDedup, Invoice};
+///
+/// # fn handle(event: &Event) {
+/// let router = EventRouter::<String>::new()
+///     .on(EventType::CustomerCreated, |_event: &Event, customer: &Customer| {
+///         println!("welcome {}", customer.id);
+///         Ok(())
+///     })
+///     .on_object(|event: &Event, invoice: &Invoice| {
+///         println!("{} {:?}", event.event_type, invoice.id);
+///         Ok(())
+///     })
+///     .fallback(|event| Err(format!("unexpected event {}", event.event_type)))
+///     // Skip events redelivered by Stripe which were already handled
+///     .dedup(InMemoryDedup::new(1000));
+///
+/// match router.route(event) {
+///     Ok(_) => { /* respond with 200 */ }
+///     Err(_) => { /* respond with 500, so that Stripe sends the event again */ }
+/// }
+/// # }
+/// ```
+///
+/// Every handler matching an event is called, in the order they were registered.
+pub struct EventRouter<E> {
+    handlers: Vec<(Route, Handler<E>)>,
+    fallback: Option<Handler<E>>,
+    dedup: Option<Box<dyn EventDedup>>,
+}
+
+impl<E> EventRouter<E> {
+    pub fn new() -> EventRouter<E> {
+        EventRouter { handlers: Vec::new(), fallback: None, dedup: None }
+    }
+
+    /// Calls `handler` with the events of type `event_type`.
+    pub fn on_event<F>(mut self, event_type: EventType, handler: F) -> EventRouter<E>
+    where
+        F: Fn(&Event) -> Result<(), E> + Send + Sync + 'static,
+    {
+        let route = Route { event_type: Some(event_type), object: |_| true };
+        self.handlers.push((route, Box::new(handler)));
+        self
+    }
+
+    /// Calls `handler` with the events of type `event_type` and their object, if it's a `T`.
+    pub fn on<T, F>(mut self, event_type: EventType, handler: F) -> EventRouter<E>
+    where
+        T: FromEventObject,
+        F: Fn(&Event, &T) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.handlers.push((Route::object::<T>(Some(event_type)), object_handler(handler)));
+        self
+    }
+
+    /// Calls `handler` with the events (of any type) about a `T`, and their object.
+    pub fn on_object<T, F>(mut self, handler: F) -> EventRouter<E>
+    where
+        T: FromEventObject,
+        F: Fn(&Event, &T) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.handlers.push((Route::object::<T>(None), object_handler(handler)));
+        self
+    }
+
+    /// Calls `handler` with the events which no other handler matches.
+    pub fn fallback<F>(mut self, handler: F) -> EventRouter<E>
+    where
+        F: Fn(&Event) -> Result<(), E> + Send + Sync + 'static,
+    {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Skips the events which `dedup` has already seen, e.g. events redelivered by Stripe.
+    pub fn dedup(mut self, dedup: impl EventDedup + 'static) -> EventRouter<E> {
+        self.dedup = Some(Box::new(dedup));
+        self
+    }
+
+    /// Passes `event` to each matching handler, or to the fallback if none match.
+    ///
+    /// If any handler fails, the errors of every handler are returned and the event is
+    /// forgotten by the dedup hook, so that it is routed again when Stripe redelivers it.
+    /// The dedup hook only records the event as handled once every handler succeeded; an event
+    /// redelivered while it's still being routed fails with a `RouteError` whose `in_progress`
+    /// is `true`, so that Stripe sends it again later.
+    pub fn route(&self, event: &Event) -> Result<Routed, RouteError<E>> {
+        if let Some(dedup) = &self.dedup {
+            match dedup.start(&event.id) {
+                DedupState::New => {}
+                DedupState::InProgress => {
+                    let event_id = event.id.clone();
+                    return Err(RouteError { event_id, errors: Vec::new(), in_progress: true });
+                }
+                DedupState::Done => return Ok(Routed::Duplicate),
+            }
+        }
+
+        let mut errors = Vec::new();
+        let mut handled = false;
+        for (route, handler) in &self.handlers {
+            if route.matches(event) {
+                handled = true;
+                errors.extend(handler(event).err());
+            }
+        }
+        let routed = match &self.fallback {
+            _ if handled => Routed::Handled,
+            Some(fallback) => {
+                errors.extend(fallback(event).err());
+                Routed::Fallback
+            }
+            None => Routed::Unhandled,
+        };
+
+        if let Some(dedup) = &self.dedup {
+            if errors.is_empty() {
+                dedup.finish(&event.id);
+            } else {
+                dedup.remove(&event.id);
+            }
+        }
+        if errors.is_empty() {
+            return Ok(routed);
+        }
+        Err(RouteError { event_id: event.id.clone(), errors, in_progress: false })
+    }
+}
+
+impl<E> Default for EventRouter<E> {
+    fn default() -> Self {
+        EventRouter::new()
+    }
+}
+
+/// How an event was routed by `EventRouter::route`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Routed {
+    /// The event was passed to the handlers which match it.
+    Handled,
+    /// No handler matches the event, so it was passed to the fallback.
+    Fallback,
+    /// No handler matches the event and there is no fallback.
+    Unhandled,
+    /// The event was skipped, because the dedup hook had already seen it handled.
+    Duplicate,
+}
+
+/// The errors returned by the handlers of an event.
+#[derive(Debug)]
+pub struct RouteError<E> {
+    pub event_id: EventId,
+    /// The error of each handler which failed, in the order the handlers were called.
+    pub errors: Vec<E>,
+    /// Whether the event wasn't routed because it's still being routed for an earlier
+    /// delivery.  No handler was called, and `errors` is empty.
+    pub in_progress: bool,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for RouteError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.in_progress {
+            return write!(f, "event {} is already being routed", self.event_id);
+        }
+        write!(f, "{} handler(s) failed for event {}", self.errors.len(), self.event_id)?;
+        for err in &self.errors {
+            write!(f, "; {}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for RouteError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.errors.first().map(|err| err as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Remembers which events were routed by an `EventRouter`, keyed on the event id, so that
+/// an event delivered more than once is only handled once.
+pub trait EventDedup: Send + Sync {
+    /// Records that the event `id` is being routed, returning how it was already seen.
+    fn start(&self, id: &EventId) -> DedupState;
+
+    /// Records that the event `id` was handled, once every handler succeeded.
+    fn finish(&self, id: &EventId);
+
+    /// Forgets the event `id`, e.g. because handling it failed.
+    fn remove(&self, id: &EventId);
+}
+
+/// How an `EventDedup` had already seen an event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DedupState {
+    /// The event wasn't seen, or was forgotten.
+    New,
+    /// The event is being routed, e.g. for an earlier delivery still being handled.
+    InProgress,
+    /// The event was handled.
+    Done,
+}
+
+impl<D: EventDedup + ?Sized> EventDedup for Arc<D> {
+    fn start(&self, id: &EventId) -> DedupState {
+        (**self).start(id)
+    }
+
+    fn finish(&self, id: &EventId) {
+        (**self).finish(id)
+    }
+
+    fn remove(&self, id: &EventId) {
+        (**self).remove(id)
+    }
+}
+
+/// Remembers the ids of the most recent events in memory.
+///
+/// N.B. Only events redelivered to the same process are skipped, so a deployment with
+/// several processes needs an `EventDedup` backed by a shared store (e.g. a database).
+pub struct InMemoryDedup {
+    capacity: usize,
+    seen: Mutex<SeenEvents>,
+}
+
+#[derive(Default)]
+struct SeenEvents {
+    handled: HashSet<EventId>,
+    order: VecDeque<EventId>,
+    in_progress: HashSet<EventId>,
+}
+
+impl InMemoryDedup {
+    /// Creates a dedup hook which remembers the last `capacity` events handled.
+    pub fn new(capacity: usize) -> InMemoryDedup {
+        InMemoryDedup { capacity, seen: Mutex::new(SeenEvents::default()) }
+    }
+}
+
+impl EventDedup for InMemoryDedup {
+    fn start(&self, id: &EventId) -> DedupState {
+        let mut seen = self.seen.lock().unwrap();
+        if seen.handled.contains(id) {
+            DedupState::Done
+        } else if !seen.in_progress.insert(id.clone()) {
+            DedupState::InProgress
+        } else {
+            DedupState::New
+        }
+    }
+
+    fn finish(&self, id: &EventId) {
+        let mut seen = self.seen.lock().unwrap();
+        seen.in_progress.remove(id);
+        if !seen.handled.insert(id.clone()) {
+            return;
+        }
+        seen.order.push_back(id.clone());
+        while seen.order.len() > self.capacity {
+            if let Some(oldest) = seen.order.pop_front() {
+                seen.handled.remove(&oldest);
+            }
+        }
+    }
+
+    fn remove(&self, id: &EventId) {
+        let mut seen = self.seen.lock().unwrap();
+        seen.in_progress.remove(id);
+        if seen.handled.remove(id) {
+            seen.order.retain(|seen| seen != id);
+        }
+    }
+}
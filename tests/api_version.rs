@@ -0,0 +1,55 @@
+use stripe::{ApiVersion, HttpResponse, ReplayTransport};
+
+const COUPON: &str = r#"{"id": "co_123", "object": "coupon"}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn sends_the_pinned_version_unless_overridden() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, COUPON));
+    transport.push(HttpResponse::json(200, COUPON));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+    let id = "co_123".parse().unwrap();
+
+    stripe::Coupon::retrieve(&client, &id, &[]).unwrap();
+    stripe::Coupon::retrieve(&client.with_api_version(ApiVersion::V2019_02_19), &id, &[]).unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].header("stripe-version"), Some(stripe::API_VERSION.as_str()));
+    assert_eq!(requests[0].header("stripe-version"), Some("2019-03-14"));
+    assert_eq!(requests[1].header("stripe-version"), Some("2019-02-19"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn fails_to_send_an_invalid_version() {
+    let transport = ReplayTransport::new();
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+    let client = client.with_api_version(ApiVersion::Unknown("2019-03-14\n".into()));
+    let id = "co_123".parse().unwrap();
+
+    match stripe::Coupon::retrieve(&client, &id, &[]) {
+        Err(stripe::Error::Serialize(_)) => {}
+        other => panic!("expected a serialize error, got {:?}", other),
+    }
+    assert!(transport.requests().is_empty());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_sends_the_pinned_version_unless_overridden() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, COUPON));
+    transport.push(HttpResponse::json(200, COUPON));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+    let id = "co_123".parse().unwrap();
+
+    stripe::Coupon::retrieve(&client, &id, &[]).await.unwrap();
+    stripe::Coupon::retrieve(&client.with_api_version(ApiVersion::V2019_02_19), &id, &[])
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].header("stripe-version"), Some("2019-03-14"));
+    assert_eq!(requests[1].header("stripe-version"), Some("2019-02-19"));
+}
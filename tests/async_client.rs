@@ -0,0 +1,99 @@
+#![cfg(feature = "async")]
+
+mod server;
+
+use server::{Reply, Server};
+use std::time::Duration;
+
+const DELETED: &str = r#"{"id": "cus_123", "object": "customer", "deleted": true}"#;
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+const INVALID_REQUEST_ERROR: &str =
+    r#"{"error": {"type": "invalid_request_error", "message": "bad request"}}"#;
+const FIRST_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_1"}, {"id": "co_2"}],
+  "has_more": true,
+  "url": "/v1/coupons"
+}"#;
+const LAST_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_3"}],
+  "has_more": false,
+  "url": "/v1/coupons"
+}"#;
+
+fn client(server: &Server) -> stripe::r#async::Client {
+    stripe::r#async::Client::from_url(server.url.as_str(), "sk_test_123")
+}
+
+fn retry_policy(max_attempts: u32) -> stripe::RetryPolicy {
+    stripe::RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        jitter: false,
+    }
+}
+
+#[tokio::test]
+async fn sends_headers_to_a_custom_host() {
+    let server = Server::serve(vec![Reply::Json(200, vec![("Request-Id", "req_123")], DELETED)]);
+    let client = client(&server).with_headers(stripe::Headers {
+        stripe_account: Some("acct_123".into()),
+        client_id: Some("ca_123".into()),
+    });
+    let id = "cus_123".parse().unwrap();
+    let (deleted, meta) =
+        client.with_response_meta(|client| stripe::Customer::delete(client, &id)).await.unwrap();
+    assert!(deleted.deleted);
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+
+    let requests = server.finish();
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[0].path, "/v1/customers/cus_123");
+    assert_eq!(requests[0].header("authorization"), Some("Bearer sk_test_123"));
+    assert_eq!(requests[0].header("stripe-account"), Some("acct_123"));
+    assert_eq!(requests[0].header("client-id"), Some("ca_123"));
+}
+
+#[tokio::test]
+async fn retries_with_the_same_idempotency_key() {
+    let server = Server::serve(vec![
+        Reply::Json(500, vec![], API_ERROR),
+        Reply::Hangup,
+        Reply::Json(400, vec![("Request-Id", "req_123")], INVALID_REQUEST_ERROR),
+    ]);
+    let client = client(&server).with_retry_policy(retry_policy(3));
+    let result = stripe::Customer::create(&client, stripe::CreateCustomer::new()).await;
+    match result {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.http_status, 400);
+            assert_eq!(err.request_id.as_deref(), Some("req_123"));
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+
+    let requests = server.finish();
+    assert_eq!(requests.len(), 3);
+    let key = requests[0].header("idempotency-key");
+    assert!(key.is_some());
+    assert!(requests.iter().all(|request| request.header("idempotency-key") == key));
+    assert!(requests.iter().all(|request| request.method == "POST" && request.body.is_empty()));
+}
+
+#[tokio::test]
+async fn gets_all_pages() {
+    let server = Server::serve(vec![
+        Reply::Json(200, vec![], FIRST_PAGE),
+        Reply::Json(200, vec![], LAST_PAGE),
+    ]);
+    let client = client(&server);
+    let list = stripe::Coupon::list(&client, stripe::ListCoupons::new()).await.unwrap();
+    let coupons = list.get_all(&client).await.unwrap();
+    let ids: Vec<_> = coupons.iter().map(|coupon| coupon.id.as_str()).collect();
+    assert_eq!(ids, vec!["co_1", "co_2", "co_3"]);
+
+    let requests = server.finish();
+    assert_eq!(requests[0].path, "/v1/coupons?");
+    assert_eq!(requests[1].path, "/v1/coupons?limit=2&starting_after=co_2");
+}
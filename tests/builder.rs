@@ -0,0 +1,79 @@
+mod server;
+
+use server::{Reply, Server};
+use std::time::Duration;
+
+const COUPON: &str = r#"{"id": "co_123", "object": "coupon"}"#;
+
+fn retrieve_coupon<C: stripe::StripeClient>(client: &C) -> C::Response<stripe::Coupon> {
+    let id = "co_123".parse().unwrap();
+    stripe::Coupon::retrieve(client, &id, &[])
+}
+
+fn assert_user_agent(request: &server::Request) {
+    let user_agent = request.header("user-agent").unwrap();
+    assert!(user_agent.starts_with("Stripe/v1 RustBindings/"), "user agent: {}", user_agent);
+    assert!(user_agent.ends_with(" my-app/1.0"), "user agent: {}", user_agent);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn builds_a_blocking_client() {
+    let server = Server::serve(vec![Reply::Json(200, vec![], COUPON)]);
+    let client = stripe::ClientBuilder::new("sk_test_123")
+        .url(server.url.as_str())
+        .connect_timeout(Duration::from_secs(1))
+        .timeout(Duration::from_secs(5))
+        .pool_idle_timeout(Duration::from_secs(10))
+        .pool_max_idle_per_host(2)
+        .user_agent_suffix("my-app/1.0")
+        .build()
+        .unwrap();
+    let coupon = retrieve_coupon(&client).unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+
+    let requests = server.finish();
+    assert_eq!(requests[0].header("authorization"), Some("Bearer sk_test_123"));
+    assert_user_agent(&requests[0]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn times_out_a_stalled_request() {
+    let server = Server::serve(vec![Reply::Stall(Duration::from_millis(500))]);
+    let client = stripe::ClientBuilder::new("sk_test_123")
+        .url(server.url.as_str())
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    match retrieve_coupon(&client) {
+        Err(stripe::Error::Http(err)) => assert!(err.is_timeout(), "unexpected error: {}", err),
+        other => panic!("expected a http error, got {:?}", other),
+    }
+    server.finish();
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn rejects_invalid_settings() {
+    let builder = stripe::ClientBuilder::new("sk_test_123");
+    assert!(builder.clone().add_root_certificate("not a certificate").build().is_err());
+    assert!(builder.proxy("not a url").build().is_err());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn builds_an_async_client() {
+    let server = Server::serve(vec![Reply::Json(200, vec![], COUPON)]);
+    let client = stripe::ClientBuilder::new("sk_test_123")
+        .url(server.url.as_str())
+        .timeout(Duration::from_secs(5))
+        .user_agent_suffix("my-app/1.0")
+        .build_async()
+        .unwrap();
+    let coupon = retrieve_coupon(&client).await.unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+
+    let requests = server.finish();
+    assert_user_agent(&requests[0]);
+}
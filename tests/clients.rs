@@ -0,0 +1,35 @@
+#![cfg(all(feature = "blocking", feature = "async"))]
+
+mod server;
+
+use server::{Reply, Server};
+use stripe::StripeClient;
+
+const COUPON: &str = r#"{"id": "co_123", "object": "coupon"}"#;
+
+fn retrieve_coupon<C: StripeClient>(client: &C) -> C::Response<stripe::Coupon> {
+    let id = "co_123".parse().unwrap();
+    stripe::Coupon::retrieve(client, &id, &[])
+}
+
+#[test]
+fn blocking_and_async_clients_share_resource_methods() {
+    let server = Server::serve(vec![
+        Reply::Json(200, vec![], COUPON),
+        Reply::Json(200, vec![], COUPON),
+    ]);
+
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    let coupon = retrieve_coupon(&client).unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+
+    let client = stripe::r#async::Client::from_url(server.url.as_str(), "sk_test_123");
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let coupon = runtime.block_on(retrieve_coupon(&client)).unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+
+    let requests = server.finish();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path, requests[1].path);
+    assert!(requests[0].path.starts_with("/v1/coupons/co_123"));
+}
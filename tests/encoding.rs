@@ -62,6 +62,51 @@ fn serialize_range_query() {
     assert_eq!(encoded, "created[gte]=1501598702&created[lt]=1504233902&limit=3");
 }
 
+#[test]
+fn serialize_range_query_bounds() {
+    use stripe::RangeQuery;
+
+    let encode = |query: RangeQuery<i64>| urldecode(serde_qs::to_string(&query).unwrap());
+    assert_eq!(encode(RangeQuery::gt(1501598702)), "gt=1501598702");
+    assert_eq!(encode(RangeQuery::gte(1501598702)), "gte=1501598702");
+    assert_eq!(encode(RangeQuery::lt(1501598702)), "lt=1501598702");
+    assert_eq!(encode(RangeQuery::lte(1501598702)), "lte=1501598702");
+
+    assert_eq!(encode((1501598702..1504233902).into()), "gte=1501598702&lt=1504233902");
+    assert_eq!(encode((1501598702..=1504233902).into()), "gte=1501598702&lte=1504233902");
+    assert_eq!(encode((1501598702..).into()), "gte=1501598702");
+    assert_eq!(encode((..1504233902).into()), "lt=1504233902");
+    assert_eq!(encode((..=1504233902).into()), "lte=1504233902");
+}
+
+#[test]
+fn serialize_range_query_from_datetime() {
+    use chrono::{Duration, TimeZone, Utc};
+    use stripe::{ListCustomers, RangeQuery, Timestamp};
+
+    let start = Utc.with_ymd_and_hms(2017, 8, 1, 14, 45, 2).unwrap();
+    let end = Utc.with_ymd_and_hms(2017, 9, 1, 2, 45, 2).unwrap() + Duration::milliseconds(500);
+    let encode = |query: RangeQuery<Timestamp>| urldecode(serde_qs::to_string(&query).unwrap());
+    assert_eq!(encode((start..end).into()), "gte=1501598702&lt=1504233903");
+    assert_eq!(encode((start..=end).into()), "gte=1501598702&lte=1504233902");
+    assert_eq!(encode((start..).into()), "gte=1501598702");
+    assert_eq!(encode((..end).into()), "lt=1504233903");
+    assert_eq!(encode((..=end).into()), "lte=1504233902");
+
+    // A lower bound with a fraction of a second is rounded up to the next whole second
+    let later = start + Duration::milliseconds(250);
+    assert_eq!(encode((later..).into()), "gte=1501598703");
+    assert_eq!(encode((later..=end).into()), "gte=1501598703&lte=1504233902");
+
+    let mut params = ListCustomers::new();
+    params.created = Some(start.into());
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "created=1501598702");
+
+    params.created = Some((start..end).into());
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "created[gte]=1501598702&created[lt]=1504233903");
+}
+
 fn urldecode(input: String) -> String {
     input.replace("%5B", "[").replace("%5D", "]")
 }
@@ -0,0 +1,184 @@
+#![cfg(feature = "blocking")]
+#![allow(clippy::result_large_err)]
+
+use stripe::{HttpResponse, ReplayTransport};
+
+const AUTHENTICATION_REQUIRED: &str = r#"{
+  "error": {
+    "type": "card_error",
+    "code": "card_declined",
+    "decline_code": "authentication_required",
+    "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+    "message": "Your card was declined. This transaction requires authentication.",
+    "param": "payment_method",
+    "payment_intent": {
+      "id": "pi_123",
+      "object": "payment_intent",
+      "amount": 1999,
+      "capture_method": "automatic",
+      "charges": {"object": "list", "data": [], "has_more": false, "url": "/v1/charges"},
+      "client_secret": "pi_123_secret_456",
+      "confirmation_method": "manual",
+      "created": 1561000000,
+      "currency": "eur",
+      "livemode": false,
+      "metadata": {},
+      "next_action": {
+        "type": "redirect_to_url",
+        "redirect_to_url": {
+          "return_url": "https://example.com/checkout",
+          "url": "https://hooks.stripe.com/3d_secure/authenticate"
+        }
+      },
+      "payment_method_types": ["card"],
+      "status": "requires_action"
+    },
+    "source": {
+      "id": "card_123",
+      "object": "card",
+      "brand": "Visa",
+      "country": "DE",
+      "exp_month": 12,
+      "exp_year": 2030,
+      "fingerprint": "abc",
+      "funding": "credit",
+      "last4": "3184",
+      "metadata": {}
+    }
+  }
+}"#;
+
+fn confirm(response: HttpResponse) -> Result<stripe::PaymentIntent, stripe::Error> {
+    let transport = ReplayTransport::new();
+    transport.push(response);
+    let client = stripe::Client::new("sk_test_123").with_transport(transport);
+    stripe::PaymentIntent::confirm(&client, "pi_123", Default::default())
+}
+
+#[test]
+fn error_details_are_parsed() {
+    let err = match confirm(HttpResponse::json(402, AUTHENTICATION_REQUIRED)) {
+        Err(stripe::Error::Stripe(err)) => err,
+        other => panic!("expected a stripe error, got {:?}", other),
+    };
+    assert_eq!(err.http_status, 402);
+    assert_eq!(err.code, Some(stripe::ErrorCode::CardDeclined));
+    assert_eq!(err.decline_code, Some(stripe::DeclineCode::AuthenticationRequired));
+    assert_eq!(err.param.as_deref(), Some("payment_method"));
+    assert_eq!(err.doc_url.as_deref(), Some("https://stripe.com/docs/error-codes/card-declined"));
+
+    let payment_intent = err.payment_intent.expect("the error has a payment intent");
+    assert_eq!(payment_intent.id.as_str(), "pi_123");
+    assert_eq!(payment_intent.client_secret.as_deref(), Some("pi_123_secret_456"));
+    let next_action = payment_intent.next_action.expect("the payment intent has a next action");
+    assert_eq!(
+        next_action.redirect_to_url.and_then(|redirect| redirect.url),
+        Some("https://hooks.stripe.com/3d_secure/authenticate".to_string())
+    );
+
+    match err.source.as_deref() {
+        Some(stripe::PaymentSource::Card(card)) => assert_eq!(card.last4.as_deref(), Some("3184")),
+        other => panic!("expected a card, got {:?}", other),
+    }
+    assert!(err.payment_method.is_none());
+    assert!(err.setup_intent.is_none());
+}
+
+#[test]
+fn minimal_error_is_parsed() {
+    let response = HttpResponse::json(400, r#"{"error": {"type": "invalid_request_error"}}"#);
+    match confirm(response) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.error_type, stripe::ErrorType::InvalidRequest);
+            assert!(err.param.is_none());
+            assert!(err.doc_url.is_none());
+            assert!(err.payment_intent.is_none());
+            assert!(err.source.is_none());
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+}
+
+#[test]
+fn partial_embedded_object_is_skipped() {
+    let body = r#"{"error": {"type": "card_error", "code": "card_declined", "message": "declined", "payment_intent": {"id": "pi_1", "object": "payment_intent"}}}"#;
+    match confirm(HttpResponse::json(402, body)) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.error_type, stripe::ErrorType::Card);
+            assert_eq!(err.code, Some(stripe::ErrorCode::CardDeclined));
+            assert_eq!(err.message.as_deref(), Some("declined"));
+            assert!(err.payment_intent.is_none());
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+}
+
+fn card_error(decline_code: &str, message: Option<&str>) -> stripe::Error {
+    let mut error = serde_json::json!({"type": "card_error", "code": "card_declined"});
+    error["decline_code"] = decline_code.into();
+    if let Some(message) = message {
+        error["message"] = message.into();
+    }
+    let body = serde_json::json!({ "error": error }).to_string();
+    confirm(HttpResponse::json(402, body)).unwrap_err()
+}
+
+#[test]
+fn card_errors_are_classified() {
+    let err = card_error("insufficient_funds", Some("Your card has insufficient funds."));
+    assert!(err.is_card_error());
+    assert!(!err.is_retryable());
+    assert!(!err.is_idempotency_conflict());
+    assert_eq!(err.user_facing_message(), Some("Your card has insufficient funds."));
+
+    // The reason of a decline which suggests fraud isn't shown to the customer
+    let err = card_error("stolen_card", Some("Your card was reported stolen."));
+    assert_eq!(err.user_facing_message(), Some("Your card was declined."));
+
+    let err = card_error("velocity_limit", None);
+    match &err {
+        stripe::Error::Stripe(err) => assert_eq!(
+            err.decline_code,
+            Some(stripe::DeclineCode::Unknown("velocity_limit".into()))
+        ),
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+    assert_eq!(err.user_facing_message(), Some("Your card was declined."));
+}
+
+#[test]
+fn other_errors_are_classified() {
+    let api_error = r#"{"error": {"type": "api_error", "message": "An unknown error occurred"}}"#;
+    let err = confirm(HttpResponse::json(500, api_error)).unwrap_err();
+    assert!(err.is_retryable());
+    assert!(!err.is_card_error());
+    assert_eq!(err.user_facing_message(), None);
+
+    // Stripe-Should-Retry takes precedence over the status
+    let response = HttpResponse::json(500, api_error).with_header("Stripe-Should-Retry", "false");
+    assert!(!confirm(response).unwrap_err().is_retryable());
+    let response = HttpResponse::json(400, r#"{"error": {"type": "invalid_request_error"}}"#)
+        .with_header("Stripe-Should-Retry", "true");
+    assert!(confirm(response).unwrap_err().is_retryable());
+
+    let in_use =
+        r#"{"error": {"type": "invalid_request_error", "code": "idempotency_key_in_use"}}"#;
+    let err = confirm(HttpResponse::json(409, in_use)).unwrap_err();
+    assert!(err.is_idempotency_conflict());
+    assert!(err.is_retryable());
+
+    let reused = r#"{"error": {"type": "idempotency_error", "message": "Keys for idempotent requests can only be used with the same parameters they were first used with."}}"#;
+    let err = confirm(HttpResponse::json(400, reused)).unwrap_err();
+    assert!(err.is_idempotency_conflict());
+    assert!(!err.is_retryable());
+
+    // An error type unknown to the library is still returned as a `RequestError`
+    let unknown = r#"{"error": {"type": "temporary_error", "message": "oops"}}"#;
+    match confirm(HttpResponse::json(400, unknown)) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.error_type, stripe::ErrorType::Unknown);
+            assert_eq!(err.message.as_deref(), Some("oops"));
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+}
@@ -0,0 +1,90 @@
+//! Checks that the fixture of an event about each type of object in `EventObject`
+//! (in `tests/fixtures/events`) is parsed as the typed variant for that object.
+
+use stripe::{Event, EventObject, EventType};
+
+fn parse(fixture: &str) -> Event {
+    let event: Event = serde_json::from_str(fixture).expect("failed to parse event");
+
+    // The object is serialized with its `object` tag
+    let json = serde_json::to_value(&event).unwrap();
+    let original: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    assert_eq!(json["data"]["object"]["object"], original["data"]["object"]["object"]);
+    event
+}
+
+macro_rules! event_object_tests {
+    ($($test:ident: $variant:ident = $event_type:ident($fixture:literal),)*) => {
+        $(
+            #[test]
+            fn $test() {
+                let event = parse(include_str!(concat!("fixtures/events/", $fixture, ".json")));
+                assert_eq!(event.event_type, EventType::$event_type);
+                match event.data.object {
+                    EventObject::$variant(_) => {}
+                    other => panic!("expected `{}`, got {:?}", stringify!($variant), other),
+                }
+            }
+        )*
+    };
+}
+
+event_object_tests! {
+    account: Account = AccountUpdated("account"),
+    application: Application = AccountApplicationDeauthorized("application"),
+    application_fee: ApplicationFee = ApplicationFeeCreated("application_fee"),
+    balance: Balance = BalanceAvailable("balance"),
+    bank_account: BankAccount = AccountExternalAccountCreated("bank_account"),
+    card: Card = CustomerSourceCreated("card"),
+    charge: Charge = ChargeSucceeded("charge"),
+    checkout_session: CheckoutSession = CheckoutSessionCompleted("checkout_session"),
+    coupon: Coupon = CouponCreated("coupon"),
+    credit_note: CreditNote = CreditNoteCreated("credit_note"),
+    customer: Customer = CustomerCreated("customer"),
+    discount: Discount = CustomerDiscountCreated("discount"),
+    dispute: Dispute = ChargeDisputeCreated("dispute"),
+    fee_refund: ApplicationFeeRefund = ApplicationFeeRefundUpdated("fee_refund"),
+    file: File = FileCreated("file"),
+    invoice: Invoice = InvoiceCreated("invoice"),
+    invoiceitem: InvoiceItem = InvoiceItemCreated("invoiceitem"),
+    issuing_authorization: IssuingAuthorization = IssuingAuthorizationCreated("issuing_authorization"),
+    issuing_card: IssuingCard = IssuingCardCreated("issuing_card"),
+    issuing_cardholder: IssuingCardholder = IssuingCardholderCreated("issuing_cardholder"),
+    issuing_dispute: IssuingDispute = IssuingDisputeCreated("issuing_dispute"),
+    issuing_settlement: IssuingSettlement = IssuingSettlementCreated("issuing_settlement"),
+    issuing_transaction: IssuingTransaction = IssuingTransactionCreated("issuing_transaction"),
+    order: Order = OrderCreated("order"),
+    order_return: OrderReturn = OrderReturnCreated("order_return"),
+    payment_intent: PaymentIntent = PaymentIntentSucceeded("payment_intent"),
+    payment_method: PaymentMethod = PaymentMethodAttached("payment_method"),
+    payout: Payout = PayoutPaid("payout"),
+    person: Person = PersonCreated("person"),
+    plan: Plan = PlanCreated("plan"),
+    product: Product = ProductCreated("product"),
+    recipient: Recipient = RecipientCreated("recipient"),
+    refund: Refund = ChargeRefundUpdated("refund"),
+    reporting_report_run: ReportingReportRun = ReportingReportRunSucceeded("reporting_report_run"),
+    reporting_report_type: ReportingReportType = ReportingReportTypeUpdated("reporting_report_type"),
+    review: Review = ReviewOpened("review"),
+    scheduled_query_run: ScheduledQueryRun = SigmaScheduledQueryRunCreated("scheduled_query_run"),
+    sku: Sku = SkuCreated("sku"),
+    source: Source = SourceChargeable("source"),
+    source_mandate_notification: SourceMandateNotification = SourceMandateNotification("source_mandate_notification"),
+    source_transaction: SourceTransaction = SourceTransactionCreated("source_transaction"),
+    subscription: Subscription = CustomerSubscriptionCreated("subscription"),
+    tax_id: TaxId = CustomerTaxIdCreated("tax_id"),
+    tax_rate: TaxRate = TaxRateCreated("tax_rate"),
+    topup: Topup = TopupSucceeded("topup"),
+    transfer: Transfer = TransferCreated("transfer"),
+}
+
+#[test]
+fn malformed_object_is_kept_as_json() {
+    let event = parse(include_str!("fixtures/events/coupon_malformed.json"));
+    assert_eq!(event.event_type, EventType::CouponCreated);
+    assert_eq!(event.data.object.object(), "coupon");
+    match event.data.object {
+        EventObject::Unknown(json) => assert_eq!(json["times_redeemed"], "many"),
+        other => panic!("expected `Unknown`, got {:?}", other),
+    }
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "webhooks")]
+
+use std::sync::{Arc, Mutex};
+use stripe::{
+    Balance, Coupon, Event, EventBuilder, EventObject, EventRouter, EventType, InMemoryDedup,
+    Routed,
+};
+
+fn event(id: &str, event_type: EventType, object: serde_json::Value) -> Event {
+    let payload = EventBuilder::new(event_type, EventObject::Unknown(object))
+        .id(id.parse().unwrap())
+        .payload();
+    serde_json::from_str(&payload).unwrap()
+}
+
+fn balance() -> serde_json::Value {
+    serde_json::json!({"object": "balance", "available": [], "livemode": false, "pending": []})
+}
+
+fn coupon() -> serde_json::Value {
+    serde_json::json!({
+        "id": "SUMMER",
+        "object": "coupon",
+        "created": 1561000000,
+        "duration": "forever",
+        "livemode": false,
+        "metadata": {},
+        "percent_off": 25.0,
+        "times_redeemed": 0,
+        "valid": true
+    })
+}
+
+type Calls = Arc<Mutex<Vec<String>>>;
+
+fn record(calls: &Calls, call: String) {
+    calls.lock().unwrap().push(call);
+}
+
+#[test]
+fn test_events_are_routed_to_typed_handlers() {
+    let calls = Calls::default();
+    let (c1, c2, c3, c4) = (calls.clone(), calls.clone(), calls.clone(), calls.clone());
+    let router = EventRouter::<String>::new()
+        .on(EventType::CouponCreated, move |event: &Event, coupon: &Coupon| {
+            record(&c1, format!("created {} {}", event.id, coupon.id));
+            Ok(())
+        })
+        .on_object(move |event: &Event, coupon: &Coupon| {
+            record(&c2, format!("{} {:?}", event.event_type, coupon.percent_off));
+            Ok(())
+        })
+        .on_event(EventType::BalanceAvailable, move |event| {
+            record(&c3, format!("balance {}", event.id));
+            Ok(())
+        })
+        // Not called, since the object of a `coupon.created` event isn't a balance
+        .on(EventType::CouponCreated, |_: &Event, _: &Balance| Err("not a balance".to_string()))
+        .fallback(move |event| {
+            record(&c4, format!("fallback {}", event.event_type));
+            Ok(())
+        });
+
+    let routed = router.route(&event("evt_1", EventType::CouponCreated, coupon()));
+    assert_eq!(routed.unwrap(), Routed::Handled);
+    let routed = router.route(&event("evt_2", EventType::CouponDeleted, coupon()));
+    assert_eq!(routed.unwrap(), Routed::Handled);
+    let routed = router.route(&event("evt_3", EventType::BalanceAvailable, balance()));
+    assert_eq!(routed.unwrap(), Routed::Handled);
+    let routed =
+        router.route(&event("evt_4", EventType::Unknown("balance.frozen".into()), balance()));
+    assert_eq!(routed.unwrap(), Routed::Fallback);
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            "created evt_1 SUMMER",
+            "coupon.created Some(25.0)",
+            "coupon.deleted Some(25.0)",
+            "balance evt_3",
+            "fallback balance.frozen",
+        ]
+    );
+
+    let router = EventRouter::<String>::new();
+    let routed = router.route(&event("evt_5", EventType::BalanceAvailable, balance()));
+    assert_eq!(routed.unwrap(), Routed::Unhandled);
+}
+
+#[test]
+fn test_errors_of_every_handler_are_returned() {
+    let router = EventRouter::new()
+        .on_event(EventType::BalanceAvailable, |_| Err("first"))
+        .on_event(EventType::BalanceAvailable, |_| Ok(()))
+        .on_event(EventType::BalanceAvailable, |_| Err("second"));
+
+    let err = router.route(&event("evt_1", EventType::BalanceAvailable, balance())).unwrap_err();
+    assert_eq!(err.event_id.as_str(), "evt_1");
+    assert_eq!(err.errors, vec!["first", "second"]);
+    assert_eq!(err.to_string(), "2 handler(s) failed for event evt_1; first; second");
+}
+
+#[test]
+fn test_redelivered_events_are_routed_once() {
+    let calls = Calls::default();
+    let c1 = calls.clone();
+    let fail = Arc::new(Mutex::new(true));
+    let f1 = fail.clone();
+    let router = EventRouter::new()
+        .on_event(EventType::BalanceAvailable, move |event| {
+            record(&c1, event.id.to_string());
+            if *f1.lock().unwrap() {
+                Err("unavailable")
+            } else {
+                Ok(())
+            }
+        })
+        .dedup(InMemoryDedup::new(2));
+
+    // A failed event is routed again when it's redelivered
+    let first = event("evt_1", EventType::BalanceAvailable, balance());
+    assert!(router.route(&first).is_err());
+    *fail.lock().unwrap() = false;
+    assert_eq!(router.route(&first).unwrap(), Routed::Handled);
+    assert_eq!(router.route(&first).unwrap(), Routed::Duplicate);
+
+    // Only the most recent events are remembered
+    assert_eq!(
+        router.route(&event("evt_2", EventType::BalanceAvailable, balance())).unwrap(),
+        Routed::Handled
+    );
+    assert_eq!(
+        router.route(&event("evt_3", EventType::BalanceAvailable, balance())).unwrap(),
+        Routed::Handled
+    );
+    assert_eq!(router.route(&first).unwrap(), Routed::Handled);
+
+    assert_eq!(*calls.lock().unwrap(), vec!["evt_1", "evt_1", "evt_2", "evt_3", "evt_1"]);
+}
+
+#[test]
+fn test_event_redelivered_while_routed_is_retried() {
+    use std::sync::Barrier;
+
+    let started = Arc::new(Barrier::new(2));
+    let release = Arc::new(Barrier::new(2));
+    let calls = Calls::default();
+    let (s1, r1, c1) = (started.clone(), release.clone(), calls.clone());
+    let router = Arc::new(
+        EventRouter::new()
+            .on_event(EventType::BalanceAvailable, move |event| {
+                record(&c1, event.id.to_string());
+                if c1.lock().unwrap().len() > 1 {
+                    return Ok(());
+                }
+                // The first delivery is still being handled when the event is redelivered
+                s1.wait();
+                r1.wait();
+                Err("unavailable")
+            })
+            .dedup(InMemoryDedup::new(10)),
+    );
+
+    let first = event("evt_1", EventType::BalanceAvailable, balance());
+    let delivery = {
+        let (router, first) = (router.clone(), first.clone());
+        std::thread::spawn(move || router.route(&first))
+    };
+    started.wait();
+    let err = router.route(&first).unwrap_err();
+    assert!(err.in_progress);
+    assert!(err.errors.is_empty());
+    assert_eq!(err.to_string(), "event evt_1 is already being routed");
+
+    // The first delivery fails, so the event is routed again when Stripe retries it
+    release.wait();
+    let err = delivery.join().unwrap().unwrap_err();
+    assert!(!err.in_progress);
+    assert_eq!(err.errors, vec!["unavailable"]);
+    assert_eq!(router.route(&first).unwrap(), Routed::Handled);
+    assert_eq!(router.route(&first).unwrap(), Routed::Duplicate);
+    assert_eq!(*calls.lock().unwrap(), vec!["evt_1", "evt_1"]);
+}
@@ -0,0 +1,112 @@
+mod server;
+
+use server::{pairs, query};
+use stripe::{EventType, HttpResponse, ReplayTransport};
+
+fn event(id: &str) -> String {
+    format!(
+        r#"{{
+  "id": "{}",
+  "object": "event",
+  "api_version": "2019-03-14",
+  "created": 1561000000,
+  "data": {{"object": {{"object": "balance", "available": [], "livemode": false, "pending": []}}}},
+  "livemode": false,
+  "pending_webhooks": 0,
+  "request": null,
+  "type": "balance.available"
+}}"#,
+        id
+    )
+}
+
+fn page(ids: &[&str], has_more: bool) -> String {
+    let data: Vec<String> = ids.iter().map(|id| event(id)).collect();
+    format!(
+        r#"{{"object": "list", "data": [{}], "has_more": {}, "url": "/v1/events"}}"#,
+        data.join(","),
+        has_more
+    )
+}
+
+fn ids(events: &[stripe::Event]) -> Vec<&str> {
+    events.iter().map(|event| event.id.as_str()).collect()
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retrieves_and_lists_events() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, event("evt_1")));
+    transport.push(HttpResponse::json(200, page(&["evt_3", "evt_2"], false)));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let id = "evt_1".parse().unwrap();
+    let event = stripe::Event::retrieve(&client, &id, &[]).unwrap();
+    assert_eq!(event.event_type, EventType::BalanceAvailable);
+
+    let mut params = stripe::ListEvents::new();
+    params.created = Some(stripe::RangeQuery::gte(1_560_000_000));
+    params.delivery_success = Some(false);
+    params.types = Some(vec![EventType::ChargeFailed, EventType::ChargeSucceeded]);
+    let events = stripe::Event::list(&client, params).unwrap();
+    assert_eq!(ids(&events.data), ["evt_3", "evt_2"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.path(), "/v1/events/evt_1");
+    assert_eq!(requests[1].url.path(), "/v1/events");
+    assert_eq!(
+        query(&requests[1]),
+        pairs(&[
+            ("created[gte]", "1560000000"),
+            ("delivery_success", "false"),
+            ("types[0]", "charge.failed"),
+            ("types[1]", "charge.succeeded"),
+        ])
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn lists_events_since_a_checkpoint_oldest_first() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, page(&["evt_3", "evt_2"], true)));
+    transport.push(HttpResponse::json(200, page(&["evt_5", "evt_4"], true)));
+    transport.push(HttpResponse::json(200, page(&["evt_6"], false)));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let mut params = stripe::ListEvents::new();
+    params.type_ = Some("balance.*");
+    let checkpoint = "evt_1".parse().unwrap();
+    let events = stripe::Event::list_since(&client, &checkpoint, params).unwrap();
+    let events: Vec<_> = events.map(Result::unwrap).collect();
+    assert_eq!(ids(&events), ["evt_2", "evt_3", "evt_4", "evt_5", "evt_6"]);
+
+    let requests = transport.requests();
+    assert_eq!(query(&requests[0]), pairs(&[("type", "balance.*"), ("ending_before", "evt_1")]));
+    assert_eq!(
+        query(&requests[1]),
+        pairs(&[("type", "balance.*"), ("limit", "2"), ("ending_before", "evt_3")])
+    );
+    assert_eq!(
+        query(&requests[2]),
+        pairs(&[("type", "balance.*"), ("limit", "2"), ("ending_before", "evt_5")])
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_lists_events_since_a_checkpoint_oldest_first() {
+    use futures_util::TryStreamExt;
+
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, page(&["evt_3", "evt_2"], true)));
+    transport.push(HttpResponse::json(200, page(&["evt_4"], false)));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let checkpoint = "evt_1".parse().unwrap();
+    let events = stripe::Event::list_since(&client, &checkpoint, stripe::ListEvents::new());
+    let events: Vec<_> = events.unwrap().try_collect().await.unwrap();
+    assert_eq!(ids(&events), ["evt_2", "evt_3", "evt_4"]);
+    assert_eq!(transport.requests().len(), 2);
+}
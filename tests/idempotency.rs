@@ -0,0 +1,98 @@
+#![cfg(feature = "blocking")]
+
+mod server;
+
+use server::{Reply, Server};
+use std::time::Duration;
+
+const DELETED: &str = r#"{"id": "cus_123", "object": "customer", "deleted": true}"#;
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+const INVALID_REQUEST_ERROR: &str =
+    r#"{"error": {"type": "invalid_request_error", "message": "bad request"}}"#;
+
+fn retry_policy(max_attempts: u32) -> stripe::RetryPolicy {
+    stripe::RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        jitter: false,
+    }
+}
+
+fn create_customer(client: &stripe::Client) {
+    let result = stripe::Customer::create(client, stripe::CreateCustomer::new());
+    assert!(result.is_err(), "expected the scripted error response");
+}
+
+#[test]
+fn sends_the_provided_key() {
+    let server = Server::serve(vec![Reply::Json(400, vec![], INVALID_REQUEST_ERROR)]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    create_customer(&client.with_idempotency_key("order_1234"));
+
+    let requests = server.finish();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].header("idempotency-key"), Some("order_1234"));
+}
+
+#[test]
+fn does_not_send_a_key_without_retries() {
+    let server = Server::serve(vec![Reply::Json(400, vec![], INVALID_REQUEST_ERROR)]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    create_customer(&client);
+
+    let requests = server.finish();
+    assert_eq!(requests[0].header("idempotency-key"), None);
+}
+
+#[test]
+fn reuses_the_provided_key_across_retries() {
+    let server = Server::serve(vec![
+        Reply::Json(500, vec![], API_ERROR),
+        Reply::Hangup,
+        Reply::Json(400, vec![], INVALID_REQUEST_ERROR),
+    ]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123")
+        .with_retry_policy(retry_policy(3))
+        .with_idempotency_key("order_1234");
+    create_customer(&client);
+
+    let requests = server.finish();
+    assert_eq!(requests.len(), 3);
+    assert!(requests.iter().all(|request| request.header("idempotency-key") == Some("order_1234")));
+}
+
+#[test]
+fn generates_one_key_per_retried_request() {
+    let server = Server::serve(vec![
+        Reply::Json(503, vec![], API_ERROR),
+        Reply::Json(400, vec![], INVALID_REQUEST_ERROR),
+        Reply::Json(400, vec![], INVALID_REQUEST_ERROR),
+    ]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123")
+        .with_retry_policy(retry_policy(2));
+    create_customer(&client);
+    create_customer(&client);
+
+    let requests = server.finish();
+    let keys: Vec<_> = requests.iter().map(|request| request.header("idempotency-key")).collect();
+    assert!(keys.iter().all(Option::is_some));
+    assert_eq!(keys[0], keys[1], "a retry must reuse the key of its first attempt");
+    assert_ne!(keys[1], keys[2], "separate requests must not share a key");
+}
+
+#[test]
+fn does_not_send_a_key_for_other_methods() {
+    let server = Server::serve(vec![Reply::Json(200, vec![], DELETED)]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123")
+        .with_retry_policy(retry_policy(2))
+        .with_idempotency_key("order_1234");
+    let id = "cus_123".parse().unwrap();
+    assert!(stripe::Customer::delete(&client, &id).unwrap().deleted);
+
+    let requests = server.finish();
+    assert_eq!(requests[0].method, "DELETE");
+    assert!(requests[0].body.is_empty());
+    assert_eq!(requests[0].path, "/v1/customers/cus_123");
+    assert_eq!(requests[0].header("idempotency-key"), None);
+}
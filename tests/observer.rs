@@ -0,0 +1,84 @@
+#![cfg(feature = "blocking")]
+
+mod server;
+
+use server::{Reply, Server};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+const CARD_ERROR: &str = r#"{"error": {"type": "card_error", "code": "card_declined"}}"#;
+
+fn observed_client(server: &Server) -> (stripe::Client, Arc<Mutex<Vec<stripe::RequestLog>>>) {
+    let logs = Arc::new(Mutex::new(Vec::new()));
+    let observed = Arc::clone(&logs);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123")
+        .with_retry_policy(stripe::RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            jitter: false,
+        })
+        .with_observer(move |log: &stripe::RequestLog| observed.lock().unwrap().push(log.clone()));
+    (client, logs)
+}
+
+#[test]
+fn observes_every_attempt_with_redacted_body() {
+    let server = Server::serve(vec![
+        Reply::Json(500, vec![("Request-Id", "req_1")], API_ERROR),
+        Reply::Json(402, vec![("Request-Id", "req_2")], CARD_ERROR),
+    ]);
+    let (client, logs) = observed_client(&server);
+
+    let card = stripe::CardParams {
+        number: "4242424242424242",
+        exp_month: "10",
+        exp_year: "20",
+        cvc: Some("123"),
+        name: None,
+    };
+    let params = stripe::ChargeParams {
+        amount: Some(1000),
+        source: Some(stripe::PaymentSourceParams::Card(card)),
+        ..Default::default()
+    };
+    assert!(stripe::Charge::create(&client, params).is_err());
+
+    let requests = server.finish();
+    assert!(requests[0].body.contains("4242424242424242"), "the request itself is not redacted");
+
+    let logs = logs.lock().unwrap();
+    assert_eq!(logs.len(), 2);
+    for (index, log) in logs.iter().enumerate() {
+        assert_eq!(log.method, "POST");
+        assert_eq!(log.path, "/v1/charges");
+        assert_eq!(log.attempt, index as u32 + 1);
+        let body = log.body.as_ref().unwrap();
+        assert!(body.starts_with("amount=1000&"), "unexpected body: {}", body);
+        assert!(!body.contains("4242424242424242"), "card number was logged: {}", body);
+        assert!(!body.contains("123"), "cvc was logged: {}", body);
+        assert!(body.contains("source[exp_month]=10"), "unexpected body: {}", body);
+    }
+    assert_eq!(logs[0].status, Some(500));
+    assert_eq!(logs[0].request_id.as_deref(), Some("req_1"));
+    assert_eq!(logs[1].status, Some(402));
+    assert_eq!(logs[1].request_id.as_deref(), Some("req_2"));
+}
+
+#[test]
+fn observes_connection_failures() {
+    let server = Server::serve(vec![Reply::Hangup, Reply::Hangup]);
+    let (client, logs) = observed_client(&server);
+    let id = "cus_123".parse().unwrap();
+    match stripe::Customer::delete(&client, &id) {
+        Err(stripe::Error::Http(_)) => (),
+        other => panic!("expected a http error, got {:?}", other),
+    }
+    server.finish();
+
+    let logs = logs.lock().unwrap();
+    assert_eq!(logs.len(), 2);
+    assert!(logs.iter().all(|log| log.method == "DELETE" && log.status.is_none()));
+    assert!(logs.iter().all(|log| log.body.is_none() && log.request_id.is_none()));
+}
@@ -0,0 +1,192 @@
+mod server;
+
+use server::{pairs, query};
+use stripe::{HttpResponse, ReplayTransport};
+
+const FIRST_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_1", "object": "coupon"}, {"id": "co_2", "object": "coupon"}],
+  "has_more": true,
+  "url": "/v1/coupons"
+}"#;
+const SECOND_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_3", "object": "coupon"}, {"id": "co_4", "object": "coupon"}],
+  "has_more": true,
+  "url": "/v1/coupons"
+}"#;
+const LAST_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_5", "object": "coupon"}],
+  "has_more": false,
+  "url": "/v1/coupons"
+}"#;
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+
+const FILTERED_PAGE: &str = r#"{
+  "object": "list",
+  "data": [{"id": "co_3", "object": "coupon"}, {"id": "co_4", "object": "coupon"}],
+  "has_more": true,
+  "url": "/v1/coupons"
+}"#;
+
+fn ids(coupons: &[stripe::Coupon]) -> Vec<&str> {
+    coupons.iter().map(|coupon| coupon.id.as_str()).collect()
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn fetches_pages_lazily() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(200, SECOND_PAGE));
+    transport.push(HttpResponse::json(200, LAST_PAGE));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let mut params = stripe::ListCoupons::new();
+    params.limit = Some(2);
+    let mut coupons = stripe::Coupon::list(&client, params).unwrap().paginate(&client);
+    let first: Vec<_> = coupons.by_ref().take(2).map(Result::unwrap).collect();
+    assert_eq!(ids(&first), ["co_1", "co_2"]);
+    assert_eq!(transport.requests().len(), 1);
+
+    let rest: Vec<_> = coupons.map(Result::unwrap).collect();
+    assert_eq!(ids(&rest), ["co_3", "co_4", "co_5"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].url.query(), Some("limit=2"));
+    assert_eq!(requests[1].url.query(), Some("limit=2&starting_after=co_2"));
+    assert_eq!(requests[2].url.query(), Some("limit=2&starting_after=co_4"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn stops_after_an_error() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(500, API_ERROR));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).unwrap();
+    let results: Vec<_> = coupons.paginate(&client).collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(Result::is_ok));
+    assert!(results[2].is_err());
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn gets_all_pages() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(200, SECOND_PAGE));
+    transport.push(HttpResponse::json(200, LAST_PAGE));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).unwrap();
+    let all = coupons.get_all(&client).unwrap();
+    assert_eq!(ids(&all), ["co_1", "co_2", "co_3", "co_4", "co_5"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].url.query(), Some("limit=2&starting_after=co_4"));
+
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(500, API_ERROR));
+    let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).unwrap();
+    assert!(coupons.get_all(&client).is_err());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn pages_forwards_and_backwards_with_the_same_filters() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FILTERED_PAGE));
+    transport.push(HttpResponse::json(200, LAST_PAGE));
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let mut params = stripe::ListCoupons::new();
+    params.created = Some(stripe::RangeQuery::gt(1_500_000_000));
+    params.limit = Some(2);
+    params.starting_after = Some("co_2".parse().unwrap());
+    let page = stripe::Coupon::list(&client, params).unwrap();
+    assert_eq!(page.url, "/v1/coupons?created[gt]=1500000000&limit=2&starting_after=co_2");
+
+    let next = page.next(&client).unwrap();
+    assert_eq!(ids(&next.data), ["co_5"]);
+    let previous = page.previous(&client).unwrap();
+    assert_eq!(ids(&previous.data), ["co_1", "co_2"]);
+
+    let requests = transport.requests();
+    assert_eq!(
+        query(&requests[1]),
+        pairs(&[("created[gt]", "1500000000"), ("limit", "2"), ("starting_after", "co_4")])
+    );
+    assert_eq!(
+        query(&requests[2]),
+        pairs(&[("created[gt]", "1500000000"), ("limit", "2"), ("ending_before", "co_3")])
+    );
+    assert!(requests.iter().all(|request| request.url.path() == "/v1/coupons"));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_pages_backwards_with_the_same_filters() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FILTERED_PAGE));
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let mut params = stripe::ListCoupons::new();
+    params.created = Some(stripe::RangeQuery::gt(1_500_000_000));
+    let page = stripe::Coupon::list(&client, params).await.unwrap();
+    let previous = page.previous(&client).await.unwrap();
+    assert_eq!(ids(&previous.data), ["co_1", "co_2"]);
+
+    let requests = transport.requests();
+    assert_eq!(
+        query(&requests[1]),
+        pairs(&[("created[gt]", "1500000000"), ("ending_before", "co_3")])
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_streams_pages_lazily() {
+    use futures_util::{StreamExt, TryStreamExt};
+
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(200, SECOND_PAGE));
+    transport.push(HttpResponse::json(200, LAST_PAGE));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).await.unwrap();
+    let mut coupons = coupons.paginate(&client);
+    let first: Vec<_> = coupons.by_ref().take(3).try_collect().await.unwrap();
+    assert_eq!(ids(&first), ["co_1", "co_2", "co_3"]);
+    assert_eq!(transport.requests().len(), 2);
+
+    let rest: Vec<_> = coupons.try_collect().await.unwrap();
+    assert_eq!(ids(&rest), ["co_4", "co_5"]);
+    assert_eq!(transport.requests()[2].url.query(), Some("limit=2&starting_after=co_4"));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_stops_after_an_error() {
+    use futures_util::StreamExt;
+
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, FIRST_PAGE));
+    transport.push(HttpResponse::json(500, API_ERROR));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+
+    let coupons = stripe::Coupon::list(&client, stripe::ListCoupons::new()).await.unwrap();
+    let results: Vec<_> = coupons.paginate(&client).collect().await;
+    assert_eq!(results.len(), 3);
+    assert!(results[2].is_err());
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "blocking")]
+#![allow(clippy::result_large_err)]
+
+mod server;
+
+use server::{Reply, Server};
+
+const DELETED: &str = r#"{"id": "cus_123", "object": "customer", "deleted": true}"#;
+const INVALID_REQUEST_ERROR: &str =
+    r#"{"error": {"type": "invalid_request_error", "message": "No such customer"}}"#;
+
+#[test]
+fn returns_meta_alongside_the_object() {
+    let server = Server::serve(vec![Reply::Json(
+        200,
+        vec![
+            ("Request-Id", "req_123"),
+            ("Stripe-Version", "2019-05-16"),
+            ("Idempotent-Replayed", "true"),
+        ],
+        DELETED,
+    )]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    let id = "cus_123".parse().unwrap();
+    let (deleted, meta) =
+        client.with_response_meta(|client| stripe::Customer::delete(client, &id)).unwrap();
+
+    assert!(deleted.deleted);
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.header("stripe-version"), Some("2019-05-16"));
+    assert!(meta.idempotent_replayed);
+
+    let requests = server.finish();
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[0].path, "/v1/customers/cus_123");
+    assert!(requests[0].body.is_empty());
+    assert!(requests[0].header("authorization").is_some());
+}
+
+#[test]
+fn request_error_carries_request_id() {
+    let server = Server::serve(vec![Reply::Json(
+        404,
+        vec![("Request-Id", "req_456")],
+        INVALID_REQUEST_ERROR,
+    )]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    let id = "cus_123".parse().unwrap();
+    match stripe::Customer::delete(&client, &id) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.http_status, 404);
+            assert_eq!(err.request_id.as_deref(), Some("req_456"));
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+    server.finish();
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "blocking")]
+#![allow(clippy::result_large_err)]
+
+mod server;
+
+use server::{Reply, Server};
+use std::time::Duration;
+
+const DELETED: &str = r#"{"id": "cus_123", "object": "customer", "deleted": true}"#;
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+const RATE_LIMIT_ERROR: &str = r#"{"error": {"type": "rate_limit_error", "message": "slow down"}}"#;
+const CARD_ERROR: &str = r#"{"error": {"type": "card_error", "code": "card_declined"}}"#;
+
+fn client(server: &Server, max_attempts: u32) -> stripe::Client {
+    stripe::Client::from_url(server.url.as_str(), "sk_test_123").with_retry_policy(
+        stripe::RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            jitter: true,
+        },
+    )
+}
+
+fn delete_customer(client: &stripe::Client) -> Result<bool, stripe::Error> {
+    let id = "cus_123".parse().unwrap();
+    stripe::Customer::delete(client, &id).map(|deleted| deleted.deleted)
+}
+
+#[test]
+fn does_not_retry_by_default() {
+    let server = Server::serve(vec![Reply::Json(500, vec![], API_ERROR)]);
+    let client = stripe::Client::from_url(server.url.as_str(), "sk_test_123");
+    match delete_customer(&client) {
+        Err(stripe::Error::Stripe(err)) => assert_eq!(err.http_status, 500),
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+    assert_eq!(server.finish().len(), 1);
+}
+
+#[test]
+fn retries_server_and_rate_limit_errors() {
+    let server = Server::serve(vec![
+        Reply::Json(500, vec![], API_ERROR),
+        Reply::Json(429, vec![], RATE_LIMIT_ERROR),
+        Reply::Json(409, vec![], API_ERROR),
+        Reply::Json(200, vec![], DELETED),
+    ]);
+    let client = client(&server, 4);
+    assert!(delete_customer(&client).unwrap());
+
+    let requests = server.finish();
+    assert_eq!(requests.len(), 4);
+    for request in requests {
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/v1/customers/cus_123");
+        assert_eq!(request.header("authorization"), Some("Bearer sk_test_123"));
+    }
+}
+
+#[test]
+fn retries_connection_failures() {
+    let server = Server::serve(vec![Reply::Hangup, Reply::Json(200, vec![], DELETED)]);
+    let client = client(&server, 2);
+    assert!(delete_customer(&client).unwrap());
+    assert_eq!(server.finish().len(), 2);
+}
+
+#[test]
+fn stops_after_max_attempts() {
+    let server = Server::serve(vec![
+        Reply::Json(503, vec![], API_ERROR),
+        Reply::Json(503, vec![], API_ERROR),
+    ]);
+    let client = client(&server, 2);
+    match delete_customer(&client) {
+        Err(stripe::Error::Stripe(err)) => assert_eq!(err.http_status, 503),
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+    assert_eq!(server.finish().len(), 2);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let server = Server::serve(vec![Reply::Json(402, vec![], CARD_ERROR)]);
+    let client = client(&server, 3);
+    match delete_customer(&client) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.error_type, stripe::ErrorType::Card);
+            assert_eq!(err.code, Some(stripe::ErrorCode::CardDeclined));
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+    assert_eq!(server.finish().len(), 1);
+}
+
+#[test]
+fn honours_should_retry_header() {
+    let server = Server::serve(vec![
+        Reply::Json(400, vec![("Stripe-Should-Retry", "true"), ("Retry-After", "0")], API_ERROR),
+        Reply::Json(503, vec![("Stripe-Should-Retry", "false")], API_ERROR),
+    ]);
+    let client = client(&server, 3);
+    match delete_customer(&client) {
+        Err(stripe::Error::Stripe(err)) => assert_eq!(err.http_status, 503),
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+
+    let requests = server.finish();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|request| request.body.is_empty()));
+}
@@ -0,0 +1,125 @@
+//! A minimal local HTTP server which replies with scripted responses,
+//! used to test the client's behaviour without talking to Stripe,
+//! and helpers to inspect the requests recorded by a `ReplayTransport`.
+
+// N.B. Each test crate only uses some of these helpers.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// A request received by the server.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+/// The decoded query parameters of a request recorded by a `ReplayTransport`.
+pub fn query(request: &stripe::HttpRequest) -> Vec<(String, String)> {
+    request.url.query_pairs().into_owned().collect()
+}
+
+/// The owned `(name, value)` pairs to compare with the `query` of a request.
+pub fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+/// A scripted reply to one request.
+pub enum Reply {
+    /// Respond with the given status, extra headers and json body.
+    Json(u16, Vec<(&'static str, &'static str)>, &'static str),
+    /// Close the connection without responding.
+    Hangup,
+    /// Wait for the given duration, then close the connection without responding.
+    Stall(Duration),
+}
+
+pub struct Server {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Server {
+    /// Serves each of `replies` in order to one incoming request, then stops.
+    pub fn serve(replies: Vec<Reply>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        let handle = std::thread::spawn(move || {
+            for reply in replies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&mut BufReader::new(&mut stream));
+                received.lock().unwrap().push(request);
+                match reply {
+                    Reply::Json(status, headers, body) => {
+                        let mut response = format!(
+                            "HTTP/1.1 {} Scripted\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                            status,
+                            body.len()
+                        );
+                        for (name, value) in headers {
+                            response.push_str(&format!("{}: {}\r\n", name, value));
+                        }
+                        response.push_str("\r\n");
+                        response.push_str(body);
+                        stream.write_all(response.as_bytes()).unwrap();
+                    }
+                    Reply::Hangup => (),
+                    Reply::Stall(duration) => std::thread::sleep(duration),
+                }
+            }
+        });
+        Server { url, requests, handle: Some(handle) }
+    }
+
+    /// Waits until every reply was served and returns the requests received.
+    pub fn finish(mut self) -> Vec<Request> {
+        self.handle.take().unwrap().join().unwrap();
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request<R: BufRead>(reader: &mut R) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(index) = line.find(':') {
+            headers.push((line[..index].to_string(), line[index + 1..].trim().to_string()));
+        }
+    }
+
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+
+    Request { method, path, headers, body: String::from_utf8(body).unwrap() }
+}
@@ -0,0 +1,68 @@
+#![allow(clippy::result_large_err)]
+
+use stripe::{HttpResponse, ReplayTransport};
+
+const COUPON: &str = r#"{"id": "co_123", "object": "coupon"}"#;
+const API_ERROR: &str = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn replays_responses_and_records_requests() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(500, API_ERROR));
+    transport.push(HttpResponse::json(200, COUPON).with_header("Request-Id", "req_123"));
+    let client = stripe::Client::new("sk_test_123")
+        .with_transport(transport.clone())
+        .with_retry_policy(stripe::RetryPolicy {
+            max_attempts: 2,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(1),
+            jitter: false,
+        });
+
+    let (coupon, meta) = client
+        .with_response_meta(|client| {
+            stripe::Coupon::create(client, stripe::CreateCoupon::new(stripe::CouponDuration::Once))
+        })
+        .unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    for request in &requests {
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url.as_str(), "https://api.stripe.com/v1/coupons");
+        assert_eq!(request.header("authorization"), Some("Bearer sk_test_123"));
+        assert!(request.header("user-agent").unwrap().starts_with("Stripe/v1 RustBindings/"));
+        assert_eq!(request.header("content-type"), Some("application/x-www-form-urlencoded"));
+        assert_eq!(request.header("idempotency-key"), requests[0].header("idempotency-key"));
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn fails_when_no_responses_are_left() {
+    let transport = ReplayTransport::new();
+    let client = stripe::Client::new("sk_test_123").with_transport(transport.clone());
+    let id = "co_123".parse().unwrap();
+    match stripe::Coupon::retrieve(&client, &id, &[]) {
+        Err(stripe::Error::Unexpected(_)) => (),
+        other => panic!("expected an unexpected error, got {:?}", other),
+    }
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn replays_responses_to_the_async_client() {
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(200, COUPON));
+    let client = stripe::r#async::Client::new("sk_test_123").with_transport(transport.clone());
+    let id = "co_123".parse().unwrap();
+    let coupon = stripe::Coupon::retrieve(&client, &id, &[]).await.unwrap();
+    assert_eq!(coupon.id.as_str(), "co_123");
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.path(), "/v1/coupons/co_123");
+    assert!(requests[0].header("user-agent").unwrap().starts_with("Stripe/v1 RustBindings/"));
+}
@@ -0,0 +1,60 @@
+use stripe::{Coupon, CouponDuration, Currency, ErrorCode, FilePurpose};
+
+#[cfg(feature = "blocking")]
+#[test]
+fn unknown_error_code_is_kept() {
+    use stripe::{HttpResponse, ReplayTransport};
+
+    let transport = ReplayTransport::new();
+    transport.push(HttpResponse::json(
+        402,
+        r#"{"error": {"type": "card_error", "code": "card_velocity_exceeded"}}"#,
+    ));
+    let client = stripe::Client::new("sk_test_123").with_transport(transport);
+
+    match Coupon::create(&client, stripe::CreateCoupon::new(CouponDuration::Once)) {
+        Err(stripe::Error::Stripe(err)) => {
+            assert_eq!(err.error_type, stripe::ErrorType::Card);
+            assert_eq!(err.code, Some(ErrorCode::Unknown("card_velocity_exceeded".into())));
+            assert_eq!(err.code.unwrap().to_string(), "card_velocity_exceeded");
+        }
+        other => panic!("expected a stripe error, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    let coupon: Coupon = serde_json::from_value(serde_json::json!({
+        "id": "SUMMER",
+        "object": "coupon",
+        "amount_off": 500,
+        "created": 1561000000,
+        "currency": "xts",
+        "duration": "seasonal",
+        "livemode": false,
+        "metadata": {},
+        "times_redeemed": 0,
+        "valid": true
+    }))
+    .unwrap();
+    assert_eq!(coupon.currency, Some(Currency::Unknown("xts".into())));
+    assert_eq!(coupon.duration, Some(CouponDuration::Unknown("seasonal".into())));
+
+    let json = serde_json::to_value(&coupon).unwrap();
+    assert_eq!(json["currency"], "xts");
+    assert_eq!(json["duration"], "seasonal");
+}
+
+#[test]
+fn known_values_are_parsed() {
+    assert_eq!(Currency::from("usd"), Currency::USD);
+    assert_eq!(Currency::from("xts").as_str(), "xts");
+    assert!("xts".parse::<Currency>().is_err());
+    assert_eq!(ErrorCode::from("card_declined"), ErrorCode::CardDeclined);
+    assert_eq!(ErrorCode::CardDeclined.as_str(), "card_declined");
+    assert_eq!(FilePurpose::from("dispute_evidence"), FilePurpose::DisputeEvidence);
+    assert_eq!(FilePurpose::Unknown("tax_form".into()).to_string(), "tax_form");
+
+    let currencies: Vec<Currency> = serde_json::from_str(r#"["eur", "jpy", "xts"]"#).unwrap();
+    assert_eq!(currencies, vec![Currency::EUR, Currency::JPY, Currency::Unknown("xts".into())]);
+}
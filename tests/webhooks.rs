@@ -0,0 +1,154 @@
+#![cfg(feature = "webhooks")]
+
+use stripe::{EventBuilder, EventObject, EventType, Webhook, WebhookError, WebhookVerifier};
+
+fn balance() -> EventObject {
+    EventObject::Unknown(serde_json::json!({
+        "object": "balance",
+        "available": [{"amount": 1000, "currency": "usd"}],
+        "livemode": false,
+        "pending": [],
+    }))
+}
+
+#[test]
+fn test_event_signed_with_test_header_is_verified() {
+    let mut previous_attributes = serde_json::Map::new();
+    previous_attributes.insert("available".into(), serde_json::json!([]));
+    let payload = EventBuilder::new(EventType::BalanceAvailable, balance())
+        .id("evt_123".parse().unwrap())
+        .account("acct_123".parse().unwrap())
+        .created(1561000000)
+        .previous_attributes(previous_attributes)
+        .payload();
+    let timestamp = chrono::Utc::now().timestamp();
+    let header = Webhook::generate_test_header(&payload, "whsec_test", timestamp);
+    assert!(header.starts_with(&format!("t={},v1=", timestamp)));
+
+    let event = WebhookVerifier::new("whsec_test").construct_event(&payload, &header).unwrap();
+    assert_eq!(event.id.as_str(), "evt_123");
+    assert_eq!(event.account.as_ref().map(|id| id.as_str()), Some("acct_123"));
+    assert_eq!(event.created, 1561000000);
+    assert_eq!(event.event_type, EventType::BalanceAvailable);
+    assert!(!event.livemode);
+    assert!(!event.has_api_version_mismatch());
+    match &event.data.object {
+        EventObject::Balance(balance) => assert_eq!(balance.available[0].amount, 1000),
+        other => panic!("expected a balance, got {:?}", other),
+    }
+    assert_eq!(event.data.previous_attributes.unwrap()["available"], serde_json::json!([]));
+
+    // The legacy api accepts the same header
+    let event = Webhook::construct_event(&payload, &header, "whsec_test");
+    assert_eq!(event.unwrap().id.as_str(), "evt_123");
+
+    match WebhookVerifier::new("whsec_other").construct_event(payload.as_bytes(), &header) {
+        Err(WebhookError::BadSignature) => {}
+        other => panic!("expected a bad signature, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_old_test_header_is_rejected() {
+    let payload = EventBuilder::new(EventType::BalanceAvailable, balance()).payload();
+    let timestamp = chrono::Utc::now().timestamp() - 3600;
+    let header = Webhook::generate_test_header(&payload, "whsec_test", timestamp);
+
+    match WebhookVerifier::new("whsec_test").verify(&payload, &header) {
+        Err(WebhookError::BadTimestamp(t)) => assert_eq!(t, timestamp),
+        other => panic!("expected a bad timestamp, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_signed_payload_which_fails_to_parse_is_acknowledged() {
+    let payload = r#"{"id": "evt_123", "object": "event"}"#;
+    let timestamp = chrono::Utc::now().timestamp();
+    let header = Webhook::generate_test_header(payload, "whsec_test", timestamp);
+
+    match WebhookVerifier::new("whsec_test").construct_event(payload, &header) {
+        Err(err @ WebhookError::BadParse(_)) => assert_eq!(err.status_code(), 200),
+        other => panic!("expected a bad parse, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_built_events_have_distinct_ids() {
+    let first = EventBuilder::new(EventType::BalanceAvailable, balance()).build();
+    let second = EventBuilder::new(EventType::BalanceAvailable, balance()).build();
+    assert!(first.id.as_str().starts_with("evt_"));
+    assert_ne!(first.id, second.id);
+    assert_eq!(first.api_version.as_deref(), Some(stripe::API_VERSION.as_str()));
+}
+
+#[cfg(feature = "hyper")]
+#[tokio::test]
+async fn test_hyper_request_is_received() {
+    use hyper::{Body, Request};
+
+    let payload = EventBuilder::new(EventType::BalanceAvailable, balance())
+        .id("evt_123".parse().unwrap())
+        .payload();
+    let header =
+        Webhook::generate_test_header(&payload, "whsec_test", chrono::Utc::now().timestamp());
+    let verifier = WebhookVerifier::new("whsec_test");
+
+    let request = Request::post("/webhook")
+        .header("Stripe-Signature", header.as_str())
+        .body(Body::from(payload.clone()))
+        .unwrap();
+    let event = verifier.receive(request).await.unwrap();
+    assert_eq!(event.id.as_str(), "evt_123");
+
+    // A request whose body was read by a framework
+    let request = Request::post("/webhook")
+        .header("Stripe-Signature", header.as_str())
+        .body(payload.clone())
+        .unwrap();
+    assert_eq!(verifier.verify_request(&request).unwrap().id.as_str(), "evt_123");
+
+    let request = Request::post("/webhook").body(Body::from(payload)).unwrap();
+    let err = verifier.receive(request).await.unwrap_err();
+    match err {
+        WebhookError::BadSignature => {}
+        ref other => panic!("expected a bad signature, got {:?}", other),
+    }
+    let response = err.to_response();
+    assert_eq!(response.status(), 400);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(&body[..], b"error comparing signatures");
+}
+
+#[cfg(feature = "hyper")]
+#[tokio::test]
+async fn test_hyper_request_body_is_limited() {
+    use hyper::{Body, Request};
+
+    let verifier = WebhookVerifier::new("whsec_test");
+    let too_large = vec![b' '; 1024 * 1024 + 1];
+
+    let request = Request::post("/webhook").body(Body::from(too_large.clone())).unwrap();
+    match verifier.receive(request).await {
+        Err(WebhookError::BadBody(err)) => {
+            assert_eq!(err.to_string(), "request body is larger than 1 MiB")
+        }
+        other => panic!("expected a bad body, got {:?}", other),
+    }
+
+    // The body isn't read when its announced length is too large
+    let request = Request::post("/webhook")
+        .header("Content-Length", too_large.len().to_string())
+        .body(Body::empty())
+        .unwrap();
+    match verifier.receive(request).await {
+        Err(err @ WebhookError::BadBody(_)) => assert_eq!(err.status_code(), 400),
+        other => panic!("expected a bad body, got {:?}", other),
+    }
+
+    // A body of exactly 1 MiB is read, then fails to verify
+    let request = Request::post("/webhook").body(Body::from(vec![b' '; 1024 * 1024])).unwrap();
+    match verifier.receive(request).await {
+        Err(WebhookError::BadSignature) => {}
+        other => panic!("expected a bad signature, got {:?}", other),
+    }
+}